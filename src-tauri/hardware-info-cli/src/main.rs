@@ -0,0 +1,368 @@
+//! `hwinfo` — prints the Hardware Info Dashboard report on a terminal.
+//!
+//! Meant for headless machines reached over SSH: it links only against
+//! `hardware-info-core`, never Tauri or GTK.
+
+mod report;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Instant;
+
+use hardware_info_core::{
+    self as core, CommandBundle, CpuInfo, HardwareInfo, LiveInfo, ProbeContext, RecordingRunner, ReplayRunner,
+    StorageOptions, SystemRunner,
+};
+use serde::Serialize;
+
+const USAGE: &str = "\
+Usage: hwinfo [OPTIONS]
+
+Prints a hardware report. With no section flags, every section is shown.
+
+Sections:
+      --system        Motherboard, BIOS, operating system and MAC addresses
+      --cpu           Processor, clock speeds, per-CPU load, time breakdown and temperature
+      --sensors       Every temperature, fan, voltage, current and power sensor, and thermal zones
+      --memory        RAM, swap and DIMM layout
+      --graphics      Display controllers
+      --pci           Every PCI device with IDs, driver and IOMMU group
+      --storage       Disks, partitions, storage stack, filesystems and disk I/O
+      --network       Network interfaces with addresses, link state, driver and traffic
+      --audio         Sound cards
+      --peripherals   USB devices
+      --optical       Optical drives
+      --runtime       Uptime and current time
+      --diagnostics   What each probe read, how long it took and why it failed
+
+Options:
+      --live          Sample only the live metrics (cpu, sensors, memory, disk and network I/O, runtime)
+      --json          Emit JSON instead of text
+      --all-disks     Also list loop, RAM, zram and other virtual disks
+      --partition-tables
+                      Decode partition tables from the disks themselves (usually needs root)
+      --root DIR      Read sys/, proc/, dev/, etc/ and run/ from a captured tree under DIR
+      --record FILE   Save the output of every external command to FILE
+      --replay FILE   Answer external commands from a FILE saved by --record
+  -h, --help          Print this help
+";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Section {
+    System,
+    Cpu,
+    Sensors,
+    Memory,
+    Graphics,
+    Pci,
+    Storage,
+    Network,
+    Audio,
+    Peripherals,
+    Optical,
+    Runtime,
+    Diagnostics,
+}
+
+impl Section {
+    const ALL: [Section; 13] = [
+        Section::System,
+        Section::Cpu,
+        Section::Sensors,
+        Section::Memory,
+        Section::Graphics,
+        Section::Pci,
+        Section::Storage,
+        Section::Network,
+        Section::Audio,
+        Section::Peripherals,
+        Section::Optical,
+        Section::Runtime,
+        Section::Diagnostics,
+    ];
+
+    fn from_flag(flag: &str) -> Option<Section> {
+        Section::ALL.into_iter().find(|s| s.flag() == flag)
+    }
+
+    fn flag(self) -> &'static str {
+        match self {
+            Section::System => "--system",
+            Section::Cpu => "--cpu",
+            Section::Sensors => "--sensors",
+            Section::Memory => "--memory",
+            Section::Graphics => "--graphics",
+            Section::Pci => "--pci",
+            Section::Storage => "--storage",
+            Section::Network => "--network",
+            Section::Audio => "--audio",
+            Section::Peripherals => "--peripherals",
+            Section::Optical => "--optical",
+            Section::Runtime => "--runtime",
+            Section::Diagnostics => "--diagnostics",
+        }
+    }
+
+    /// Top-level keys of the serialized `HardwareInfo` this section owns.
+    fn json_keys(self) -> &'static [&'static str] {
+        match self {
+            Section::System => &["staticData"],
+            // A live sample has no `cpu`, so its power draw stays at the top level.
+            Section::Cpu => &["cpu", "cpuCurrentSpeed", "currentLoad", "cpuTimes", "cpuPower", "cpuTemperature"],
+            Section::Sensors => &["sensors"],
+            Section::Memory => &["memory"],
+            Section::Graphics => &["graphics"],
+            Section::Pci => &["pci"],
+            Section::Storage => &["storage", "diskIo"],
+            Section::Network => &["network", "networkIo"],
+            Section::Audio => &["audio"],
+            Section::Peripherals => &["peripherals"],
+            Section::Optical => &["optical"],
+            Section::Runtime => &["runtime"],
+            Section::Diagnostics => &["diagnostics"],
+        }
+    }
+
+    fn is_live(self) -> bool {
+        matches!(
+            self,
+            Section::Cpu | Section::Sensors | Section::Memory | Section::Storage | Section::Network | Section::Runtime
+        )
+    }
+}
+
+struct Options {
+    sections: Vec<Section>,
+    live: bool,
+    json: bool,
+    storage: StorageOptions,
+    ctx: ProbeContext,
+    record: Option<PathBuf>,
+    replay: Option<PathBuf>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut sections = Vec::new();
+    let mut live = false;
+    let mut json = false;
+    let mut storage = StorageOptions::default();
+    let mut ctx = ProbeContext::default();
+    let mut record = None;
+    let mut replay = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--live" => live = true,
+            "--json" => json = true,
+            "--all-disks" => storage.include_virtual = true,
+            "--partition-tables" => storage.read_partition_tables = true,
+            "--root" => match args.next() {
+                Some(dir) => ctx = ProbeContext::from_root(dir),
+                None => return Err("--root needs a directory".to_string()),
+            },
+            "--record" => match args.next() {
+                Some(file) => record = Some(PathBuf::from(file)),
+                None => return Err("--record needs a file".to_string()),
+            },
+            "--replay" => match args.next() {
+                Some(file) => replay = Some(PathBuf::from(file)),
+                None => return Err("--replay needs a file".to_string()),
+            },
+            other => match Section::from_flag(other) {
+                Some(section) if !sections.contains(&section) => sections.push(section),
+                Some(_) => {}
+                None => return Err(format!("unknown option '{}'", other)),
+            },
+        }
+    }
+
+    if record.is_some() && replay.is_some() {
+        return Err("--record and --replay cannot be combined".to_string());
+    }
+
+    if sections.is_empty() {
+        sections = Section::ALL
+            .into_iter()
+            .filter(|s| !live || s.is_live())
+            .collect();
+    } else if live {
+        if let Some(s) = sections.iter().find(|s| !s.is_live()) {
+            return Err(format!("{} is not available with --live", s.flag()));
+        }
+    }
+    // Print in struct order regardless of the order flags were given in.
+    sections.sort_by_key(|s| Section::ALL.iter().position(|a| a == s));
+
+    Ok(Some(Options {
+        sections,
+        live,
+        json,
+        storage,
+        ctx,
+        record,
+        replay,
+    }))
+}
+
+/// Collects only the requested sections; the rest stay at their defaults.
+fn collect(ctx: &ProbeContext, sections: &[Section], storage: &StorageOptions) -> HardwareInfo {
+    let needs_system = sections
+        .iter()
+        .any(|s| matches!(s, Section::System | Section::Cpu | Section::Memory));
+    // The CPU time breakdown, power draw, disk and network I/O cover the
+    // refresh interval; only the second sample's probes are reported.
+    let first = ctx.unrecorded();
+    let cpu_before = sections
+        .contains(&Section::Cpu)
+        .then(|| (core::sample_cpu_times(&first), core::sample_cpu_power(&first)));
+    let disks_before = sections.contains(&Section::Storage).then(|| core::sample_disk_stats(&first));
+    let network_before = sections.contains(&Section::Network).then(|| core::sample_network_stats(&first));
+    let started = Instant::now();
+    let sys = if needs_system {
+        core::refreshed_system()
+    } else {
+        if disks_before.is_some() || network_before.is_some() {
+            std::thread::sleep(core::SAMPLE_INTERVAL);
+        }
+        core::sysinfo::System::new()
+    };
+
+    // Graphics are the display-class devices of the PCI list; walk the bus once for both.
+    let pci = if sections.iter().any(|s| matches!(s, Section::Graphics | Section::Pci)) {
+        core::collect_pci(ctx)
+    } else {
+        core::PciInfo::default()
+    };
+
+    let mut info = HardwareInfo::default();
+    for section in sections {
+        match section {
+            Section::System => info.static_data = core::collect_static_data(ctx, &sys),
+            Section::Cpu => {
+                info.cpu = core::collect_cpu_info(ctx, &sys);
+                info.cpu_current_speed = core::collect_cpu_speed(&sys);
+                info.current_load = core::collect_cpu_load(&sys);
+                if let Some((times, power)) = &cpu_before {
+                    info.cpu_times = core::collect_cpu_times(times, &core::sample_cpu_times(ctx));
+                    info.cpu.power = core::collect_cpu_power(power, &core::sample_cpu_power(ctx), started.elapsed());
+                }
+                info.cpu_temperature = core::collect_cpu_temp(ctx);
+            }
+            Section::Sensors => info.sensors = core::collect_sensors(ctx),
+            Section::Memory => info.memory = core::collect_memory(ctx, &sys),
+            Section::Graphics => info.graphics = core::graphics_from_pci(ctx, &pci),
+            Section::Pci => info.pci = pci.clone(),
+            Section::Storage => {
+                info.storage = core::collect_storage_with(ctx, storage);
+                if let Some(before) = &disks_before {
+                    info.disk_io = core::collect_disk_io(before, &core::sample_disk_stats(ctx), started.elapsed());
+                }
+            }
+            Section::Network => {
+                info.network = core::collect_network(ctx);
+                if let Some(before) = &network_before {
+                    let after = core::sample_network_stats(ctx);
+                    info.network_io = core::collect_network_io(before, &after, started.elapsed());
+                }
+            }
+            Section::Audio => info.audio = core::collect_audio(ctx),
+            Section::Peripherals => info.peripherals = core::collect_peripherals(ctx),
+            Section::Optical => info.optical = core::collect_optical(ctx),
+            Section::Runtime => info.runtime = core::collect_runtime(),
+            // Last in `Section::ALL`, so it sees every probe that ran above.
+            Section::Diagnostics => info.diagnostics = ctx.take_diagnostics(),
+        }
+    }
+    info
+}
+
+/// Widens a live sample into a `HardwareInfo` so both modes share one renderer.
+fn from_live(live: LiveInfo) -> HardwareInfo {
+    HardwareInfo {
+        cpu_current_speed: live.cpu_current_speed,
+        current_load: live.current_load,
+        cpu: CpuInfo {
+            power: live.cpu_power,
+            ..Default::default()
+        },
+        cpu_times: live.cpu_times,
+        cpu_temperature: live.cpu_temperature,
+        sensors: live.sensors,
+        memory: live.memory,
+        disk_io: live.disk_io,
+        network_io: live.network_io,
+        runtime: live.runtime,
+        ..Default::default()
+    }
+}
+
+/// Serializes `report` keeping only the keys owned by `sections`. Keys the
+/// report type does not have (e.g. `cpu` on a `LiveInfo`) are simply absent.
+fn to_json(report: &impl Serialize, sections: &[Section]) -> Result<String, serde_json::Error> {
+    let mut value = serde_json::to_value(report)?;
+    if let Some(map) = value.as_object_mut() {
+        map.retain(|key, _| sections.iter().any(|s| s.json_keys().contains(&key.as_str())));
+    }
+    serde_json::to_string_pretty(&value)
+}
+
+fn main() -> ExitCode {
+    let mut options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            print!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("hwinfo: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let recorder = options.record.as_ref().map(|_| Arc::new(RecordingRunner::new(Arc::new(SystemRunner))));
+    if let Some(recorder) = &recorder {
+        options.ctx = options.ctx.with_runner(recorder.clone());
+    }
+    if let Some(path) = &options.replay {
+        match CommandBundle::load(path) {
+            Ok(bundle) => options.ctx = options.ctx.with_runner(Arc::new(ReplayRunner::new(bundle))),
+            Err(e) => {
+                eprintln!("hwinfo: cannot read {}: {}", path.display(), e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let (info, json) = if options.live {
+        let live = core::collect_live_info_with(&options.ctx);
+        let json = options.json.then(|| to_json(&live, &options.sections));
+        (from_live(live), json)
+    } else {
+        let info = collect(&options.ctx, &options.sections, &options.storage);
+        let json = options.json.then(|| to_json(&info, &options.sections));
+        (info, json)
+    };
+
+    if let (Some(recorder), Some(path)) = (&recorder, &options.record) {
+        if let Err(e) = recorder.bundle().save(path) {
+            eprintln!("hwinfo: cannot write {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if let Some(json) = json {
+        match json {
+            Ok(text) => println!("{}", text),
+            Err(e) => {
+                eprintln!("hwinfo: failed to serialize report: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        print!("{}", report::render(&info, &options.sections, options.live));
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,924 @@
+//! Plain-text rendering of a `HardwareInfo`, one block per section.
+
+use std::fmt::Write;
+
+use hardware_info_core::{
+    CacheInstance, CpuCache, CpuFeatures, CpuPower, CpuTopology, FrequencyScaling, HardwareInfo, InterfaceAddress,
+    NetworkInfo, NetworkInterface, Sensor, StorageNode, UsbNode, VethPeer, WirelessInfo,
+};
+
+use crate::Section;
+
+const LABEL_WIDTH: usize = 22;
+const DASH: &str = "—";
+
+struct Report {
+    out: String,
+}
+
+impl Report {
+    fn heading(&mut self, title: &str) {
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        let _ = writeln!(self.out, "{}\n{}", title, "=".repeat(title.len()));
+    }
+
+    fn subheading(&mut self, title: &str) {
+        let _ = writeln!(self.out, "\n  {}", title);
+    }
+
+    fn row(&mut self, label: &str, value: impl ToString) {
+        let value = value.to_string();
+        let value = if value.is_empty() { DASH.to_string() } else { value };
+        let _ = writeln!(self.out, "  {:<width$} {}", label, value, width = LABEL_WIDTH);
+    }
+
+    fn empty(&mut self, message: &str) {
+        let _ = writeln!(self.out, "  {}", message);
+    }
+}
+
+/// Renders the requested sections. `live` suppresses the static fields a
+/// live sample does not carry.
+pub(crate) fn render(info: &HardwareInfo, sections: &[Section], live: bool) -> String {
+    let mut r = Report { out: String::new() };
+    for section in sections {
+        match section {
+            Section::System => system(&mut r, info),
+            Section::Cpu => cpu(&mut r, info, live),
+            Section::Sensors => sensors(&mut r, info),
+            Section::Memory => memory(&mut r, info, live),
+            Section::Graphics => graphics(&mut r, info),
+            Section::Pci => pci(&mut r, info),
+            Section::Storage => storage(&mut r, info, live),
+            Section::Network => network(&mut r, info, live),
+            Section::Audio => audio(&mut r, info),
+            Section::Peripherals => peripherals(&mut r, info),
+            Section::Optical => optical(&mut r, info),
+            Section::Runtime => runtime(&mut r, info),
+            Section::Diagnostics => diagnostics(&mut r, info),
+        }
+    }
+    r.out
+}
+
+fn system(r: &mut Report, info: &HardwareInfo) {
+    let data = &info.static_data;
+    r.heading("System");
+    r.row("Platform", &data.os.platform);
+    r.row("Distribution", &data.os.distro);
+    r.row("Release", &data.os.release);
+    r.row("Kernel", &data.os.kernel);
+    r.row("Architecture", &data.os.arch);
+    r.row("Hostname", &data.os.hostname);
+    r.row("Firmware", if data.os.uefi { "UEFI" } else { "Legacy BIOS" });
+
+    r.subheading("Motherboard");
+    r.row("Manufacturer", &data.baseboard.manufacturer);
+    r.row("Model", &data.baseboard.model);
+    r.row("Version", &data.baseboard.version);
+    r.row("Serial", &data.baseboard.serial);
+
+    r.subheading("BIOS");
+    r.row("Vendor", &data.bios.vendor);
+    r.row("Version", &data.bios.version);
+    r.row("Release Date", &data.bios.release_date);
+
+    r.subheading("MAC Addresses");
+    if data.uuid.macs.is_empty() {
+        r.empty("None detected.");
+    }
+    for mac in &data.uuid.macs {
+        r.empty(mac);
+    }
+}
+
+fn cpu(r: &mut Report, info: &HardwareInfo, live: bool) {
+    let cpu = &info.cpu;
+    r.heading("CPU");
+    if !live {
+        r.row("Name", &cpu.brand);
+        r.row("Vendor ID", &cpu.vendor);
+        r.row("Family", &cpu.family);
+        r.row("Model", &cpu.model);
+        r.row("Stepping", &cpu.stepping);
+        r.row("Physical Cores", cpu.physical_cores);
+        r.row("Logical Processors", cpu.cores);
+        for core_type in &cpu.core_types {
+            let label = match core_type.kind.as_str() {
+                "performance" => "Performance Cores",
+                "efficiency" => "Efficiency Cores",
+                _ => "Mid Cores",
+            };
+            r.row(
+                label,
+                format!(
+                    "{} (CPUs {}), {} – {}",
+                    core_type.cores,
+                    format_cpu_list(&core_type.cpus),
+                    format_ghz(core_type.speed_min),
+                    format_ghz(core_type.speed_max)
+                ),
+            );
+        }
+        r.row("L1 Data Cache", format_bytes(cpu.cache.l1d));
+        r.row("L1 Instruction Cache", format_bytes(cpu.cache.l1i));
+        r.row("L2 Cache", format_bytes(cpu.cache.l2));
+        r.row("L3 Cache", format_bytes(cpu.cache.l3));
+        r.row("Base Frequency", format_ghz(cpu.speed));
+        r.row("Max Frequency", format_ghz(cpu.speed_max));
+    }
+    let speed = &info.cpu_current_speed;
+    r.row("Current Frequency", format_ghz(speed.avg));
+    r.row("Frequency Range", format!("{} – {}", format_ghz(speed.min), format_ghz(speed.max)));
+    r.row("Load", format_percent(info.current_load.current_load));
+    r.row("Package Temperature", format_celsius(info.cpu_temperature.main));
+    r.row("Max Core Temperature", format_celsius(info.cpu_temperature.max));
+
+    let times = &info.cpu_times;
+    let breakdown = [
+        ("User", times.user),
+        ("Nice", times.nice),
+        ("System", times.system),
+        ("Idle", times.idle),
+        ("I/O Wait", times.iowait),
+        ("IRQ", times.irq),
+        ("Soft IRQ", times.softirq),
+        ("Steal", times.steal),
+    ];
+    if breakdown.iter().any(|(_, pct)| *pct > 0.0) {
+        r.subheading("CPU Time");
+        for (label, pct) in breakdown {
+            r.row(label, format_percent(pct));
+        }
+    }
+
+    cpu_power(r, &cpu.power, live);
+
+    if !live {
+        cpu_cache(r, &cpu.cache);
+        cpu_topology(r, &cpu.topology);
+        cpu_features(r, &cpu.features);
+        frequency_scaling(r, &cpu.frequency_scaling);
+    }
+
+    let load = &info.current_load.cpus;
+    if !load.is_empty() {
+        r.subheading("Per CPU");
+        for (idx, pct) in load.iter().enumerate() {
+            let speed = speed.cores.get(idx).copied().unwrap_or(0.0);
+            r.row(&format!("CPU {}", idx), format!("{} at {}", format_percent(*pct), format_ghz(speed)));
+        }
+    }
+}
+
+/// Draw of each domain, subzones indented under their package, followed
+/// outside live mode by the limits that are set.
+fn cpu_power(r: &mut Report, power: &CpuPower, live: bool) {
+    if power.domains.is_empty() {
+        return;
+    }
+    r.subheading("Power");
+    for domain in &power.domains {
+        let mut value = domain.watts.map_or(String::new(), |w| format_reading(w, "W"));
+        if !domain.enabled && !live {
+            value = format!("{} (disabled)", if value.is_empty() { DASH } else { &value });
+        }
+        let indent = if domain.zone.matches(':').count() > 1 { "  " } else { "" };
+        r.row(&format!("{}{}", indent, domain.name), value);
+        if live {
+            continue;
+        }
+        for limit in domain.limits.iter().filter(|l| l.watts > 0.0) {
+            // The names Intel's documentation uses.
+            let label = match limit.name.as_str() {
+                "long_term" => "PL1",
+                "short_term" => "PL2",
+                "peak_power" => "PL4",
+                other => other,
+            };
+            let mut value = format_reading(limit.watts, "W");
+            if let Some(window) = limit.time_window {
+                value += &format!(" over {}", format_window(window));
+            }
+            if let Some(max) = limit.max_watts {
+                value += &format!(", max {}", format_reading(max, "W"));
+            }
+            r.row(&format!("{}{} {}", indent, domain.name, label), value);
+        }
+    }
+}
+
+fn format_window(secs: f64) -> String {
+    if secs >= 1.0 {
+        format!("{:.1} s", secs)
+    } else {
+        format!("{:.1} ms", secs * 1000.0)
+    }
+}
+
+/// Per-level totals, then each distinct cache geometry with how many
+/// instances have it and the CPUs they serve.
+fn cpu_cache(r: &mut Report, cache: &CpuCache) {
+    if cache.totals.is_empty() {
+        return;
+    }
+    r.subheading("Cache");
+    for total in &cache.totals {
+        let label = format!("L{} {}", total.level, total.cache_type);
+        r.row(&label, format_bytes(total.size));
+        let mut shapes: Vec<(&CacheInstance, usize, Vec<u32>)> = Vec::new();
+        let instances = cache.instances.iter().filter(|c| (c.level, &c.cache_type) == (total.level, &total.cache_type));
+        for instance in instances {
+            let same = |s: &&mut (&CacheInstance, usize, Vec<u32>)| {
+                (s.0.size, s.0.ways, s.0.line_size) == (instance.size, instance.ways, instance.line_size)
+            };
+            match shapes.iter_mut().find(same) {
+                Some((_, count, cpus)) => {
+                    *count += 1;
+                    cpus.extend(&instance.cpus);
+                }
+                None => shapes.push((instance, 1, instance.cpus.clone())),
+            }
+        }
+        for (instance, count, mut cpus) in shapes {
+            cpus.sort_unstable();
+            cpus.dedup();
+            let size = format!("{} × {}", count, format_bytes(instance.size));
+            let geometry = format!("{}-way, {} B lines", instance.ways, instance.line_size);
+            r.row("", format!("{}, {}, CPUs {}", size, geometry, format_cpu_list(&cpus)));
+        }
+    }
+}
+
+fn cpu_features(r: &mut Report, features: &CpuFeatures) {
+    if features.flags.is_empty() && features.vulnerabilities.is_empty() {
+        return;
+    }
+    r.subheading("Features");
+    r.row("Microcode", &features.microcode);
+    let avx512 = features.flags.iter().any(|f| f == "avx512f");
+    r.row("AVX-512", if avx512 { "yes" } else { "no" });
+    if features.hypervisor {
+        r.row("Hypervisor", "running as a guest");
+    }
+    for category in &features.extensions {
+        r.row(&category.category, category.flags.join(" "));
+    }
+    if !features.vulnerabilities.is_empty() {
+        r.subheading("Vulnerabilities");
+        for vulnerability in &features.vulnerabilities {
+            r.row(&vulnerability.name, &vulnerability.detail);
+        }
+    }
+}
+
+fn frequency_scaling(r: &mut Report, scaling: &FrequencyScaling) {
+    if scaling.policies.is_empty() {
+        return;
+    }
+    r.subheading("Frequency Scaling");
+    r.row("Driver", &scaling.driver);
+    r.row("Boost", scaling.boost.map_or("", |on| if on { "enabled" } else { "disabled" }));
+    let distinct = |values: Vec<&str>| {
+        let mut values: Vec<&str> = values.into_iter().filter(|v| !v.is_empty()).collect();
+        values.sort_unstable();
+        values.dedup();
+        values.join(", ")
+    };
+    r.row("Governor", distinct(scaling.policies.iter().map(|p| p.governor.as_str()).collect()));
+    r.row("Available Governors", scaling.policies[0].available_governors.join(" "));
+    let epp = distinct(scaling.policies.iter().map(|p| p.energy_performance_preference.as_str()).collect());
+    if !epp.is_empty() {
+        r.row("Energy Preference", epp);
+    }
+    for policy in &scaling.policies {
+        let mut value = format!(
+            "CPUs {}, {} – {}",
+            format_cpu_list(&policy.cpus),
+            format_ghz(policy.hardware_min),
+            format_ghz(policy.hardware_max)
+        );
+        if (policy.scaling_min, policy.scaling_max) != (policy.hardware_min, policy.hardware_max) {
+            value += &format!(", limited to {} – {}", format_ghz(policy.scaling_min), format_ghz(policy.scaling_max));
+        }
+        if policy.base > 0.0 {
+            value += &format!(", base {}", format_ghz(policy.base));
+        }
+        r.row(&format!("Policy {}", policy.policy), value);
+    }
+}
+
+fn cpu_topology(r: &mut Report, topology: &CpuTopology) {
+    if topology.packages.is_empty() {
+        return;
+    }
+    r.subheading("Topology");
+    for package in &topology.packages {
+        r.empty(&format!("  Package {}", package.id));
+        for die in &package.dies {
+            r.empty(&format!("    Die {}", die.id));
+            for cluster in &die.clusters {
+                let cores: Vec<String> = cluster
+                    .cores
+                    .iter()
+                    .map(|core| format!("core {} [{}]", core.id, format_cpu_list(&core.threads)))
+                    .collect();
+                r.empty(&format!("      Cluster {}: {}", cluster.id, cores.join(", ")));
+            }
+        }
+    }
+    let offline: Vec<u32> = topology.cpus.iter().filter(|c| !c.online).map(|c| c.cpu).collect();
+    if !offline.is_empty() {
+        r.row("Offline CPUs", format_cpu_list(&offline));
+    }
+
+    if !topology.numa_nodes.is_empty() {
+        r.subheading("NUMA Nodes");
+        for node in &topology.numa_nodes {
+            let distances: Vec<String> = node.distances.iter().map(u32::to_string).collect();
+            r.row(
+                &format!("Node {}", node.id),
+                format!(
+                    "CPUs {}, {}, distances {}",
+                    format_cpu_list(&node.cpus),
+                    format_bytes(node.memory_total),
+                    distances.join(" ")
+                ),
+            );
+        }
+    }
+}
+
+/// `0-3,8`, the kernel's own notation.
+fn format_cpu_list(cpus: &[u32]) -> String {
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for &cpu in cpus {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == cpu => *end = cpu,
+            _ => ranges.push((cpu, cpu)),
+        }
+    }
+    let ranges: Vec<String> = ranges
+        .into_iter()
+        .map(|(start, end)| if start == end { start.to_string() } else { format!("{}-{}", start, end) })
+        .collect();
+    ranges.join(",")
+}
+
+fn sensors(r: &mut Report, info: &HardwareInfo) {
+    let sensors = &info.sensors;
+    r.heading("Sensors");
+    if sensors.chips.is_empty() && sensors.thermal_zones.is_empty() {
+        r.empty("No sensors detected.");
+        return;
+    }
+    for chip in &sensors.chips {
+        let device = if chip.device.is_empty() { &chip.hwmon } else { &chip.device };
+        r.subheading(&format!("{} ({})", chip.name, device));
+        for sensor in &chip.sensors {
+            let label = if sensor.label.is_empty() { &sensor.channel } else { &sensor.label };
+            r.row(label, sensor_reading(sensor));
+        }
+    }
+    if !sensors.thermal_zones.is_empty() {
+        r.subheading("Thermal Zones");
+        for zone in &sensors.thermal_zones {
+            let mut value = zone.zone_type.clone();
+            if let Some(temp) = zone.temperature {
+                let _ = write!(value, " {}", format_reading(temp, "°C"));
+            }
+            for trip in &zone.trip_points {
+                let _ = write!(value, ", {} at {}", trip.trip_type, format_reading(trip.temperature, "°C"));
+            }
+            r.row(&zone.zone, value);
+        }
+    }
+}
+
+/// `45.0°C (max 80.0°C, crit 100.0°C)`, with any raised alarms after it.
+fn sensor_reading(sensor: &Sensor) -> String {
+    let mut out = sensor.value.map(|v| format_reading(v, &sensor.unit)).unwrap_or_else(|| DASH.to_string());
+    let limits: Vec<String> = [("min", sensor.min), ("max", sensor.max), ("crit", sensor.crit)]
+        .into_iter()
+        .filter_map(|(name, limit)| Some(format!("{} {}", name, format_reading(limit?, &sensor.unit))))
+        .collect();
+    if !limits.is_empty() {
+        let _ = write!(out, " ({})", limits.join(", "));
+    }
+    if !sensor.alarms.is_empty() {
+        let _ = write!(out, " [{}]", sensor.alarms.join(", "));
+    }
+    out
+}
+
+fn memory(r: &mut Report, info: &HardwareInfo, live: bool) {
+    let mem = &info.memory;
+    r.heading("Memory");
+    r.row("Total", format_bytes(mem.total));
+    r.row("Used", format_bytes(mem.used));
+    r.row("Available", format_bytes(mem.available));
+    r.row("Swap Total", format_bytes(mem.swaptotal));
+    r.row("Swap Used", format_bytes(mem.swapused));
+    if live {
+        return;
+    }
+
+    r.subheading("Slots");
+    if mem.layout.is_empty() {
+        r.empty("No slot information (dmidecode needs root).");
+    }
+    for slot in &mem.layout {
+        r.row(
+            &format!("Slot {}", slot.slot),
+            format!(
+                "{} {} {} MT/s {} {}",
+                format_bytes(slot.size),
+                slot.mem_type,
+                slot.clock_speed,
+                slot.manufacturer,
+                slot.part_num
+            )
+            .trim(),
+        );
+    }
+}
+
+fn graphics(r: &mut Report, info: &HardwareInfo) {
+    r.heading("Graphics");
+    if info.graphics.controllers.is_empty() {
+        r.empty("No display controllers detected.");
+    }
+    for gpu in &info.graphics.controllers {
+        r.subheading(&gpu.model);
+        r.row("Vendor", &gpu.vendor);
+        r.row("Bus", &gpu.bus);
+        r.row("VRAM", if gpu.vram > 0 { format_bytes(gpu.vram) } else { String::new() });
+    }
+}
+
+fn pci(r: &mut Report, info: &HardwareInfo) {
+    r.heading("PCI Devices");
+    if info.pci.devices.is_empty() {
+        r.empty("No PCI devices detected.");
+    }
+    for dev in &info.pci.devices {
+        r.subheading(&format!("{} {}", dev.address, dev.device));
+        r.row("Class", if dev.subclass_name.is_empty() { &dev.class_name } else { &dev.subclass_name });
+        r.row("Vendor", &dev.vendor);
+        r.row("IDs", format!("{}:{} rev {}", dev.vendor_id, dev.device_id, dev.revision));
+        r.row("Subsystem", format!("{} {}", dev.subsystem_vendor, dev.subsystem).trim());
+        r.row("Driver", &dev.driver);
+        r.row("IOMMU Group", dev.iommu_group.map(|g| g.to_string()).unwrap_or_default());
+        if let Some(node) = dev.numa_node {
+            r.row("NUMA Node", node);
+        }
+    }
+}
+
+fn storage(r: &mut Report, info: &HardwareInfo, live: bool) {
+    r.heading("Storage");
+    if live {
+        disk_io(r, info);
+        return;
+    }
+    if info.storage.disk_layout.is_empty() {
+        r.empty("No disks detected.");
+    }
+    for disk in &info.storage.disk_layout {
+        r.subheading(format!("{} {}", disk.name, disk.model).trim());
+        r.row("Type", format!("{} {}", disk.disk_type, disk.interface_type).trim());
+        r.row("Vendor", &disk.vendor);
+        r.row("Capacity", format_bytes(disk.size));
+        r.row("Serial", &disk.serial);
+        r.row("WWN", &disk.wwn);
+        r.row("Firmware", &disk.firmware_revision);
+        r.row("Sector Size", format!("{} B logical, {} B physical", disk.logical_sector_size, disk.physical_sector_size));
+        r.row("Removable", if disk.removable { "Yes" } else { "No" });
+        if let Some(table) = &disk.partition_table {
+            if table.scheme.is_empty() {
+                r.row("Partition Table", "Unknown (no udev record, device not read)");
+            } else {
+                r.row("Partition Table", format!("{} {}", table.scheme, table.disk_id));
+            }
+            for part in &table.partitions {
+                let details = [format_bytes(part.size), part.type_name.clone(), part.label.clone(), part.flags.join(",")];
+                let details: Vec<String> = details.into_iter().filter(|d| !d.is_empty()).collect();
+                r.row(&format!("  #{}", part.number), details.join(" "));
+            }
+        }
+    }
+    if !info.storage.stack.is_empty() {
+        r.subheading("Stack");
+        for node in &info.storage.stack {
+            storage_node(r, node, 1);
+        }
+    }
+    r.subheading("Filesystems");
+    for fs in &info.storage.filesystems {
+        r.row(
+            &fs.mount,
+            format!(
+                "{} {} / {} ({})",
+                fs.fs_type,
+                format_bytes(fs.used),
+                format_bytes(fs.size),
+                format_percent(fs.usage_pct)
+            ),
+        );
+    }
+    disk_io(r, info);
+}
+
+fn network(r: &mut Report, info: &HardwareInfo, live: bool) {
+    r.heading("Network");
+    if live {
+        network_io(r, info);
+        return;
+    }
+    if info.network.interfaces.is_empty() {
+        r.empty("No network interfaces detected.");
+    }
+    for iface in &info.network.interfaces {
+        r.subheading(&format!("{} ({})", iface.iface, iface.kind));
+        r.row("IPv4 Addresses", addresses(&iface.ip4));
+        r.row("IPv6 Addresses", addresses(&iface.ip6));
+        r.row("MAC Address", &iface.mac);
+        r.row("MTU", iface.mtu);
+        let carrier = match iface.carrier {
+            Some(true) => ", carrier",
+            Some(false) => ", no carrier",
+            None => "",
+        };
+        r.row("State", format!("{}{}", iface.operstate, carrier));
+        if let Some(speed) = iface.speed_mbps {
+            let duplex = if iface.duplex.is_empty() { String::new() } else { format!(" {} duplex", iface.duplex) };
+            r.row("Link Speed", format!("{} Mb/s{}", speed, duplex));
+        }
+        r.row("Driver", &iface.driver);
+        if !iface.parent_device.is_empty() {
+            r.row("Device", format!("{} {}", iface.parent_bus, iface.parent_device));
+        }
+        if let Some(wifi) = &iface.wireless {
+            wireless(r, wifi);
+        }
+        if !iface.master.is_empty() {
+            r.row("Member Of", &iface.master);
+        }
+        if let Some(bond) = &iface.bond {
+            r.row("Bond", format!("{}, active {}, MII {}", bond.mode, bond.active_slave, bond.mii_status));
+        }
+        if let Some(vlan) = &iface.vlan {
+            r.row("VLAN", format!("{} on {}", vlan.id, vlan.parent));
+        }
+        if let Some(peer) = &iface.veth_peer {
+            r.row("Peer", veth_peer(peer));
+        }
+    }
+    topology(r, &info.network);
+    routing(r, info);
+    network_io(r, info);
+}
+
+fn veth_peer(peer: &VethPeer) -> String {
+    match (peer.name.as_str(), peer.netns_id) {
+        (name, None) if !name.is_empty() => name.to_string(),
+        (_, Some(_)) if !peer.netns.is_empty() => format!("ifindex {} in {}", peer.ifindex, peer.netns),
+        (_, Some(id)) => format!("ifindex {} in namespace {}", peer.ifindex, id),
+        _ => format!("ifindex {}", peer.ifindex),
+    }
+}
+
+/// Interfaces each one is built on: bridge ports, bond slaves, a VLAN's
+/// parent.
+fn lower_interfaces(iface: &NetworkInterface) -> Vec<&str> {
+    let mut lower: Vec<&str> = iface.ports.iter().map(String::as_str).collect();
+    lower.extend(iface.vlan.as_ref().map(|v| v.parent.as_str()));
+    lower
+}
+
+/// Bridges and bonds down to the NICs beneath them, when there are any.
+fn topology(r: &mut Report, network: &NetworkInfo) {
+    let interfaces = &network.interfaces;
+    if interfaces.iter().all(|i| lower_interfaces(i).is_empty()) {
+        return;
+    }
+    r.subheading("Topology");
+    let lower: Vec<&str> = interfaces.iter().flat_map(lower_interfaces).collect();
+    for root in interfaces.iter().filter(|i| !lower.contains(&i.iface.as_str())) {
+        topology_node(r, interfaces, root, 1);
+    }
+    if !network.namespaces.is_empty() {
+        let namespaces: Vec<String> = network
+            .namespaces
+            .iter()
+            .map(|ns| match ns.id {
+                Some(id) => format!("{} (id {})", ns.name, id),
+                None => ns.name.clone(),
+            })
+            .collect();
+        r.row("Network Namespaces", namespaces.join(", "));
+    }
+}
+
+fn topology_node(r: &mut Report, interfaces: &[NetworkInterface], iface: &NetworkInterface, depth: usize) {
+    let mut line = format!("{}{} [{}]", "  ".repeat(depth), iface.iface, iface.kind);
+    if let Some(vlan) = &iface.vlan {
+        let _ = write!(line, " id {}", vlan.id);
+    }
+    if let Some(bond) = &iface.bond {
+        let _ = write!(line, " {}", bond.mode);
+    }
+    if let Some(peer) = &iface.veth_peer {
+        let _ = write!(line, " peer {}", veth_peer(peer));
+    }
+    r.empty(&line);
+    // Guards against a malformed tree that loops.
+    if depth > 8 {
+        return;
+    }
+    for name in lower_interfaces(iface) {
+        if let Some(child) = interfaces.iter().find(|i| i.iface == name) {
+            topology_node(r, interfaces, child, depth + 1);
+        }
+    }
+}
+
+fn routing(r: &mut Report, info: &HardwareInfo) {
+    let network = &info.network;
+    r.subheading("Routing");
+    for (label, gateway) in [("IPv4 Gateway", &network.ipv4_gateway), ("IPv6 Gateway", &network.ipv6_gateway)] {
+        match gateway {
+            Some(route) => r.row(label, format!("{} via {}", route.gateway, route.iface)),
+            None => r.row(label, ""),
+        }
+    }
+    for route in network.routes.iter().filter(|route| route.table == "main") {
+        let via = if route.gateway.is_empty() { String::new() } else { format!(" via {}", route.gateway) };
+        r.row(&route.destination, format!("dev {}{} metric {}", route.iface, via, route.metric));
+    }
+
+    let dns = &network.dns;
+    r.subheading("DNS");
+    r.row("Servers", dns.servers.join(", "));
+    r.row("Search Domains", dns.search_domains.join(" "));
+    if !dns.stub_resolver.is_empty() {
+        r.row("Stub Resolver", &dns.stub_resolver);
+    }
+}
+
+fn wireless(r: &mut Report, wifi: &WirelessInfo) {
+    if wifi.ssid.is_empty() {
+        r.row("Wi-Fi", "Not connected");
+    } else {
+        r.row("Wi-Fi", format!("{} ({})", wifi.ssid, wifi.bssid));
+    }
+    if let Some(freq) = wifi.frequency_mhz {
+        let mut channel = format!("{} MHz, {}", freq, wifi.band);
+        if let Some(number) = wifi.channel {
+            let _ = write!(channel, ", channel {}", number);
+        }
+        if let Some(width) = wifi.channel_width_mhz {
+            let _ = write!(channel, ", {} MHz wide", width);
+        }
+        r.row("Channel", channel);
+    }
+    let mut signal = wifi.signal_dbm.map(|s| format!("{} dBm", s)).unwrap_or_default();
+    if let Some(noise) = wifi.noise_dbm {
+        let _ = write!(signal, ", noise {} dBm", noise);
+    }
+    if let Some(quality) = wifi.link_quality_pct {
+        let _ = write!(signal, ", quality {}", format_percent(quality));
+    }
+    r.row("Signal", signal.trim_start_matches(", "));
+    if let (Some(tx), Some(rx)) = (wifi.tx_bitrate_mbps, wifi.rx_bitrate_mbps) {
+        r.row("Bitrate", format!("tx {} Mb/s, rx {} Mb/s", tx, rx));
+    }
+    r.row("Radio", format!("{} {}", wifi.phy, wifi.supported_bands.join(", ")).trim());
+    r.row("Regulatory Domain", &wifi.regulatory_domain);
+}
+
+fn network_io(r: &mut Report, info: &HardwareInfo) {
+    r.subheading("Traffic");
+    if info.network_io.is_empty() {
+        r.empty("No network traffic sampled.");
+    }
+    for io in &info.network_io {
+        let mut line = format!(
+            "rx {}/s ({:.0} pkt/s)  tx {}/s ({:.0} pkt/s)  total rx {} tx {}",
+            format_bytes(io.rx_bytes_per_sec as u64),
+            io.rx_packets_per_sec,
+            format_bytes(io.tx_bytes_per_sec as u64),
+            io.tx_packets_per_sec,
+            format_bytes(io.rx_bytes_total),
+            format_bytes(io.tx_bytes_total)
+        );
+        let errors = io.rx_errors + io.tx_errors;
+        let dropped = io.rx_dropped + io.tx_dropped;
+        if errors + dropped > 0 {
+            let _ = write!(line, "  {} errors, {} dropped", errors, dropped);
+        }
+        r.row(&io.iface, line);
+    }
+}
+
+fn addresses(list: &[InterfaceAddress]) -> String {
+    list.iter()
+        .map(|a| format!("{}/{}", a.address, a.prefix_len))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn audio(r: &mut Report, info: &HardwareInfo) {
+    r.heading("Audio");
+    if info.audio.devices.is_empty() {
+        r.empty("No audio devices detected.");
+    }
+    for dev in &info.audio.devices {
+        r.row(&dev.name, format!("{} ({})", dev.manufacturer, dev.status));
+    }
+}
+
+fn peripherals(r: &mut Report, info: &HardwareInfo) {
+    r.heading("Peripherals");
+    if info.peripherals.usb_devices.is_empty() {
+        r.empty("No USB devices detected.");
+    }
+    for usb in &info.peripherals.usb_devices {
+        r.row(
+            &format!("{}:{}", usb.bus, usb.device),
+            format!("{}:{} {} {}", usb.vendor_id, usb.product_id, usb.vendor, usb.name),
+        );
+    }
+
+    if !info.peripherals.usb_tree.is_empty() {
+        r.subheading("Topology");
+        for node in &info.peripherals.usb_tree {
+            usb_node(r, node, 1);
+        }
+    }
+}
+
+fn usb_node(r: &mut Report, node: &UsbNode, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let dev = &node.device;
+    let port = if node.port > 0 { format!("port {}: ", node.port) } else { String::new() };
+    let downgraded = if node.speed_downgraded { " (USB 3 device at USB 2 speed)" } else { "" };
+    r.empty(&format!(
+        "{}{}{} {} [{}]{}",
+        indent,
+        port,
+        dev.vendor,
+        dev.name,
+        format_usb_speed(dev.speed_mbps),
+        downgraded
+    ));
+    for iface in &node.interfaces {
+        let driver = if iface.driver.is_empty() { DASH } else { &iface.driver };
+        r.empty(&format!("{}  - if {}: {} ({})", indent, iface.number, iface.class_name, driver));
+    }
+    for child in &node.children {
+        usb_node(r, child, depth + 1);
+    }
+}
+
+fn disk_io(r: &mut Report, info: &HardwareInfo) {
+    r.subheading("I/O");
+    if info.disk_io.is_empty() {
+        r.empty("No disk activity sampled.");
+    }
+    for io in &info.disk_io {
+        r.row(
+            &io.name,
+            format!(
+                "read {}/s ({:.0} IOPS, {:.1} ms)  write {}/s ({:.0} IOPS, {:.1} ms)  queue {:.2}  busy {}",
+                format_bytes(io.read_bytes_per_sec as u64),
+                io.read_iops,
+                io.read_latency_ms,
+                format_bytes(io.write_bytes_per_sec as u64),
+                io.write_iops,
+                io.write_latency_ms,
+                io.queue_depth,
+                format_percent(io.utilization_pct)
+            ),
+        );
+    }
+}
+
+fn storage_node(r: &mut Report, node: &StorageNode, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let name = if node.mapper_name.is_empty() { &node.name } else { &node.mapper_name };
+    let mut line = format!("{}{} [{}] {}", indent, name, node.kind, format_bytes(node.size));
+    if let Some(raid) = &node.raid {
+        let _ = write!(line, " {} {}", raid.level, raid.array_state);
+        if raid.degraded > 0 {
+            let _ = write!(line, ", degraded ({} of {} missing)", raid.degraded, raid.raid_disks);
+        }
+        if let Some(progress) = raid.sync_progress {
+            let _ = write!(line, ", {} {:.1}%", raid.sync_action, progress);
+        }
+    }
+    if !node.mountpoints.is_empty() {
+        let _ = write!(line, " {} on {}", node.fs_type, node.mountpoints.join(", "));
+    }
+    r.empty(&line);
+    if let Some(raid) = &node.raid {
+        for member in &raid.members {
+            let slot = member.slot.map(|s| format!("slot {}", s)).unwrap_or_else(|| "spare".to_string());
+            r.empty(&format!("{}  - {}: {} ({})", indent, slot, member.name, member.state));
+        }
+    }
+    for child in &node.children {
+        storage_node(r, child, depth + 1);
+    }
+}
+
+fn optical(r: &mut Report, info: &HardwareInfo) {
+    r.heading("Optical Drives");
+    if info.optical.devices.is_empty() {
+        r.empty("No optical drives detected.");
+    }
+    for dev in &info.optical.devices {
+        r.row(&dev.name, format!("{} {}", dev.vendor, dev.model));
+    }
+}
+
+fn runtime(r: &mut Report, info: &HardwareInfo) {
+    r.heading("Runtime");
+    let uptime = info.runtime.uptime;
+    r.row("Uptime", format!("{}h {}m", uptime / 3600, (uptime % 3600) / 60));
+    r.row("Current Time", info.runtime.current);
+}
+
+fn diagnostics(r: &mut Report, info: &HardwareInfo) {
+    r.heading("Diagnostics");
+    if info.diagnostics.probes.is_empty() {
+        r.empty("No probes ran.");
+    }
+    for report in &info.diagnostics.probes {
+        let outcome = match &report.error {
+            Some(err) => err.to_string(),
+            None => "ok".to_string(),
+        };
+        r.row(
+            &report.probe,
+            format!("{} ({}, {:.1} ms)", outcome, report.source, report.duration_ms),
+        );
+    }
+}
+
+// ——— Formatting (mirrors src/utils.ts) ———
+
+fn format_bytes(value: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut size = value as f64;
+    let mut idx = 0;
+    while size >= 1024.0 && idx < UNITS.len() - 1 {
+        size /= 1024.0;
+        idx += 1;
+    }
+    format!("{:.*} {}", if idx > 1 { 1 } else { 0 }, size, UNITS[idx])
+}
+
+fn format_percent(value: f64) -> String {
+    if value.is_nan() {
+        return DASH.to_string();
+    }
+    format!("{:.1}%", value)
+}
+
+fn format_ghz(value: f64) -> String {
+    if value.is_nan() || value <= 0.0 {
+        return DASH.to_string();
+    }
+    format!("{:.2} GHz", value)
+}
+
+fn format_usb_speed(mbps: f64) -> String {
+    if mbps <= 0.0 {
+        return DASH.to_string();
+    }
+    if mbps >= 1000.0 {
+        format!("{} Gbit/s", mbps / 1000.0)
+    } else {
+        format!("{} Mbit/s", mbps)
+    }
+}
+
+fn format_reading(value: f64, unit: &str) -> String {
+    match unit {
+        "°C" => format!("{:.1}°C", value),
+        "RPM" => format!("{:.0} RPM", value),
+        "V" => format!("{:.3} V", value),
+        "A" => format!("{:.2} A", value),
+        _ => format!("{:.1} {}", value, unit),
+    }
+}
+
+fn format_celsius(value: f64) -> String {
+    if value <= 0.0 {
+        return "N/A".to_string();
+    }
+    format!("{:.1}°C", value)
+}
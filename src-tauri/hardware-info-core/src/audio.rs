@@ -0,0 +1,51 @@
+use crate::context::ProbeContext;
+use crate::types::{AudioInfo, ProbeError};
+#[cfg(target_os = "linux")]
+use crate::types::AudioDevice;
+
+/// Sound cards, from `aplay -l` on Linux.
+#[cfg(target_os = "linux")]
+pub fn collect_audio(ctx: &ProbeContext) -> AudioInfo {
+    // `aplay -l` exits non-zero when there are no cards; that is an empty
+    // list, not a failure.
+    let devices = ctx.probe("audio", "aplay -l", || {
+        let out = ctx.run("aplay", &["-l"]).map_err(|e| ProbeError::from_spawn("aplay", &e))?;
+        Ok(parse_aplay_cards(&out.stdout))
+    });
+
+    AudioInfo { devices }
+}
+
+#[cfg(target_os = "linux")]
+fn parse_aplay_cards(text: &str) -> Vec<AudioDevice> {
+    let mut devices = Vec::new();
+
+    for line in text.lines() {
+        if line.starts_with("card") {
+            // card 0: PCH [HDA Intel PCH], device 0: ALC3246 Analog...
+            let parts: Vec<&str> = line.splitn(4, ':').collect();
+            if parts.len() >= 2 {
+                let name_part = parts[1].trim();
+                let name = name_part.split('[').next().unwrap_or(name_part).trim().to_string();
+                let manufacturer = if name_part.contains('[') {
+                    name_part.split('[').nth(1).and_then(|s| s.split(']').next()).unwrap_or("Unknown").to_string()
+                } else {
+                    "Unknown".to_string()
+                };
+
+                devices.push(AudioDevice {
+                    name,
+                    manufacturer,
+                    status: "Active".to_string(),
+                });
+            }
+        }
+    }
+
+    devices
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn collect_audio(ctx: &ProbeContext) -> AudioInfo {
+    ctx.probe("audio", "none", || Err(ProbeError::unsupported()))
+}
@@ -0,0 +1,190 @@
+//! External command execution behind a swappable [`CommandRunner`].
+//!
+//! Probes that shell out (`aplay`, `dmidecode`, `ip`, `iw`) go through the
+//! runner on their [`ProbeContext`](crate::ProbeContext) instead of calling
+//! `std::process::Command` directly. [`RecordingRunner`] captures every
+//! invocation into a [`CommandBundle`] that can be saved next to a
+//! sysfs/procfs capture; [`ReplayRunner`] plays it back so a customer's
+//! report can be reproduced offline.
+
+use std::fmt::Debug;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+/// What a finished command printed and how it exited.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandOutput {
+    pub stdout: String,
+    pub stderr: String,
+    /// `None` when the process was killed by a signal.
+    pub exit_code: Option<i32>,
+}
+
+impl CommandOutput {
+    pub fn success(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+}
+
+/// Runs external programs on behalf of the probes.
+pub trait CommandRunner: Debug + Send + Sync {
+    /// Runs `program` with `args` to completion. An `Err` means the program
+    /// could not be started at all (typically [`io::ErrorKind::NotFound`]).
+    fn run(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput>;
+}
+
+/// Runs commands on the local machine.
+#[derive(Debug, Default)]
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn run(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput> {
+        let out = std::process::Command::new(program).args(args).output()?;
+        Ok(CommandOutput {
+            stdout: String::from_utf8_lossy(&out.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&out.stderr).to_string(),
+            exit_code: out.status.code(),
+        })
+    }
+}
+
+// ——— Bundles ———
+
+/// Why a recorded command could not be started.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SpawnError {
+    NotFound,
+    PermissionDenied,
+    Other,
+}
+
+impl SpawnError {
+    fn from_io(err: &io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::NotFound => SpawnError::NotFound,
+            io::ErrorKind::PermissionDenied => SpawnError::PermissionDenied,
+            _ => SpawnError::Other,
+        }
+    }
+
+    fn to_io(self, program: &str) -> io::Error {
+        let kind = match self {
+            SpawnError::NotFound => io::ErrorKind::NotFound,
+            SpawnError::PermissionDenied => io::ErrorKind::PermissionDenied,
+            SpawnError::Other => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, format!("{} (replayed)", program))
+    }
+}
+
+/// One invocation and its outcome. Exactly one of `output` and
+/// `spawn_error` is set.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<CommandOutput>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spawn_error: Option<SpawnError>,
+}
+
+impl RecordedCommand {
+    fn matches(&self, program: &str, args: &[&str]) -> bool {
+        self.program == program && self.args.iter().map(String::as_str).eq(args.iter().copied())
+    }
+}
+
+/// A set of recorded invocations, stored as JSON.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandBundle {
+    pub commands: Vec<RecordedCommand>,
+}
+
+impl CommandBundle {
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        serde_json::from_str(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let text = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        std::fs::write(path, text)
+    }
+}
+
+// ——— Record / replay ———
+
+/// Forwards to another runner and remembers every invocation.
+#[derive(Debug)]
+pub struct RecordingRunner {
+    inner: Arc<dyn CommandRunner>,
+    bundle: Mutex<CommandBundle>,
+}
+
+impl RecordingRunner {
+    pub fn new(inner: Arc<dyn CommandRunner>) -> Self {
+        RecordingRunner {
+            inner,
+            bundle: Mutex::new(CommandBundle::default()),
+        }
+    }
+
+    /// Everything recorded so far.
+    pub fn bundle(&self) -> CommandBundle {
+        self.bundle.lock().map(|b| b.clone()).unwrap_or_default()
+    }
+}
+
+impl CommandRunner for RecordingRunner {
+    fn run(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput> {
+        let result = self.inner.run(program, args);
+        let entry = RecordedCommand {
+            program: program.to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+            output: result.as_ref().ok().cloned(),
+            spawn_error: result.as_ref().err().map(SpawnError::from_io),
+        };
+        if let Ok(mut bundle) = self.bundle.lock() {
+            bundle.commands.push(entry);
+        }
+        result
+    }
+}
+
+/// Answers from a [`CommandBundle`] without running anything. Commands that
+/// were never recorded behave as if the tool is not installed.
+#[derive(Debug, Default)]
+pub struct ReplayRunner {
+    bundle: CommandBundle,
+}
+
+impl ReplayRunner {
+    pub fn new(bundle: CommandBundle) -> Self {
+        ReplayRunner { bundle }
+    }
+}
+
+impl CommandRunner for ReplayRunner {
+    fn run(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput> {
+        let entry = self
+            .bundle
+            .commands
+            .iter()
+            .find(|c| c.matches(program, args))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{} was not recorded", program)))?;
+
+        match (&entry.output, entry.spawn_error) {
+            (Some(output), _) => Ok(output.clone()),
+            (None, Some(err)) => Err(err.to_io(program)),
+            (None, None) => Err(SpawnError::Other.to_io(program)),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crate::command::{CommandOutput, CommandRunner, SystemRunner};
+use crate::types::{Diagnostics, ProbeReport};
+
+/// Where the Linux probes look for kernel-provided and system
+/// configuration files.
+///
+/// The default points at the live `/sys`, `/proc`, `/dev`, `/etc` and
+/// `/run`. Pointing the roots at an extracted capture of another machine
+/// (see [`ProbeContext::from_root`]) makes every file-based reader return
+/// that machine's data instead, which is how bug reports are reproduced and
+/// how the fixture tests run.
+///
+/// External tools are run through [`ProbeContext::runner`], so a recorded
+/// [`CommandBundle`](crate::CommandBundle) can stand in for them the same
+/// way.
+///
+/// Every probe run against the context leaves a [`ProbeReport`]; collect
+/// them with [`ProbeContext::take_diagnostics`]. Clones share the same
+/// reports.
+///
+/// Values that sysinfo reads on its own (CPU brand and usage, memory totals,
+/// mounted filesystems, OS release) still come from the running system.
+#[derive(Clone, Debug)]
+pub struct ProbeContext {
+    pub sysfs_root: PathBuf,
+    pub procfs_root: PathBuf,
+    pub devfs_root: PathBuf,
+    pub etc_root: PathBuf,
+    pub run_root: PathBuf,
+    /// Directories searched, in order, for the `pci.ids` and `usb.ids` name
+    /// databases. A trimmed copy bundled with the crate is used when none
+    /// has them.
+    pub hwdata_dirs: Vec<PathBuf>,
+    pub runner: Arc<dyn CommandRunner>,
+    reports: Arc<Mutex<Vec<ProbeReport>>>,
+}
+
+impl Default for ProbeContext {
+    fn default() -> Self {
+        ProbeContext {
+            sysfs_root: PathBuf::from("/sys"),
+            procfs_root: PathBuf::from("/proc"),
+            devfs_root: PathBuf::from("/dev"),
+            etc_root: PathBuf::from("/etc"),
+            run_root: PathBuf::from("/run"),
+            hwdata_dirs: vec![PathBuf::from("/usr/share/hwdata"), PathBuf::from("/usr/share/misc")],
+            runner: Arc::new(SystemRunner),
+            reports: Arc::default(),
+        }
+    }
+}
+
+impl ProbeContext {
+    /// Context for a captured filesystem tree laid out as `<root>/sys`,
+    /// `<root>/proc`, `<root>/dev`, `<root>/etc` and `<root>/run`, with name
+    /// databases under `<root>/usr/share/hwdata` or `<root>/usr/share/misc`.
+    pub fn from_root(root: impl AsRef<Path>) -> Self {
+        let root = root.as_ref();
+        ProbeContext {
+            sysfs_root: root.join("sys"),
+            procfs_root: root.join("proc"),
+            devfs_root: root.join("dev"),
+            etc_root: root.join("etc"),
+            run_root: root.join("run"),
+            hwdata_dirs: vec![root.join("usr/share/hwdata"), root.join("usr/share/misc")],
+            runner: Arc::new(SystemRunner),
+            reports: Arc::default(),
+        }
+    }
+
+    /// Replaces the command runner, e.g. with a
+    /// [`ReplayRunner`](crate::ReplayRunner).
+    pub fn with_runner(mut self, runner: Arc<dyn CommandRunner>) -> Self {
+        self.runner = runner;
+        self
+    }
+
+    /// Same roots and runner, with probe reports kept apart from this
+    /// context's and dropped with the copy. For the first of two samples,
+    /// whose probes run again for the second.
+    pub fn unrecorded(&self) -> Self {
+        ProbeContext {
+            reports: Arc::default(),
+            ..self.clone()
+        }
+    }
+
+    /// Resolves a path relative to the sysfs root, e.g. `"class/dmi/id/bios_vendor"`.
+    pub fn sys_path(&self, rel: impl AsRef<Path>) -> PathBuf {
+        self.sysfs_root.join(rel)
+    }
+
+    /// Resolves a path relative to the procfs root, e.g. `"cpuinfo"`.
+    pub fn proc_path(&self, rel: impl AsRef<Path>) -> PathBuf {
+        self.procfs_root.join(rel)
+    }
+
+    /// Resolves a path relative to the devfs root, e.g. `"sr0"`.
+    pub fn dev_path(&self, rel: impl AsRef<Path>) -> PathBuf {
+        self.devfs_root.join(rel)
+    }
+
+    /// Resolves a path relative to the `/etc` root, e.g. `"resolv.conf"`.
+    pub fn etc_path(&self, rel: impl AsRef<Path>) -> PathBuf {
+        self.etc_root.join(rel)
+    }
+
+    /// Resolves a path relative to the `/run` root, e.g.
+    /// `"systemd/resolve/resolv.conf"`.
+    pub fn run_path(&self, rel: impl AsRef<Path>) -> PathBuf {
+        self.run_root.join(rel)
+    }
+
+    /// Runs an external program through [`ProbeContext::runner`].
+    pub fn run(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput> {
+        self.runner.run(program, args)
+    }
+
+    /// Returns and clears the reports of every probe run so far.
+    pub fn take_diagnostics(&self) -> Diagnostics {
+        let probes = self.reports.lock().map(|mut r| std::mem::take(&mut *r)).unwrap_or_default();
+        Diagnostics { probes }
+    }
+
+    /// Whether the roots are the running system's own, so that what sysinfo
+    /// reads describes the same machine as the files.
+    pub(crate) fn is_live(&self) -> bool {
+        self.sysfs_root == Path::new("/sys") && self.procfs_root == Path::new("/proc")
+    }
+
+    pub(crate) fn record(&self, report: ProbeReport) {
+        if let Ok(mut reports) = self.reports.lock() {
+            reports.push(report);
+        }
+    }
+}
@@ -0,0 +1,508 @@
+#[cfg(target_os = "windows")]
+use sysinfo::Components;
+use sysinfo::System;
+
+use crate::context::ProbeContext;
+#[cfg(target_os = "linux")]
+use crate::{cpu_features, cpu_topology, cpufreq};
+#[cfg(target_os = "linux")]
+use crate::read_sysfs_file;
+#[cfg(target_os = "linux")]
+use crate::types::{CacheInstance, CacheTotal};
+use crate::types::{
+    CpuCache, CpuCoreType, CpuCurrentSpeed, CpuFeatures, CpuInfo, CpuPower, CpuTemperature, CpuTimes, CpuTopology,
+    CpuVulnerability, CurrentLoad, FrequencyScaling, ProbeError,
+};
+
+/// Processor identification, core counts, clock speeds, caches, topology and
+/// feature flags.
+pub fn collect_cpu_info(ctx: &ProbeContext, sys: &System) -> CpuInfo {
+    let cpus = sys.cpus();
+    let first = cpus.first();
+
+    let brand = first.map(|c| c.brand().to_string()).unwrap_or_default();
+    let vendor = first.map(|c| c.vendor_id().to_string()).unwrap_or_default();
+    let physical_cores = sys.physical_core_count().unwrap_or(0);
+    let cores = cpus.len();
+
+    // Frequencies
+    let freqs: Vec<f64> = cpus.iter().map(|c| c.frequency() as f64 / 1000.0).collect();
+    let average = freqs.iter().copied().sum::<f64>() / freqs.len().max(1) as f64;
+    let observed_max = freqs.iter().copied().fold(0.0_f64, f64::max);
+
+    let cache = ctx.probe("cpu.cache", cache_source(ctx), || read_cache(ctx));
+
+    // CPU family/model/stepping
+    let (family, model, stepping) = ctx.probe("cpu.ids", cpu_ids_source(ctx), || read_cpu_ids(ctx));
+
+    let topology = ctx.probe("cpu.topology", topology_source(ctx), || read_topology(ctx));
+    let core_types = ctx.probe("cpu.hybrid", topology_source(ctx), || read_core_types(ctx, &topology));
+    let mut features = ctx.probe("cpu.features", cpu_ids_source(ctx), || read_features(ctx));
+    features.vulnerabilities =
+        ctx.probe("cpu.vulnerabilities", vulnerabilities_source(ctx), || read_vulnerabilities(ctx));
+    let frequency_scaling =
+        ctx.probe("cpu.frequency", frequency_scaling_source(ctx), || read_frequency_scaling(ctx));
+    // Current frequencies are poor stand-ins for the base clock and the
+    // hardware limit; use what cpufreq reports where it does.
+    let speed = frequency_scaling.policies.iter().map(|p| p.base).fold(0.0_f64, f64::max);
+    let speed = if speed > 0.0 { speed } else { average };
+    let speed_max = rated_speed_max(ctx, &topology).unwrap_or(observed_max);
+
+    CpuInfo {
+        brand,
+        vendor,
+        family,
+        model,
+        stepping,
+        physical_cores,
+        cores,
+        speed,
+        speed_max,
+        cache,
+        topology,
+        core_types,
+        features,
+        frequency_scaling,
+        // Needs two samples; the caller times them.
+        power: CpuPower::default(),
+    }
+}
+
+/// Average, minimum and maximum current frequency across logical CPUs, in GHz.
+pub fn collect_cpu_speed(sys: &System) -> CpuCurrentSpeed {
+    let cpus = sys.cpus();
+    let freqs: Vec<f64> = cpus.iter().map(|c| c.frequency() as f64 / 1000.0).collect();
+    let len = freqs.len().max(1) as f64;
+    CpuCurrentSpeed {
+        avg: freqs.iter().copied().sum::<f64>() / len,
+        min: freqs.iter().copied().fold(f64::MAX, f64::min),
+        max: freqs.iter().copied().fold(0.0_f64, f64::max),
+        cores: freqs,
+    }
+}
+
+/// Average CPU usage across logical CPUs, and that of each, in percent.
+///
+/// `sys` must have had its CPUs refreshed twice with a delay in between
+/// (see [`crate::refreshed_system`]), otherwise usage reads as zero.
+pub fn collect_cpu_load(sys: &System) -> CurrentLoad {
+    let cpus: Vec<f64> = sys.cpus().iter().map(|c| c.cpu_usage() as f64).collect();
+    let avg = cpus.iter().sum::<f64>() / cpus.len().max(1) as f64;
+    CurrentLoad { current_load: avg, cpus }
+}
+
+/// Cumulative CPU time counters at one instant. Two samples taken apart
+/// give the breakdown through [`collect_cpu_times`].
+#[derive(Clone, Debug, Default)]
+pub struct CpuTimesSample {
+    /// user, nice, system, idle, iowait, irq, softirq, steal.
+    jiffies: [u64; 8],
+}
+
+/// Reads the counters, from `/proc/stat` on Linux.
+pub fn sample_cpu_times(ctx: &ProbeContext) -> CpuTimesSample {
+    ctx.probe("cpu.times", cpu_times_source(ctx), || read_cpu_times(ctx))
+}
+
+/// Share of each kind of CPU time between two samples. All zero when no
+/// time passed.
+pub fn collect_cpu_times(before: &CpuTimesSample, after: &CpuTimesSample) -> CpuTimes {
+    // Saturating: the counters of an offlined CPU drop out of the sum.
+    let delta: Vec<u64> = after.jiffies.iter().zip(&before.jiffies).map(|(a, b)| a.saturating_sub(*b)).collect();
+    let total: u64 = delta.iter().sum();
+    if total == 0 {
+        return CpuTimes::default();
+    }
+    let pct = |i: usize| delta[i] as f64 / total as f64 * 100.0;
+    CpuTimes {
+        user: pct(0),
+        nice: pct(1),
+        system: pct(2),
+        idle: pct(3),
+        iowait: pct(4),
+        irq: pct(5),
+        softirq: pct(6),
+        steal: pct(7),
+    }
+}
+
+/// Package and hottest-core temperature, in °C.
+pub fn collect_cpu_temp(ctx: &ProbeContext) -> CpuTemperature {
+    ctx.probe("cpu.temperature", cpu_temp_source(ctx), || read_cpu_temp(ctx))
+}
+
+// ——— Platform-specific helpers (Linux) ———
+
+/// Labels of the whole-package reading, best first: coretemp's
+/// `Package id N`, then AMD's, as k10temp and zenpower name them. Tctl can
+/// carry a fan-control offset that Tdie does not.
+#[cfg(target_os = "linux")]
+const PACKAGE_TEMP_LABELS: [&str; 3] = ["Package id", "Tdie", "Tctl"];
+
+/// Thermal zones that track the CPU, for machines with no such hwmon chip:
+/// Intel's package sensor, and the SoC zone of ARM boards.
+#[cfg(target_os = "linux")]
+const CPU_THERMAL_ZONES: [&str; 3] = ["x86_pkg_temp", "cpu-thermal", "cpu_thermal"];
+
+#[cfg(target_os = "linux")]
+fn cpu_temp_source(ctx: &ProbeContext) -> String {
+    format!("{}, {}", ctx.sys_path("class/hwmon").display(), ctx.sys_path("class/thermal").display())
+}
+
+/// The readings of the hwmon chips with a package label, whatever the
+/// driver; failing those, the CPU's thermal zones.
+#[cfg(target_os = "linux")]
+fn read_cpu_temp(ctx: &ProbeContext) -> Result<CpuTemperature, ProbeError> {
+    let is_package = |label: &str| PACKAGE_TEMP_LABELS.iter().position(|wanted| label.starts_with(wanted));
+    let chips = crate::sensors::read_chips(ctx).unwrap_or_default();
+    let readings: Vec<(&str, f64)> = chips
+        .iter()
+        .filter(|chip| chip.sensors.iter().any(|s| s.kind == "temperature" && is_package(&s.label).is_some()))
+        .flat_map(|chip| &chip.sensors)
+        .filter(|sensor| sensor.kind == "temperature")
+        .filter_map(|sensor| Some((sensor.label.as_str(), sensor.value?)))
+        .collect();
+    let main = readings
+        .iter()
+        .filter_map(|&(label, temp)| Some((is_package(label)?, temp)))
+        .min_by_key(|&(rank, _)| rank)
+        .map(|(_, temp)| temp);
+    if let Some(main) = main {
+        let max = readings.iter().map(|&(_, temp)| temp).fold(main, f64::max);
+        return Ok(CpuTemperature { main, max });
+    }
+
+    let zones = crate::sensors::read_thermal_zones(ctx).unwrap_or_default();
+    let temps: Vec<f64> = zones
+        .iter()
+        .filter(|zone| CPU_THERMAL_ZONES.contains(&zone.zone_type.as_str()))
+        .filter_map(|zone| zone.temperature)
+        .collect();
+    let Some(&main) = temps.first() else {
+        return Err(no_sensors_error(ctx));
+    };
+    Ok(CpuTemperature { main, max: temps.iter().copied().fold(main, f64::max) })
+}
+
+#[cfg(target_os = "linux")]
+fn cache_source(ctx: &ProbeContext) -> String {
+    ctx.sys_path("devices/system/cpu").display().to_string()
+}
+
+/// Every `cpu*/cache/index*` entry, keeping one instance per set of
+/// CPUs sharing it.
+#[cfg(target_os = "linux")]
+fn read_cache(ctx: &ProbeContext) -> Result<CpuCache, ProbeError> {
+    let cpu_dir = ctx.sys_path("devices/system/cpu");
+    let cache_dir = cpu_dir.join("cpu0/cache");
+    std::fs::metadata(&cache_dir).map_err(|e| ProbeError::from_io(&cache_dir, &e))?;
+    let entries = std::fs::read_dir(&cpu_dir).map_err(|e| ProbeError::from_io(&cpu_dir, &e))?;
+    let mut cpus: Vec<u32> = entries
+        .flatten()
+        .filter_map(|e| e.file_name().to_str()?.strip_prefix("cpu")?.parse().ok())
+        .collect();
+    cpus.sort_unstable();
+
+    let mut cache = CpuCache::default();
+    for cpu in cpus {
+        let indexes = std::fs::read_dir(cpu_dir.join(format!("cpu{}/cache", cpu))).into_iter().flatten().flatten();
+        let mut indexes: Vec<_> = indexes.filter(|e| e.file_name().to_string_lossy().starts_with("index")).collect();
+        indexes.sort_by_key(|e| e.file_name());
+        for index in indexes {
+            let dir = index.path();
+            let attr = |name: &str| read_sysfs_file(dir.join(name));
+            let number = |name: &str| attr(name).parse().unwrap_or(0);
+            let mut instance = CacheInstance {
+                level: number("level"),
+                cache_type: attr("type"),
+                size: parse_cache_size(&attr("size")),
+                line_size: number("coherency_line_size"),
+                ways: number("ways_of_associativity"),
+                sets: number("number_of_sets"),
+                cpus: cpu_topology::parse_cpu_list(&attr("shared_cpu_list")),
+            };
+            // Kernels without shared_cpu_list still name the CPU itself.
+            if instance.cpus.is_empty() {
+                instance.cpus.push(cpu);
+            }
+            if cpu == 0 {
+                match (instance.level, instance.cache_type.as_str()) {
+                    (1, "Data") => cache.l1d = instance.size,
+                    (1, "Instruction") => cache.l1i = instance.size,
+                    (2, _) => cache.l2 = instance.size,
+                    (3, _) => cache.l3 = instance.size,
+                    _ => {}
+                }
+            }
+            let seen = cache.instances.iter().any(|c| {
+                (c.level, &c.cache_type, &c.cpus) == (instance.level, &instance.cache_type, &instance.cpus)
+            });
+            if !seen {
+                cache.instances.push(instance);
+            }
+        }
+    }
+    // Stable, so instances of a level stay in CPU order.
+    cache.instances.sort_by(|a, b| (a.level, &a.cache_type).cmp(&(b.level, &b.cache_type)));
+
+    for instance in &cache.instances {
+        match cache.totals.last_mut() {
+            Some(total) if (total.level, &total.cache_type) == (instance.level, &instance.cache_type) => {
+                total.size += instance.size;
+                total.instances += 1;
+            }
+            _ => cache.totals.push(CacheTotal {
+                level: instance.level,
+                cache_type: instance.cache_type.clone(),
+                size: instance.size,
+                instances: 1,
+            }),
+        }
+    }
+    Ok(cache)
+}
+
+#[cfg(target_os = "linux")]
+fn parse_cache_size(s: &str) -> u64 {
+    let s = s.trim();
+    if let Some(kb) = s.strip_suffix('K') {
+        kb.parse::<u64>().unwrap_or(0) * 1024
+    } else if let Some(mb) = s.strip_suffix('M') {
+        mb.parse::<u64>().unwrap_or(0) * 1024 * 1024
+    } else {
+        s.parse::<u64>().unwrap_or(0)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn cpu_ids_source(ctx: &ProbeContext) -> String {
+    ctx.proc_path("cpuinfo").display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn read_cpu_ids(ctx: &ProbeContext) -> Result<(String, String, String), ProbeError> {
+    let path = ctx.proc_path("cpuinfo");
+    let cpuinfo = std::fs::read_to_string(&path).map_err(|e| ProbeError::from_io(&path, &e))?;
+    let mut family = String::new();
+    let mut model = String::new();
+    let mut stepping = String::new();
+
+    for line in cpuinfo.lines() {
+        if line.starts_with("cpu family") {
+            family = cpuinfo_value(line);
+        } else if line.starts_with("model\t") || line.starts_with("model ") {
+            if !line.starts_with("model name") {
+                model = cpuinfo_value(line);
+            }
+        } else if line.starts_with("stepping") {
+            stepping = cpuinfo_value(line);
+        }
+        if !family.is_empty() && !model.is_empty() && !stepping.is_empty() {
+            break;
+        }
+    }
+
+    Ok((family, model, stepping))
+}
+
+#[cfg(target_os = "linux")]
+fn cpuinfo_value(line: &str) -> String {
+    line.rsplit(':').next().unwrap_or("").trim().to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn read_features(ctx: &ProbeContext) -> Result<CpuFeatures, ProbeError> {
+    cpu_features::read_features(ctx)
+}
+
+#[cfg(target_os = "linux")]
+fn vulnerabilities_source(ctx: &ProbeContext) -> String {
+    ctx.sys_path("devices/system/cpu/vulnerabilities").display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn read_vulnerabilities(ctx: &ProbeContext) -> Result<Vec<CpuVulnerability>, ProbeError> {
+    Ok(cpu_features::read_vulnerabilities(ctx))
+}
+
+#[cfg(target_os = "linux")]
+fn frequency_scaling_source(ctx: &ProbeContext) -> String {
+    ctx.sys_path("devices/system/cpu/cpufreq").display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn read_frequency_scaling(ctx: &ProbeContext) -> Result<FrequencyScaling, ProbeError> {
+    cpufreq::read_frequency_scaling(ctx)
+}
+
+#[cfg(target_os = "linux")]
+fn topology_source(ctx: &ProbeContext) -> String {
+    ctx.sys_path("devices/system/cpu").display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn read_topology(ctx: &ProbeContext) -> Result<CpuTopology, ProbeError> {
+    cpu_topology::read_topology(ctx)
+}
+
+#[cfg(target_os = "linux")]
+fn read_core_types(ctx: &ProbeContext, topology: &CpuTopology) -> Result<Vec<CpuCoreType>, ProbeError> {
+    Ok(cpu_topology::read_core_types(ctx, topology))
+}
+
+/// Highest `cpuinfo_max_freq` of any CPU, in GHz.
+#[cfg(target_os = "linux")]
+fn rated_speed_max(ctx: &ProbeContext, topology: &CpuTopology) -> Option<f64> {
+    let cpus: Vec<u32> = topology.cpus.iter().map(|c| c.cpu).collect();
+    let (_, max) = cpu_topology::frequency_limits(ctx, &cpus);
+    (max > 0.0).then_some(max)
+}
+
+#[cfg(target_os = "linux")]
+fn cpu_times_source(ctx: &ProbeContext) -> String {
+    ctx.proc_path("stat").display().to_string()
+}
+
+/// The aggregate `cpu` line: `cpu  user nice system idle iowait irq softirq
+/// steal guest guest_nice`, in clock ticks. Guest time is already counted
+/// in user.
+#[cfg(target_os = "linux")]
+fn read_cpu_times(ctx: &ProbeContext) -> Result<CpuTimesSample, ProbeError> {
+    let path = ctx.proc_path("stat");
+    let stat = std::fs::read_to_string(&path).map_err(|e| ProbeError::from_io(&path, &e))?;
+    let parse_failure = || ProbeError::ParseFailure {
+        source: path.display().to_string(),
+        message: "no aggregate cpu line".to_string(),
+    };
+    let line = stat.lines().find(|l| l.starts_with("cpu ")).ok_or_else(parse_failure)?;
+
+    let mut jiffies = [0; 8];
+    // Kernels before 2.6.11 stop before steal; missing fields stay zero.
+    for (slot, field) in jiffies.iter_mut().zip(line.split_whitespace().skip(1)) {
+        *slot = field.parse().map_err(|_| parse_failure())?;
+    }
+    Ok(CpuTimesSample { jiffies })
+}
+
+#[cfg(target_os = "linux")]
+fn no_sensors_error(ctx: &ProbeContext) -> ProbeError {
+    ProbeError::NotFound {
+        path: ctx.sys_path("class/hwmon").display().to_string(),
+    }
+}
+
+// ——— Platform-specific helpers (Windows) ———
+
+#[cfg(target_os = "windows")]
+fn cpu_ids_source(_ctx: &ProbeContext) -> String {
+    "sysinfo".to_string()
+}
+
+#[cfg(target_os = "windows")]
+fn read_cpu_ids(_ctx: &ProbeContext) -> Result<(String, String, String), ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(target_os = "windows")]
+fn cache_source(_ctx: &ProbeContext) -> String {
+    "Win32_CacheMemory".to_string()
+}
+
+#[cfg(target_os = "windows")]
+fn read_cache(_ctx: &ProbeContext) -> Result<CpuCache, ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(target_os = "windows")]
+fn read_features(_ctx: &ProbeContext) -> Result<CpuFeatures, ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(target_os = "windows")]
+fn vulnerabilities_source(_ctx: &ProbeContext) -> String {
+    "none".to_string()
+}
+
+#[cfg(target_os = "windows")]
+fn read_vulnerabilities(_ctx: &ProbeContext) -> Result<Vec<CpuVulnerability>, ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(target_os = "windows")]
+fn frequency_scaling_source(_ctx: &ProbeContext) -> String {
+    "none".to_string()
+}
+
+#[cfg(target_os = "windows")]
+fn read_frequency_scaling(_ctx: &ProbeContext) -> Result<FrequencyScaling, ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(target_os = "windows")]
+fn topology_source(_ctx: &ProbeContext) -> String {
+    "none".to_string()
+}
+
+#[cfg(target_os = "windows")]
+fn read_topology(_ctx: &ProbeContext) -> Result<CpuTopology, ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(target_os = "windows")]
+fn read_core_types(_ctx: &ProbeContext, _topology: &CpuTopology) -> Result<Vec<CpuCoreType>, ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(target_os = "windows")]
+fn rated_speed_max(_ctx: &ProbeContext, _topology: &CpuTopology) -> Option<f64> {
+    None
+}
+
+#[cfg(target_os = "windows")]
+fn cpu_times_source(_ctx: &ProbeContext) -> String {
+    "none".to_string()
+}
+
+#[cfg(target_os = "windows")]
+fn read_cpu_times(_ctx: &ProbeContext) -> Result<CpuTimesSample, ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(target_os = "windows")]
+fn cpu_temp_source(_ctx: &ProbeContext) -> String {
+    "sysinfo components".to_string()
+}
+
+#[cfg(target_os = "windows")]
+fn read_cpu_temp(ctx: &ProbeContext) -> Result<CpuTemperature, ProbeError> {
+    let components = Components::new_with_refreshed_list();
+    if components.is_empty() {
+        return Err(no_sensors_error(ctx));
+    }
+
+    let mut main_temp = 0.0_f64;
+    let mut max_temp = 0.0_f64;
+
+    for comp in components.iter() {
+        let label = comp.label().to_lowercase();
+        let temp = comp.temperature() as f64;
+        if label.contains("core") || label.contains("cpu") || label.contains("package") || label.contains("tctl") {
+            if label.contains("package") || label.contains("tctl") || main_temp == 0.0 {
+                main_temp = temp;
+            }
+            if temp > max_temp {
+                max_temp = temp;
+            }
+        }
+    }
+
+    Ok(CpuTemperature {
+        main: main_temp,
+        max: max_temp,
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn no_sensors_error(_ctx: &ProbeContext) -> ProbeError {
+    ProbeError::unsupported()
+}
@@ -0,0 +1,118 @@
+//! Feature flags and microcode revision from `/proc/cpuinfo`, and the
+//! kernel's verdict on each known speculative-execution vulnerability from
+//! `/sys/devices/system/cpu/vulnerabilities`.
+
+use crate::context::ProbeContext;
+use crate::read_sysfs_file;
+use crate::types::{CpuFeatures, CpuVulnerability, FeatureCategory, ProbeError};
+
+/// x86 instruction set extensions by category, as named in the `flags`
+/// line. Flags not listed here still appear in `CpuFeatures::flags`.
+const X86_EXTENSIONS: &[(&str, &[&str])] = &[
+    ("SIMD", &[
+        "mmx", "sse", "sse2", "pni", "ssse3", "sse4_1", "sse4_2", "sse4a", "avx", "avx2", "fma", "fma4", "f16c",
+        "avx_vnni", "avx_vnni_int8", "avx_ifma", "avx_ne_convert",
+    ]),
+    ("AVX-512", &[
+        "avx512f", "avx512cd", "avx512dq", "avx512bw", "avx512vl", "avx512ifma", "avx512vbmi", "avx512_vbmi2",
+        "avx512_vnni", "avx512_bitalg", "avx512_vpopcntdq", "avx512_bf16", "avx512_fp16", "avx512_vp2intersect",
+    ]),
+    ("Matrix", &["amx_tile", "amx_bf16", "amx_int8", "amx_fp16"]),
+    ("Cryptography", &["aes", "vaes", "pclmulqdq", "vpclmulqdq", "sha_ni", "gfni"]),
+    ("Bit Manipulation", &["popcnt", "abm", "bmi1", "bmi2", "adx", "movbe", "tbm"]),
+    ("Random Numbers", &["rdrand", "rdseed"]),
+    ("Atomics", &["cx8", "cx16"]),
+    ("Virtualization", &["vmx", "svm", "ept", "vpid", "npt", "ept_ad", "flexpriority", "tpr_shadow"]),
+    ("Security", &["nx", "smep", "smap", "umip", "pku", "ibt", "shstk", "sgx", "sme", "sev"]),
+];
+
+/// The same for ARM's `Features` line, where some names (`sme`, `aes`)
+/// mean something else or cover more than on x86.
+const ARM_EXTENSIONS: &[(&str, &[&str])] = &[
+    ("SIMD", &["asimd", "asimdhp", "asimddp", "asimdfhm", "asimdrdm", "i8mm", "bf16"]),
+    ("SVE", &["sve", "sve2", "svei8mm", "svebf16", "svef32mm", "svef64mm"]),
+    ("Matrix", &["sme", "sme2"]),
+    ("Cryptography", &["aes", "pmull", "sha1", "sha2", "sha3", "sha512", "sm3", "sm4"]),
+    ("Random Numbers", &["rng"]),
+    ("Atomics", &["atomics", "lrcpc", "ilrcpc", "lse2"]),
+    ("Security", &["paca", "pacg", "bti", "mte"]),
+];
+
+pub(crate) fn read_features(ctx: &ProbeContext) -> Result<CpuFeatures, ProbeError> {
+    let path = ctx.proc_path("cpuinfo");
+    let cpuinfo = std::fs::read_to_string(&path).map_err(|e| ProbeError::from_io(&path, &e))?;
+    // Every processor block repeats the same lines; the first one will do.
+    let field = |name: &str| {
+        cpuinfo
+            .lines()
+            .find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                (key.trim() == name).then(|| value.trim())
+            })
+            .unwrap_or("")
+    };
+    let (raw, table) = match field("flags") {
+        "" => (field("Features"), ARM_EXTENSIONS),
+        flags => (flags, X86_EXTENSIONS),
+    };
+    let flags: Vec<String> = raw.split_whitespace().map(str::to_string).collect();
+    let has = |flag: &str| flags.iter().any(|f| f == flag);
+
+    let extensions = table
+        .iter()
+        .map(|(category, known)| FeatureCategory {
+            category: category.to_string(),
+            flags: known.iter().filter(|f| has(f)).map(|f| f.to_string()).collect(),
+        })
+        .filter(|c| !c.flags.is_empty())
+        .collect();
+    let virtualization = ["vmx", "svm"].into_iter().find(|f| has(f)).unwrap_or("").to_string();
+
+    Ok(CpuFeatures {
+        extensions,
+        microcode: field("microcode").to_string(),
+        virtualization,
+        hypervisor: has("hypervisor"),
+        vulnerabilities: Vec::new(),
+        flags,
+    })
+}
+
+/// Sorted by name; empty on kernels before 4.15, which have no
+/// `vulnerabilities` directory.
+pub(crate) fn read_vulnerabilities(ctx: &ProbeContext) -> Vec<CpuVulnerability> {
+    let dir = ctx.sys_path("devices/system/cpu/vulnerabilities");
+    let mut vulnerabilities: Vec<CpuVulnerability> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| {
+            let detail = read_sysfs_file(e.path());
+            CpuVulnerability {
+                name: e.file_name().to_string_lossy().into_owned(),
+                status: vulnerability_status(&detail).to_string(),
+                detail,
+            }
+        })
+        .collect();
+    vulnerabilities.sort_by(|a, b| a.name.cmp(&b.name));
+    vulnerabilities
+}
+
+/// `Not affected`, `Mitigation: …`, `Vulnerable…` or `Unknown: …`, possibly
+/// behind the subsystem it applies to, as in `KVM: Mitigation: VMX disabled`.
+fn vulnerability_status(detail: &str) -> &'static str {
+    let detail = match detail.split_once(": ") {
+        Some((subsystem, rest)) if subsystem.chars().all(|c| c.is_ascii_uppercase()) => rest,
+        _ => detail,
+    };
+    if detail.starts_with("Not affected") {
+        "not affected"
+    } else if detail.starts_with("Mitigation") {
+        "mitigated"
+    } else if detail.starts_with("Vulnerable") {
+        "vulnerable"
+    } else {
+        "unknown"
+    }
+}
@@ -0,0 +1,221 @@
+//! Processor layout from `/sys/devices/system/cpu/cpu*/topology`, NUMA
+//! nodes with their CPUs, memory and distances from
+//! `/sys/devices/system/node`, and the core types of hybrid processors.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::context::ProbeContext;
+use crate::read_sysfs_file;
+use crate::types::{
+    CpuCluster, CpuCore, CpuCoreType, CpuDie, CpuPackage, CpuTopology, LogicalCpu, NumaNode, ProbeError,
+};
+
+/// Package → die → cluster → core → logical CPU numbers.
+type Tree = BTreeMap<u32, BTreeMap<u32, BTreeMap<u32, BTreeMap<u32, Vec<u32>>>>>;
+
+pub(crate) fn read_topology(ctx: &ProbeContext) -> Result<CpuTopology, ProbeError> {
+    let cpu_dir = ctx.sys_path("devices/system/cpu");
+    let entries = std::fs::read_dir(&cpu_dir).map_err(|e| ProbeError::from_io(&cpu_dir, &e))?;
+    // Skips cpufreq, cpuidle and the other non-CPU entries.
+    let mut numbers: Vec<u32> = entries
+        .flatten()
+        .filter_map(|e| e.file_name().to_str()?.strip_prefix("cpu")?.parse().ok())
+        .collect();
+    numbers.sort_unstable();
+
+    let numa_nodes = read_numa_nodes(ctx);
+    let mut tree = Tree::new();
+    let mut cpus = Vec::new();
+    for cpu in numbers {
+        let dir = cpu_dir.join(format!("cpu{}", cpu));
+        // The boot CPU usually cannot go offline and has no `online` file.
+        let online = read_sysfs_file(dir.join("online")) != "0";
+        cpus.push(LogicalCpu {
+            cpu,
+            online,
+            numa_node: numa_nodes.iter().find(|n| n.cpus.contains(&cpu)).map(|n| n.id),
+        });
+        // The kernel drops the topology of CPUs it takes offline.
+        let topology = dir.join("topology");
+        if online && topology.is_dir() {
+            place_cpu(&mut tree, &topology, cpu);
+        }
+    }
+
+    Ok(CpuTopology { packages: build_packages(tree), cpus, numa_nodes })
+}
+
+/// Missing `die_id` (before 5.2) or `cluster_id` (before 5.16), and the
+/// `-1` of CPUs without clusters, all read as 0.
+fn place_cpu(tree: &mut Tree, topology: &Path, cpu: u32) {
+    let id = |name: &str| read_sysfs_file(topology.join(name)).parse().unwrap_or(0);
+    tree.entry(id("physical_package_id"))
+        .or_default()
+        .entry(id("die_id"))
+        .or_default()
+        .entry(id("cluster_id"))
+        .or_default()
+        .entry(id("core_id"))
+        .or_default()
+        .push(cpu);
+}
+
+fn build_packages(tree: Tree) -> Vec<CpuPackage> {
+    tree.into_iter()
+        .map(|(id, dies)| CpuPackage {
+            id,
+            dies: dies
+                .into_iter()
+                .map(|(id, clusters)| CpuDie {
+                    id,
+                    clusters: clusters
+                        .into_iter()
+                        .map(|(id, cores)| CpuCluster {
+                            id,
+                            cores: cores.into_iter().map(|(id, threads)| CpuCore { id, threads }).collect(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Empty on kernels built without NUMA support, which have no node
+/// directory.
+fn read_numa_nodes(ctx: &ProbeContext) -> Vec<NumaNode> {
+    let node_dir = ctx.sys_path("devices/system/node");
+    let mut nodes: Vec<NumaNode> = std::fs::read_dir(node_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|e| {
+            let id = e.file_name().to_str()?.strip_prefix("node")?.parse().ok()?;
+            let dir = e.path();
+            Some(NumaNode {
+                id,
+                cpus: parse_cpu_list(&read_sysfs_file(dir.join("cpulist"))),
+                memory_total: node_memory_total(&dir),
+                distances: read_sysfs_file(dir.join("distance"))
+                    .split_whitespace()
+                    .filter_map(|d| d.parse().ok())
+                    .collect(),
+            })
+        })
+        .collect();
+    nodes.sort_by_key(|n| n.id);
+    nodes
+}
+
+/// `Node 0 MemTotal:       67108864 kB` from the node's own meminfo.
+fn node_memory_total(dir: &Path) -> u64 {
+    let meminfo = read_sysfs_file(dir.join("meminfo"));
+    meminfo
+        .lines()
+        .find_map(|line| line.split_once("MemTotal:"))
+        .and_then(|(_, rest)| rest.split_whitespace().next()?.parse::<u64>().ok())
+        .map_or(0, |kb| kb * 1024)
+}
+
+/// Kernel CPU list syntax: `0-3,8-11`, or empty.
+pub(crate) fn parse_cpu_list(list: &str) -> Vec<u32> {
+    list.split(',')
+        .filter_map(|range| match range.trim().split_once('-') {
+            Some((start, end)) => Some((start.parse().ok()?..=end.parse().ok()?).collect::<Vec<u32>>()),
+            None => Some(vec![range.trim().parse().ok()?]),
+        })
+        .flatten()
+        .collect()
+}
+
+/// Groups the online CPUs of a hybrid processor by core type, from the
+/// first source that tells them apart: Intel's per-type PMUs, the
+/// scheduler's `cpu_capacity`, or on ARM the cpufreq maximum of each
+/// cluster. Intel's favoured cores also differ in maximum frequency, so
+/// that last source is not used there.
+pub(crate) fn read_core_types(ctx: &ProbeContext, topology: &CpuTopology) -> Vec<CpuCoreType> {
+    let cpu_dir = ctx.sys_path("devices/system/cpu");
+    let online: Vec<u32> = topology.cpus.iter().filter(|c| c.online).map(|c| c.cpu).collect();
+    let attr = |cpu: u32, rel: &str| read_sysfs_file(cpu_dir.join(format!("cpu{}", cpu)).join(rel));
+
+    let pmu = |name: &str| parse_cpu_list(&read_sysfs_file(ctx.sys_path("devices").join(name).join("cpus")));
+    let (performance, efficiency) = (pmu("cpu_core"), pmu("cpu_atom"));
+    let groups = if !performance.is_empty() && !efficiency.is_empty() {
+        vec![performance, efficiency]
+    } else {
+        let by_capacity = group_by_descending(&online, |cpu| attr(cpu, "cpu_capacity").parse().ok());
+        if by_capacity.len() > 1 {
+            by_capacity
+        } else if is_arm(ctx) {
+            group_by_descending(&online, |cpu| attr(cpu, "cpufreq/cpuinfo_max_freq").parse().ok())
+        } else {
+            Vec::new()
+        }
+    };
+    if groups.len() < 2 {
+        return Vec::new();
+    }
+
+    let last = groups.len() - 1;
+    groups
+        .into_iter()
+        .enumerate()
+        .map(|(tier, cpus)| {
+            let (speed_min, speed_max) = frequency_limits(ctx, &cpus);
+            CpuCoreType {
+                kind: match tier {
+                    0 => "performance",
+                    t if t == last => "efficiency",
+                    _ => "mid",
+                }
+                .to_string(),
+                cores: count_cores(topology, &cpus),
+                cpus,
+                speed_min,
+                speed_max,
+            }
+        })
+        .collect()
+}
+
+/// CPUs sharing a value of `key`, highest value first; nothing when any CPU
+/// lacks one.
+fn group_by_descending(cpus: &[u32], key: impl Fn(u32) -> Option<u64>) -> Vec<Vec<u32>> {
+    let mut groups: BTreeMap<Reverse<u64>, Vec<u32>> = BTreeMap::new();
+    for &cpu in cpus {
+        match key(cpu) {
+            Some(value) => groups.entry(Reverse(value)).or_default().push(cpu),
+            None => return Vec::new(),
+        }
+    }
+    groups.into_values().collect()
+}
+
+fn is_arm(ctx: &ProbeContext) -> bool {
+    std::fs::read_to_string(ctx.proc_path("cpuinfo")).is_ok_and(|cpuinfo| cpuinfo.contains("CPU implementer"))
+}
+
+/// Physical cores with at least one thread among `cpus`.
+fn count_cores(topology: &CpuTopology, cpus: &[u32]) -> usize {
+    topology
+        .packages
+        .iter()
+        .flat_map(|p| &p.dies)
+        .flat_map(|d| &d.clusters)
+        .flat_map(|c| &c.cores)
+        .filter(|core| core.threads.iter().any(|t| cpus.contains(t)))
+        .count()
+}
+
+/// Lowest `cpuinfo_min_freq` and highest `cpuinfo_max_freq` of `cpus`, in
+/// GHz; 0 where cpufreq is not available.
+pub(crate) fn frequency_limits(ctx: &ProbeContext, cpus: &[u32]) -> (f64, f64) {
+    let khz = |cpu: u32, name: &str| -> Option<u64> {
+        read_sysfs_file(ctx.sys_path(format!("devices/system/cpu/cpu{}/cpufreq/{}", cpu, name))).parse().ok()
+    };
+    let min = cpus.iter().filter_map(|&cpu| khz(cpu, "cpuinfo_min_freq")).min().unwrap_or(0);
+    let max = cpus.iter().filter_map(|&cpu| khz(cpu, "cpuinfo_max_freq")).max().unwrap_or(0);
+    (min as f64 / 1_000_000.0, max as f64 / 1_000_000.0)
+}
@@ -0,0 +1,75 @@
+//! Frequency scaling policies from `/sys/devices/system/cpu/cpufreq`.
+
+use std::path::Path;
+
+use crate::context::ProbeContext;
+use crate::cpu_topology::parse_cpu_list;
+use crate::read_sysfs_file;
+use crate::types::{CpufreqPolicy, FrequencyScaling, ProbeError};
+
+/// Empty when the kernel has no cpufreq directory; that is normal inside
+/// virtual machines.
+pub(crate) fn read_frequency_scaling(ctx: &ProbeContext) -> Result<FrequencyScaling, ProbeError> {
+    let dir = ctx.sys_path("devices/system/cpu/cpufreq");
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(FrequencyScaling::default()),
+        Err(e) => return Err(ProbeError::from_io(&dir, &e)),
+    };
+    let mut policies: Vec<(CpufreqPolicy, String)> = entries
+        .flatten()
+        .filter_map(|e| {
+            let policy = e.file_name().to_str()?.strip_prefix("policy")?.parse().ok()?;
+            Some(read_policy(&e.path(), policy))
+        })
+        .collect();
+    policies.sort_by_key(|(p, _)| p.policy);
+
+    Ok(FrequencyScaling {
+        driver: policies.first().map(|(_, driver)| driver.clone()).unwrap_or_default(),
+        boost: read_boost(ctx),
+        policies: policies.into_iter().map(|(p, _)| p).collect(),
+    })
+}
+
+/// The policy, and its `scaling_driver`.
+fn read_policy(dir: &Path, policy: u32) -> (CpufreqPolicy, String) {
+    let attr = |name: &str| read_sysfs_file(dir.join(name));
+    let ghz = |name: &str| attr(name).parse::<u64>().map_or(0.0, |khz| khz as f64 / 1_000_000.0);
+    let list = |name: &str| attr(name).split_whitespace().map(str::to_string).collect();
+    // affected_cpus leaves out offline CPUs the policy would otherwise cover.
+    let mut cpus = parse_cpu_list(&attr("related_cpus").replace(' ', ","));
+    if cpus.is_empty() {
+        cpus = parse_cpu_list(&attr("affected_cpus").replace(' ', ","));
+    }
+    let policy = CpufreqPolicy {
+        policy,
+        cpus,
+        governor: attr("scaling_governor"),
+        available_governors: list("scaling_available_governors"),
+        hardware_min: ghz("cpuinfo_min_freq"),
+        hardware_max: ghz("cpuinfo_max_freq"),
+        scaling_min: ghz("scaling_min_freq"),
+        scaling_max: ghz("scaling_max_freq"),
+        current: ghz("scaling_cur_freq"),
+        base: ghz("base_frequency"),
+        energy_performance_preference: attr("energy_performance_preference"),
+        available_preferences: list("energy_performance_available_preferences"),
+    };
+    (policy, attr("scaling_driver"))
+}
+
+/// intel_pstate has its own `no_turbo` switch; acpi-cpufreq and amd-pstate
+/// share `cpufreq/boost`.
+fn read_boost(ctx: &ProbeContext) -> Option<bool> {
+    match read_sysfs_file(ctx.sys_path("devices/system/cpu/intel_pstate/no_turbo")).as_str() {
+        "0" => return Some(true),
+        "1" => return Some(false),
+        _ => {}
+    }
+    match read_sysfs_file(ctx.sys_path("devices/system/cpu/cpufreq/boost")).as_str() {
+        "1" => Some(true),
+        "0" => Some(false),
+        _ => None,
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::time::Instant;
+
+use crate::command::CommandOutput;
+use crate::context::ProbeContext;
+use crate::types::{ProbeError, ProbeReport};
+
+impl ProbeError {
+    /// Classifies a failed file or directory read.
+    pub(crate) fn from_io(path: &Path, err: &io::Error) -> Self {
+        let path = path.display().to_string();
+        match err.kind() {
+            io::ErrorKind::NotFound => ProbeError::NotFound { path },
+            io::ErrorKind::PermissionDenied => ProbeError::PermissionDenied { path },
+            _ => ProbeError::Io {
+                path,
+                message: err.to_string(),
+            },
+        }
+    }
+
+    /// Classifies a command that could not be started.
+    pub(crate) fn from_spawn(tool: &str, err: &io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::NotFound => ProbeError::MissingTool { tool: tool.to_string() },
+            io::ErrorKind::PermissionDenied => ProbeError::PermissionDenied { path: tool.to_string() },
+            _ => ProbeError::Io {
+                path: tool.to_string(),
+                message: err.to_string(),
+            },
+        }
+    }
+
+    /// Classifies a command that ran but failed.
+    pub(crate) fn from_command(tool: &str, out: &CommandOutput) -> Self {
+        let stderr = out.stderr.trim();
+        let lower = stderr.to_lowercase();
+        if lower.contains("permission denied") || lower.contains("must be root") || lower.contains("not permitted") {
+            return ProbeError::PermissionDenied { path: tool.to_string() };
+        }
+        ProbeError::CommandFailed {
+            tool: tool.to_string(),
+            exit_code: out.exit_code,
+            stderr: stderr.to_string(),
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub(crate) fn unsupported() -> Self {
+        ProbeError::Unsupported {
+            platform: std::env::consts::OS.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ProbeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProbeError::MissingTool { tool } => write!(f, "{} is not installed", tool),
+            ProbeError::PermissionDenied { path } => write!(f, "permission denied: {}", path),
+            ProbeError::NotFound { path } => write!(f, "not present: {}", path),
+            ProbeError::ParseFailure { source, message } => write!(f, "could not parse {}: {}", source, message),
+            ProbeError::CommandFailed { tool, exit_code, stderr } => {
+                match exit_code {
+                    Some(code) => write!(f, "{} exited with status {}", tool, code)?,
+                    None => write!(f, "{} was terminated by a signal", tool)?,
+                }
+                if !stderr.is_empty() {
+                    write!(f, ": {}", stderr)?;
+                }
+                Ok(())
+            }
+            ProbeError::Io { path, message } => write!(f, "{}: {}", path, message),
+            ProbeError::Unsupported { platform } => write!(f, "not supported on {}", platform),
+        }
+    }
+}
+
+impl std::error::Error for ProbeError {}
+
+impl ProbeContext {
+    /// Runs one probe, records its source, duration and outcome, and returns
+    /// its value (or the type's default when it failed).
+    pub(crate) fn probe<T: Default>(
+        &self,
+        name: &str,
+        source: impl fmt::Display,
+        f: impl FnOnce() -> Result<T, ProbeError>,
+    ) -> T {
+        let started = Instant::now();
+        let result = f();
+        let report = ProbeReport {
+            probe: name.to_string(),
+            source: source.to_string(),
+            duration_ms: started.elapsed().as_secs_f64() * 1000.0,
+            error: result.as_ref().err().cloned(),
+        };
+        self.record(report);
+        result.unwrap_or_default()
+    }
+
+    /// Runs a command and turns both spawn failures and non-zero exits into
+    /// a [`ProbeError`].
+    pub(crate) fn run_checked(&self, program: &str, args: &[&str]) -> Result<CommandOutput, ProbeError> {
+        let out = self.run(program, args).map_err(|e| ProbeError::from_spawn(program, &e))?;
+        if out.success() {
+            Ok(out)
+        } else {
+            Err(ProbeError::from_command(program, &out))
+        }
+    }
+}
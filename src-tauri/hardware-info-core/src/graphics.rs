@@ -0,0 +1,73 @@
+use crate::context::ProbeContext;
+#[cfg(target_os = "linux")]
+use crate::pci::collect_pci;
+use crate::types::{GpuController, GraphicsInfo, PciInfo};
+#[cfg(target_os = "windows")]
+use crate::types::ProbeError;
+
+/// Display controllers (GPUs) found on the system.
+///
+/// On Linux these are the display-class devices of
+/// [`collect_pci`](crate::collect_pci), so no external tool is needed.
+pub fn collect_graphics(ctx: &ProbeContext) -> GraphicsInfo {
+    #[cfg(target_os = "linux")]
+    let pci = collect_pci(ctx);
+    #[cfg(not(target_os = "linux"))]
+    let pci = PciInfo::default();
+
+    graphics_from_pci(ctx, &pci)
+}
+
+/// Builds [`GraphicsInfo`] from an already collected PCI list, so a caller
+/// that also reports the PCI bus walks it only once.
+#[cfg(target_os = "linux")]
+pub fn graphics_from_pci(_ctx: &ProbeContext, pci: &PciInfo) -> GraphicsInfo {
+    // Base class 0x03: VGA, XGA, 3D and other display controllers
+    let controllers = pci
+        .devices
+        .iter()
+        .filter(|d| d.class_id.starts_with("03"))
+        .map(|d| GpuController {
+            model: d.device.clone(),
+            vendor: d.vendor.clone(),
+            vram: 0, // Not exposed generically by sysfs
+            bus: d.address.clone(),
+        })
+        .collect();
+
+    GraphicsInfo { controllers }
+}
+
+#[cfg(target_os = "windows")]
+pub fn graphics_from_pci(ctx: &ProbeContext, _pci: &PciInfo) -> GraphicsInfo {
+    GraphicsInfo {
+        controllers: ctx.probe("graphics", "Win32_VideoController", || read_gpu_info(ctx)),
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+pub fn graphics_from_pci(_ctx: &ProbeContext, _pci: &PciInfo) -> GraphicsInfo {
+    GraphicsInfo::default()
+}
+
+// ——— Platform-specific helpers (Windows) ———
+
+#[cfg(target_os = "windows")]
+fn read_gpu_info(_ctx: &ProbeContext) -> Result<Vec<GpuController>, ProbeError> {
+    use crate::windows::{exec_powershell, PsGpu};
+
+    let script = "@(Get-CimInstance Win32_VideoController) | Select-Object Name, VideoProcessor, AdapterRAM | ConvertTo-Json -Compress";
+
+    let ps_gpus: Vec<PsGpu> = exec_powershell(script)?;
+    let mut res = Vec::new();
+
+    for gpu in ps_gpus {
+        res.push(GpuController {
+            model: gpu.Name.unwrap_or_default(),
+            vendor: gpu.VideoProcessor.clone().unwrap_or_else(|| "Unknown".to_string()),
+            vram: gpu.AdapterRAM.unwrap_or(0),
+            bus: "PCI".to_string(),
+        });
+    }
+    Ok(res)
+}
@@ -0,0 +1,173 @@
+//! Parser for the `pci.ids` / `usb.ids` name databases.
+//!
+//! Both files share one layout: a vendor line at column 0, its devices
+//! indented by one tab, and (in `pci.ids`) subsystems by two. Device classes
+//! follow in a `C` section with subclasses nested the same way. Deeper
+//! class levels (programming interfaces, protocols) and every other section
+//! (`usb.ids` also carries HID usages, languages and more) are skipped.
+
+use std::collections::HashMap;
+use std::io;
+
+use crate::context::ProbeContext;
+use crate::types::ProbeError;
+
+#[derive(Debug, Default)]
+pub(crate) struct IdsDatabase {
+    vendors: HashMap<u16, Vendor>,
+    classes: HashMap<u8, Class>,
+}
+
+#[derive(Debug, Default)]
+struct Vendor {
+    name: String,
+    devices: HashMap<u16, Device>,
+}
+
+#[derive(Debug, Default)]
+struct Device {
+    name: String,
+    subsystems: HashMap<(u16, u16), String>,
+}
+
+#[derive(Debug, Default)]
+struct Class {
+    name: String,
+    subclasses: HashMap<u8, String>,
+}
+
+/// Which top-level entry indented lines currently belong to.
+enum Section {
+    Vendor(u16),
+    Class(u8),
+    Other,
+}
+
+impl IdsDatabase {
+    /// Loads `file_name` from the first of [`ProbeContext::hwdata_dirs`] that
+    /// has it, falling back to `bundled`.
+    pub(crate) fn load(ctx: &ProbeContext, file_name: &str, bundled: &str) -> IdsDatabase {
+        for dir in &ctx.hwdata_dirs {
+            let path = dir.join(file_name);
+            match std::fs::read_to_string(&path) {
+                Ok(text) => return ctx.probe(file_name, path.display(), || Ok(IdsDatabase::parse(&text))),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                // Unreadable copy: say so, then keep looking.
+                Err(e) => ctx.probe(file_name, path.display(), || Err(ProbeError::from_io(&path, &e))),
+            }
+        }
+        ctx.probe(file_name, format!("bundled {}", file_name), || Ok(IdsDatabase::parse(bundled)))
+    }
+
+    pub(crate) fn parse(text: &str) -> IdsDatabase {
+        let mut db = IdsDatabase::default();
+        let mut section = Section::Other;
+        let mut device: Option<u16> = None;
+
+        for line in text.lines() {
+            if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+            let depth = line.chars().take_while(|&c| c == '\t').count();
+            let line = &line[depth..];
+
+            match (depth, &section) {
+                (0, _) => {
+                    device = None;
+                    section = if let Some(rest) = line.strip_prefix("C ") {
+                        match entry_u8(rest) {
+                            Some((id, name)) => {
+                                db.classes.insert(id, Class { name, ..Default::default() });
+                                Section::Class(id)
+                            }
+                            None => Section::Other,
+                        }
+                    } else {
+                        match entry_u16(line) {
+                            Some((id, name)) => {
+                                db.vendors.insert(id, Vendor { name, ..Default::default() });
+                                Section::Vendor(id)
+                            }
+                            None => Section::Other,
+                        }
+                    };
+                }
+                (1, Section::Vendor(vendor)) => {
+                    if let (Some((id, name)), Some(v)) = (entry_u16(line), db.vendors.get_mut(vendor)) {
+                        v.devices.insert(id, Device { name, ..Default::default() });
+                        device = Some(id);
+                    }
+                }
+                (2, Section::Vendor(vendor)) => {
+                    // "1043 87c1  Name": subsystem vendor and device, then the name.
+                    let Some((sub_vendor, rest)) = line.split_once(' ') else { continue };
+                    let d = device.and_then(|d| db.vendors.get_mut(vendor)?.devices.get_mut(&d));
+                    if let (Some(sub_vendor), Some((sub_device, name)), Some(d)) = (parse_u16(sub_vendor), entry_u16(rest), d) {
+                        d.subsystems.insert((sub_vendor, sub_device), name);
+                    }
+                }
+                (1, Section::Class(class)) => {
+                    if let (Some((id, name)), Some(c)) = (entry_u8(line), db.classes.get_mut(class)) {
+                        c.subclasses.insert(id, name);
+                    }
+                }
+                // Programming interfaces and every other section.
+                _ => {}
+            }
+        }
+
+        db
+    }
+
+    pub(crate) fn vendor(&self, vendor: u16) -> Option<&str> {
+        self.vendors.get(&vendor).map(|v| v.name.as_str())
+    }
+
+    pub(crate) fn device(&self, vendor: u16, device: u16) -> Option<&str> {
+        self.vendors.get(&vendor)?.devices.get(&device).map(|d| d.name.as_str())
+    }
+
+    pub(crate) fn subsystem(&self, vendor: u16, device: u16, sub_vendor: u16, sub_device: u16) -> Option<&str> {
+        self.vendors
+            .get(&vendor)?
+            .devices
+            .get(&device)?
+            .subsystems
+            .get(&(sub_vendor, sub_device))
+            .map(String::as_str)
+    }
+
+    pub(crate) fn class(&self, class: u8) -> Option<&str> {
+        self.classes.get(&class).map(|c| c.name.as_str())
+    }
+
+    pub(crate) fn subclass(&self, class: u8, subclass: u8) -> Option<&str> {
+        self.classes.get(&class)?.subclasses.get(&subclass).map(String::as_str)
+    }
+}
+
+/// Splits `"10de  NVIDIA Corporation"` into the id and the name.
+fn split_id(line: &str) -> Option<(&str, String)> {
+    let (id, name) = line.split_once(char::is_whitespace)?;
+    Some((id, name.trim().to_string()))
+}
+
+fn entry_u16(line: &str) -> Option<(u16, String)> {
+    let (id, name) = split_id(line)?;
+    Some((parse_u16(id)?, name))
+}
+
+fn entry_u8(line: &str) -> Option<(u8, String)> {
+    let (id, name) = split_id(line)?;
+    if id.len() != 2 {
+        return None;
+    }
+    Some((u8::from_str_radix(id, 16).ok()?, name))
+}
+
+fn parse_u16(id: &str) -> Option<u16> {
+    if id.len() != 4 {
+        return None;
+    }
+    u16::from_str_radix(id, 16).ok()
+}
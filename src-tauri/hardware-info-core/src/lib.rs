@@ -0,0 +1,206 @@
+//! Headless hardware information collection.
+//!
+//! This crate holds everything the Hardware Info Dashboard knows about the
+//! machine it runs on, without any dependency on Tauri or a webview. The
+//! desktop app is a thin consumer of [`collect_hardware_info`] and
+//! [`collect_live_info`]; services and CLI tools can call the same entry
+//! points, or the per-section `collect_*` functions when they only need part
+//! of the report.
+
+mod audio;
+mod command;
+mod context;
+mod cpu;
+#[cfg(target_os = "linux")]
+mod cpu_features;
+#[cfg(target_os = "linux")]
+mod cpu_topology;
+#[cfg(target_os = "linux")]
+mod cpufreq;
+mod diagnostics;
+mod graphics;
+#[cfg(target_os = "linux")]
+mod ids;
+mod memory;
+mod network;
+#[cfg(target_os = "linux")]
+mod network_topology;
+mod optical;
+mod partitions;
+mod pci;
+mod peripherals;
+mod power;
+#[cfg(target_os = "linux")]
+mod routing;
+mod sensors;
+mod storage;
+#[cfg(target_os = "linux")]
+mod storage_stack;
+mod system;
+mod types;
+#[cfg(target_os = "windows")]
+mod windows;
+#[cfg(target_os = "linux")]
+mod wireless;
+
+use std::time::{Duration, Instant};
+
+use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind, System};
+
+pub use audio::collect_audio;
+pub use command::{
+    CommandBundle, CommandOutput, CommandRunner, RecordedCommand, RecordingRunner, ReplayRunner, SpawnError,
+    SystemRunner,
+};
+pub use context::ProbeContext;
+pub use cpu::{
+    collect_cpu_info, collect_cpu_load, collect_cpu_speed, collect_cpu_temp, collect_cpu_times, sample_cpu_times,
+    CpuTimesSample,
+};
+pub use graphics::{collect_graphics, graphics_from_pci};
+pub use memory::{collect_memory, collect_memory_live};
+pub use network::{collect_network, collect_network_io, sample_network_stats, NetworkStatsSample};
+pub use optical::collect_optical;
+pub use partitions::read_partition_table;
+pub use pci::collect_pci;
+pub use peripherals::collect_peripherals;
+pub use power::{collect_cpu_power, sample_cpu_power, CpuPowerSample};
+pub use sensors::collect_sensors;
+pub use storage::{
+    collect_disk_io, collect_storage, collect_storage_with, sample_disk_stats, DiskStatsSample, StorageOptions,
+};
+pub use system::{collect_runtime, collect_static_data};
+pub use types::*;
+
+/// Re-exported so callers can build the [`System`] handle the per-section
+/// collectors expect without pinning their own `sysinfo` version.
+pub use sysinfo;
+
+/// How long to wait between the two CPU refreshes sysinfo needs before
+/// usage percentages are meaningful, and between the two counter samples
+/// behind a rate.
+pub const SAMPLE_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Returns a fully refreshed [`System`] with CPU usage already sampled.
+///
+/// Blocks for roughly 200 ms.
+pub fn refreshed_system() -> System {
+    let mut sys = System::new_all();
+    sys.refresh_all();
+    // Allow CPU usage to be measured (needs 2 refreshes with delay)
+    std::thread::sleep(SAMPLE_INTERVAL);
+    sys.refresh_cpu_all();
+    sys
+}
+
+/// Collects the complete hardware report shown by the dashboard.
+pub fn collect_hardware_info() -> HardwareInfo {
+    collect_hardware_info_with(&ProbeContext::default())
+}
+
+/// Same as [`collect_hardware_info`], reading kernel files under `ctx`.
+///
+/// The returned [`HardwareInfo::diagnostics`] lists every probe run since
+/// the last [`ProbeContext::take_diagnostics`] on `ctx`.
+pub fn collect_hardware_info_with(ctx: &ProbeContext) -> HardwareInfo {
+    // Refreshing the system takes a CPU sample interval; measure CPU time,
+    // power, disk and network activity across the same window. Only the
+    // second sample's probes are reported.
+    let first = ctx.unrecorded();
+    let cpu_times_before = sample_cpu_times(&first);
+    let power_before = sample_cpu_power(&first);
+    let disks_before = sample_disk_stats(&first);
+    let network_before = sample_network_stats(&first);
+    let started = Instant::now();
+    let sys = refreshed_system();
+    let elapsed = started.elapsed();
+    let cpu_times = collect_cpu_times(&cpu_times_before, &sample_cpu_times(ctx));
+    let power = collect_cpu_power(&power_before, &sample_cpu_power(ctx), elapsed);
+    let disk_io = collect_disk_io(&disks_before, &sample_disk_stats(ctx), elapsed);
+    let network_io = collect_network_io(&network_before, &sample_network_stats(ctx), elapsed);
+    let pci = collect_pci(ctx);
+
+    HardwareInfo {
+        static_data: collect_static_data(ctx, &sys),
+        cpu: CpuInfo { power, ..collect_cpu_info(ctx, &sys) },
+        cpu_current_speed: collect_cpu_speed(&sys),
+        current_load: collect_cpu_load(&sys),
+        cpu_times,
+        cpu_temperature: collect_cpu_temp(ctx),
+        sensors: collect_sensors(ctx),
+        graphics: graphics_from_pci(ctx, &pci),
+        pci,
+        network: collect_network(ctx),
+        network_io,
+        storage: collect_storage(ctx),
+        disk_io,
+        memory: collect_memory(ctx, &sys),
+        audio: collect_audio(ctx),
+        peripherals: collect_peripherals(ctx),
+        optical: collect_optical(ctx),
+        runtime: collect_runtime(),
+        diagnostics: ctx.take_diagnostics(),
+    }
+}
+
+/// Collects the subset of metrics that change over time and are polled by
+/// the dashboard while it is open.
+pub fn collect_live_info() -> LiveInfo {
+    collect_live_info_with(&ProbeContext::default())
+}
+
+/// Same as [`collect_live_info`], reading kernel files under `ctx`.
+///
+/// Rates (CPU load, time breakdown and power, disk and network I/O) cover
+/// one sample interval of about 200 ms.
+pub fn collect_live_info_with(ctx: &ProbeContext) -> LiveInfo {
+    let mut sys = System::new_with_specifics(
+        RefreshKind::new()
+            .with_cpu(CpuRefreshKind::everything())
+            .with_memory(MemoryRefreshKind::everything()),
+    );
+    let first = ctx.unrecorded();
+    let cpu_times_before = sample_cpu_times(&first);
+    let power_before = sample_cpu_power(&first);
+    let disks_before = sample_disk_stats(&first);
+    let network_before = sample_network_stats(&first);
+    let started = Instant::now();
+    std::thread::sleep(SAMPLE_INTERVAL);
+    sys.refresh_cpu_all();
+    let elapsed = started.elapsed();
+    let cpu_times = collect_cpu_times(&cpu_times_before, &sample_cpu_times(ctx));
+    let cpu_power = collect_cpu_power(&power_before, &sample_cpu_power(ctx), elapsed);
+    let disk_io = collect_disk_io(&disks_before, &sample_disk_stats(ctx), elapsed);
+    let network_io = collect_network_io(&network_before, &sample_network_stats(ctx), elapsed);
+
+    LiveInfo {
+        cpu_current_speed: collect_cpu_speed(&sys),
+        current_load: collect_cpu_load(&sys),
+        cpu_times,
+        cpu_power,
+        cpu_temperature: collect_cpu_temp(ctx),
+        sensors: collect_sensors(ctx),
+        memory: collect_memory_live(&sys),
+        disk_io,
+        network_io,
+        runtime: collect_runtime(),
+    }
+}
+
+// ——— Shared helpers ———
+
+#[cfg(target_os = "linux")]
+fn read_sysfs_file(path: impl AsRef<std::path::Path>) -> String {
+    std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+/// Last component of a symlink target, e.g. the driver name behind a
+/// device's `driver` link.
+#[cfg(target_os = "linux")]
+fn read_link_name(path: impl AsRef<std::path::Path>) -> Option<String> {
+    let target = std::fs::read_link(path).ok()?;
+    Some(target.file_name()?.to_string_lossy().to_string())
+}
@@ -0,0 +1,141 @@
+use sysinfo::System;
+
+use crate::context::ProbeContext;
+use crate::types::{MemoryInfo, MemorySlot, ProbeError};
+
+/// RAM and swap usage, plus the per-DIMM layout from SMBIOS.
+///
+/// On Linux the layout comes from `dmidecode`, which needs root; when it is
+/// not available the app falls back to a `pkexec` prompt.
+pub fn collect_memory(ctx: &ProbeContext, sys: &System) -> MemoryInfo {
+    MemoryInfo {
+        total: sys.total_memory(),
+        used: sys.used_memory(),
+        available: sys.available_memory(),
+        active: sys.used_memory(),
+        swaptotal: sys.total_swap(),
+        swapused: sys.used_swap(),
+        swapfree: sys.total_swap().saturating_sub(sys.used_swap()),
+        layout: ctx.probe("memory.layout", LAYOUT_SOURCE, || read_memory_layout(ctx)),
+    }
+}
+
+/// Same as [`collect_memory`] without the DIMM layout, for live polling.
+pub fn collect_memory_live(sys: &System) -> MemoryInfo {
+    MemoryInfo {
+        total: sys.total_memory(),
+        used: sys.used_memory(),
+        available: sys.available_memory(),
+        active: sys.used_memory(),
+        swaptotal: sys.total_swap(),
+        swapused: sys.used_swap(),
+        swapfree: sys.total_swap().saturating_sub(sys.used_swap()),
+        layout: Vec::new(),
+    }
+}
+
+// ——— Platform-specific helpers (Linux) ———
+
+#[cfg(target_os = "linux")]
+const LAYOUT_SOURCE: &str = "dmidecode -t 17";
+
+#[cfg(target_os = "linux")]
+fn read_memory_layout(ctx: &ProbeContext) -> Result<Vec<MemorySlot>, ProbeError> {
+    // Try dmidecode first (needs root)
+    let out = match ctx.run_checked("dmidecode", &["-t", "17"]) {
+        Ok(out) => out,
+        // Fallback to pkexec for GUI prompt. If that fails too, dmidecode's
+        // own error says more than pkexec's.
+        Err(err) => ctx.run_checked("pkexec", &["dmidecode", "-t", "17"]).map_err(|_| err)?,
+    };
+
+    Ok(parse_dmidecode_memory(&out.stdout))
+}
+
+#[cfg(target_os = "linux")]
+fn parse_dmidecode_memory(text: &str) -> Vec<MemorySlot> {
+    let mut slots = Vec::new();
+    let mut current: Option<MemorySlot> = None;
+    let mut slot_index: usize = 0;
+
+    for line in text.lines() {
+        let line = line.trim();
+
+        if line.starts_with("Memory Device") {
+            if let Some(slot) = current.take() {
+                if slot.size > 0 {
+                    slots.push(slot);
+                }
+            }
+            current = Some(MemorySlot {
+                slot: slot_index,
+                ..Default::default()
+            });
+            slot_index += 1;
+        }
+
+        if let Some(ref mut slot) = current {
+            if let Some(val) = line.strip_prefix("Size:") {
+                let val = val.trim();
+                if let Some(mb_str) = val.strip_suffix("MB") {
+                    slot.size = mb_str.trim().parse::<u64>().unwrap_or(0) * 1024 * 1024;
+                } else if let Some(gb_str) = val.strip_suffix("GB") {
+                    slot.size = gb_str.trim().parse::<u64>().unwrap_or(0) * 1024 * 1024 * 1024;
+                }
+            } else if let Some(val) = line.strip_prefix("Speed:") {
+                let val = val.trim();
+                if let Some(mhz_str) = val.strip_suffix("MT/s") {
+                    slot.clock_speed = mhz_str.trim().parse().unwrap_or(0);
+                } else if let Some(mhz_str) = val.strip_suffix("MHz") {
+                    slot.clock_speed = mhz_str.trim().parse().unwrap_or(0);
+                }
+            } else if let Some(val) = line.strip_prefix("Type:") {
+                slot.mem_type = val.trim().to_string();
+            } else if let Some(val) = line.strip_prefix("Form Factor:") {
+                slot.form_factor = val.trim().to_string();
+            } else if let Some(val) = line.strip_prefix("Manufacturer:") {
+                slot.manufacturer = val.trim().to_string();
+            } else if let Some(val) = line.strip_prefix("Part Number:") {
+                slot.part_num = val.trim().to_string();
+            } else if let Some(val) = line.strip_prefix("Serial Number:") {
+                slot.serial_num = val.trim().to_string();
+            }
+        }
+    }
+
+    if let Some(slot) = current {
+        if slot.size > 0 {
+            slots.push(slot);
+        }
+    }
+
+    slots
+}
+
+// ——— Platform-specific helpers (Windows) ———
+
+#[cfg(target_os = "windows")]
+const LAYOUT_SOURCE: &str = "Win32_PhysicalMemory";
+
+#[cfg(target_os = "windows")]
+fn read_memory_layout(_ctx: &ProbeContext) -> Result<Vec<MemorySlot>, ProbeError> {
+    use crate::windows::{exec_powershell, PsMem};
+
+    let script = "@(Get-CimInstance Win32_PhysicalMemory) | Select-Object Capacity, Speed, Manufacturer, PartNumber, SerialNumber, FormFactor, MemoryType | ConvertTo-Json -Compress";
+    let ps_mems: Vec<PsMem> = exec_powershell(script)?;
+    let mut slots = Vec::new();
+
+    for (i, mem) in ps_mems.iter().enumerate() {
+        slots.push(MemorySlot {
+            slot: i,
+            size: mem.Capacity.unwrap_or(0),
+            clock_speed: mem.Speed.unwrap_or(0) as u64,
+            mem_type: format!("{}", mem.MemoryType.unwrap_or(0)),
+            form_factor: format!("{}", mem.FormFactor.unwrap_or(0)),
+            manufacturer: mem.Manufacturer.clone().unwrap_or_default(),
+            part_num: mem.PartNumber.clone().unwrap_or_default(),
+            serial_num: mem.SerialNumber.clone().unwrap_or_default(),
+        });
+    }
+    Ok(slots)
+}
@@ -0,0 +1,544 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use sysinfo::Networks;
+
+use crate::context::ProbeContext;
+#[cfg(target_os = "linux")]
+use crate::network_topology;
+#[cfg(target_os = "linux")]
+use crate::types::VethPeer;
+#[cfg(target_os = "linux")]
+use crate::{read_link_name, read_sysfs_file};
+use crate::types::{
+    DnsConfig, InterfaceAddress, NetworkInfo, NetworkInterface, NetworkIo, NetworkNamespace, ProbeError, Route,
+    VlanInfo,
+};
+
+/// Addresses and link details of one interface, as sysinfo or `ip` sees
+/// it.
+#[derive(Default)]
+struct LinkAddresses {
+    ip4: Vec<InterfaceAddress>,
+    ip6: Vec<InterfaceAddress>,
+    mac: String,
+    /// Link kind from `ip -d`, e.g. `veth` or `wireguard`.
+    info_kind: String,
+    /// Namespace of the link's peer, when that is not this one.
+    link_netnsid: Option<u32>,
+    /// VLAN id and parent from `ip -d`, which unlike `/proc/net/vlan`
+    /// needs no root.
+    vlan: Option<VlanInfo>,
+}
+
+/// Network interfaces known to the OS, with routes and resolver settings.
+pub fn collect_network(ctx: &ProbeContext) -> NetworkInfo {
+    let names = ctx.probe("network.interfaces", interface_source(ctx), || interface_names(ctx));
+    let mut addresses = ctx.probe("network.addresses", "sysinfo networks", || read_addresses(ctx));
+    merge_links(&mut addresses, ctx.probe("network.links", links_source(), || read_links(ctx)));
+    let namespaces = ctx.probe("network.namespaces", namespaces_source(), || read_namespaces(ctx));
+
+    let mut interfaces: Vec<NetworkInterface> = names
+        .into_iter()
+        .map(|name| {
+            let link = addresses.remove(&name).unwrap_or_default();
+            let mut iface = read_interface(ctx, &name, &link);
+            if iface.mac.is_empty() {
+                iface.mac = link.mac;
+            }
+            iface.ip4 = link.ip4;
+            iface.ip6 = link.ip6;
+            iface
+        })
+        .collect();
+    name_veth_peers(&mut interfaces, &namespaces);
+
+    let mut routes = ctx.probe("network.routes", routes_source(ctx, "route"), || read_ipv4_routes(ctx));
+    routes.extend(ctx.probe("network.routes6", routes_source(ctx, "ipv6_route"), || read_ipv6_routes(ctx)));
+
+    NetworkInfo {
+        interfaces,
+        ipv4_gateway: default_gateway(&routes, "ipv4"),
+        ipv6_gateway: default_gateway(&routes, "ipv6"),
+        routes,
+        dns: ctx.probe("network.dns", dns_source(ctx), || read_dns(ctx)),
+        namespaces,
+    }
+}
+
+/// Every address of every interface, keyed by interface name. None for a
+/// capture: sysinfo would describe this machine's interfaces instead.
+fn read_addresses(ctx: &ProbeContext) -> Result<HashMap<String, LinkAddresses>, ProbeError> {
+    if !ctx.is_live() {
+        return Ok(HashMap::new());
+    }
+    let networks = Networks::new_with_refreshed_list();
+    let mut map = HashMap::new();
+    for (name, data) in networks.iter() {
+        let mut entry = LinkAddresses {
+            mac: data.mac_address().to_string(),
+            ..Default::default()
+        };
+        for net in data.ip_networks() {
+            let address = InterfaceAddress {
+                address: net.addr.to_string(),
+                prefix_len: net.prefix,
+                scope: String::new(),
+            };
+            if net.addr.is_ipv4() {
+                entry.ip4.push(address);
+            } else {
+                entry.ip6.push(address);
+            }
+        }
+        map.insert(name.to_string(), entry);
+    }
+    Ok(map)
+}
+
+/// Adds the link kind, peer namespace and address scopes that only `ip`
+/// reports. Addresses `ip` lists but sysinfo did not, as with a capture, are
+/// kept too.
+fn merge_links(addresses: &mut HashMap<String, LinkAddresses>, links: HashMap<String, LinkAddresses>) {
+    for (name, link) in links {
+        let entry = addresses.entry(name).or_default();
+        entry.info_kind = link.info_kind;
+        entry.link_netnsid = link.link_netnsid;
+        entry.vlan = link.vlan;
+        if entry.mac.is_empty() {
+            entry.mac = link.mac;
+        }
+        for (known, listed) in [(&mut entry.ip4, link.ip4), (&mut entry.ip6, link.ip6)] {
+            for address in listed {
+                match known.iter_mut().find(|a| a.address == address.address) {
+                    Some(a) => a.scope = address.scope,
+                    None => known.push(address),
+                }
+            }
+        }
+    }
+}
+
+/// Fills in peer names from the interface list, or from the namespace list
+/// when the peer was moved into another namespace.
+fn name_veth_peers(interfaces: &mut [NetworkInterface], namespaces: &[NetworkNamespace]) {
+    let names: HashMap<u32, String> = interfaces.iter().map(|i| (i.ifindex, i.iface.clone())).collect();
+    for peer in interfaces.iter_mut().filter_map(|i| i.veth_peer.as_mut()) {
+        match peer.netns_id {
+            None => peer.name = names.get(&peer.ifindex).cloned().unwrap_or_default(),
+            Some(id) => {
+                peer.netns = namespaces.iter().find(|ns| ns.id == Some(id)).map(|ns| ns.name.clone()).unwrap_or_default()
+            }
+        }
+    }
+}
+
+/// The default route of `family` with the lowest metric.
+fn default_gateway(routes: &[Route], family: &str) -> Option<Route> {
+    let default = if family == "ipv4" { "0.0.0.0/0" } else { "::/0" };
+    routes
+        .iter()
+        .filter(|r| r.family == family && r.destination == default && r.table == "main")
+        .min_by_key(|r| r.metric)
+        .cloned()
+}
+
+/// Cumulative traffic counters of every interface at one instant. Two
+/// samples taken apart give rates through [`collect_network_io`].
+#[derive(Clone, Debug, Default)]
+pub struct NetworkStatsSample {
+    interfaces: Vec<InterfaceCounters>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct InterfaceCounters {
+    name: String,
+    rx_bytes: u64,
+    rx_packets: u64,
+    rx_errors: u64,
+    rx_dropped: u64,
+    tx_bytes: u64,
+    tx_packets: u64,
+    tx_errors: u64,
+    tx_dropped: u64,
+}
+
+/// Reads the counters, from `/proc/net/dev` on Linux.
+pub fn sample_network_stats(ctx: &ProbeContext) -> NetworkStatsSample {
+    ctx.probe("network.io", netdev_source(ctx), || read_network_stats(ctx))
+}
+
+/// Per-interface rates between two samples taken `elapsed` apart.
+pub fn collect_network_io(before: &NetworkStatsSample, after: &NetworkStatsSample, elapsed: Duration) -> Vec<NetworkIo> {
+    let secs = elapsed.as_secs_f64();
+    if secs <= 0.0 {
+        return Vec::new();
+    }
+
+    after
+        .interfaces
+        .iter()
+        .filter_map(|now| {
+            let prev = before.interfaces.iter().find(|i| i.name == now.name)?;
+            // Saturating: counters restart when an interface is recreated.
+            let delta = |field: fn(&InterfaceCounters) -> u64| field(now).saturating_sub(field(prev));
+            Some(NetworkIo {
+                iface: now.name.clone(),
+                rx_bytes_per_sec: delta(|i| i.rx_bytes) as f64 / secs,
+                tx_bytes_per_sec: delta(|i| i.tx_bytes) as f64 / secs,
+                rx_packets_per_sec: delta(|i| i.rx_packets) as f64 / secs,
+                tx_packets_per_sec: delta(|i| i.tx_packets) as f64 / secs,
+                rx_errors: delta(|i| i.rx_errors),
+                tx_errors: delta(|i| i.tx_errors),
+                rx_dropped: delta(|i| i.rx_dropped),
+                tx_dropped: delta(|i| i.tx_dropped),
+                rx_bytes_total: now.rx_bytes,
+                tx_bytes_total: now.tx_bytes,
+                rx_packets_total: now.rx_packets,
+                tx_packets_total: now.tx_packets,
+                rx_errors_total: now.rx_errors,
+                tx_errors_total: now.tx_errors,
+                rx_dropped_total: now.rx_dropped,
+                tx_dropped_total: now.tx_dropped,
+            })
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn interface_source(ctx: &ProbeContext) -> String {
+    ctx.sys_path("class/net").display().to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn interface_source(_ctx: &ProbeContext) -> String {
+    "sysinfo networks".to_string()
+}
+
+/// Interface names, sorted. On Linux these are the entries of
+/// `/sys/class/net`, the same list sysinfo would report.
+#[cfg(target_os = "linux")]
+pub(crate) fn interface_names(ctx: &ProbeContext) -> Result<Vec<String>, ProbeError> {
+    let dir = ctx.sys_path("class/net");
+    let mut names: Vec<String> = std::fs::read_dir(&dir)
+        .map_err(|e| ProbeError::from_io(&dir, &e))?
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn interface_names(_ctx: &ProbeContext) -> Result<Vec<String>, ProbeError> {
+    let networks = Networks::new_with_refreshed_list();
+    let mut names: Vec<String> = networks.iter().map(|(name, _)| name.to_string()).collect();
+    names.sort();
+    Ok(names)
+}
+
+// ——— Platform-specific helpers (Linux) ———
+
+#[cfg(target_os = "linux")]
+const IP_ARGS: [&str; 4] = ["-j", "-d", "address", "show"];
+
+/// `ARPHRD_LOOPBACK` in `/sys/class/net/<if>/type`.
+#[cfg(target_os = "linux")]
+const ARPHRD_LOOPBACK: &str = "772";
+
+/// `IFF_TAP` in `tun_flags`; tun devices have `IFF_TUN` (0x1) instead.
+#[cfg(target_os = "linux")]
+const IFF_TAP: u32 = 0x0002;
+
+#[cfg(target_os = "linux")]
+fn links_source() -> String {
+    format!("ip {}", IP_ARGS.join(" "))
+}
+
+/// Link attributes from `/sys/class/net/<name>`.
+#[cfg(target_os = "linux")]
+fn read_interface(ctx: &ProbeContext, name: &str, link: &LinkAddresses) -> NetworkInterface {
+    let dir = ctx.sys_path("class/net").join(name);
+    let attr = |rel: &str| read_sysfs_file(dir.join(rel));
+
+    let kind = interface_kind(&dir, &link.info_kind);
+
+    // carrier, speed and duplex fail with EINVAL while the link is down,
+    // which reads as empty here.
+    NetworkInterface {
+        iface: name.to_string(),
+        ifindex: attr("ifindex").parse().unwrap_or(0),
+        wireless: (kind == "wireless").then(|| crate::wireless::read_wireless(ctx, name)),
+        master: read_link_name(dir.join("master")).unwrap_or_default(),
+        ports: network_topology::read_ports(&dir),
+        bond: network_topology::read_bond(&dir),
+        vlan: (kind == "vlan").then(|| network_topology::read_vlan(ctx, &dir, name, link.vlan.clone())),
+        // iflink of a veth is its peer's ifindex.
+        veth_peer: (kind == "veth").then(|| VethPeer {
+            ifindex: attr("iflink").parse().unwrap_or(0),
+            netns_id: link.link_netnsid,
+            ..Default::default()
+        }),
+        kind,
+        mac: attr("address"),
+        mtu: attr("mtu").parse().unwrap_or(0),
+        operstate: attr("operstate"),
+        carrier: match attr("carrier").as_str() {
+            "1" => Some(true),
+            "0" => Some(false),
+            _ => None,
+        },
+        // -1 when the driver does not know
+        speed_mbps: attr("speed").parse().ok(),
+        duplex: attr("duplex"),
+        driver: read_link_name(dir.join("device/driver")).unwrap_or_default(),
+        parent_bus: read_link_name(dir.join("device/subsystem")).unwrap_or_default(),
+        parent_device: read_link_name(dir.join("device")).unwrap_or_default(),
+        ..Default::default()
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn interface_kind(dir: &std::path::Path, info_kind: &str) -> String {
+    let uevent = read_sysfs_file(dir.join("uevent"));
+    let devtype = uevent.lines().find_map(|line| line.strip_prefix("DEVTYPE=")).unwrap_or("");
+    let tun_flags = read_sysfs_file(dir.join("tun_flags"));
+
+    let kind = if read_sysfs_file(dir.join("type")) == ARPHRD_LOOPBACK {
+        "loopback"
+    } else if devtype == "wlan" || dir.join("wireless").is_dir() || dir.join("phy80211").exists() {
+        "wireless"
+    } else if dir.join("bridge").is_dir() {
+        "bridge"
+    } else if dir.join("bonding").is_dir() {
+        "bond"
+    } else if devtype == "vlan" {
+        "vlan"
+    } else if !tun_flags.is_empty() {
+        let flags = u32::from_str_radix(tun_flags.trim_start_matches("0x"), 16).unwrap_or(0);
+        if flags & IFF_TAP != 0 {
+            "tap"
+        } else {
+            "tun"
+        }
+    } else if dir.join("device").exists() {
+        "physical"
+    } else if !info_kind.is_empty() {
+        // veth, wireguard, vxlan, …: nothing in sysfs tells these apart.
+        info_kind
+    } else if !devtype.is_empty() {
+        devtype
+    } else {
+        "virtual"
+    };
+    kind.to_string()
+}
+
+/// Link details and addresses of every interface, keyed by interface name.
+#[cfg(target_os = "linux")]
+fn read_links(ctx: &ProbeContext) -> Result<HashMap<String, LinkAddresses>, ProbeError> {
+    let out = ctx.run_checked("ip", &IP_ARGS)?;
+    parse_ip_addresses(&out.stdout).map_err(|message| ProbeError::ParseFailure {
+        source: links_source(),
+        message,
+    })
+}
+
+/// `ip -j -d address show`: one object per link with an `addr_info` array.
+#[cfg(target_os = "linux")]
+fn parse_ip_addresses(json: &str) -> Result<HashMap<String, LinkAddresses>, String> {
+    let links: Vec<serde_json::Value> = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let text = |value: &serde_json::Value, key: &str| value[key].as_str().unwrap_or_default().to_string();
+
+    let mut map = HashMap::new();
+    for link in links {
+        let linkinfo = &link["linkinfo"];
+        let vlan_id = (linkinfo["info_kind"] == "vlan").then(|| linkinfo["info_data"]["id"].as_u64()).flatten();
+        let mut entry = LinkAddresses {
+            mac: text(&link, "address"),
+            info_kind: text(linkinfo, "info_kind"),
+            link_netnsid: link["link_netnsid"].as_u64().map(|id| id as u32),
+            vlan: vlan_id.map(|id| VlanInfo { id: id as u16, parent: text(&link, "link") }),
+            ..Default::default()
+        };
+        for addr in link["addr_info"].as_array().into_iter().flatten() {
+            let address = InterfaceAddress {
+                address: text(addr, "local"),
+                prefix_len: addr["prefixlen"].as_u64().unwrap_or(0) as u8,
+                scope: text(addr, "scope"),
+            };
+            match addr["family"].as_str() {
+                Some("inet") => entry.ip4.push(address),
+                Some("inet6") => entry.ip6.push(address),
+                _ => {}
+            }
+        }
+        map.insert(text(&link, "ifname"), entry);
+    }
+    Ok(map)
+}
+
+#[cfg(target_os = "linux")]
+fn namespaces_source() -> String {
+    "ip -j netns list".to_string()
+}
+
+/// Named namespaces; `ip` prints nothing at all when there are none.
+#[cfg(target_os = "linux")]
+fn read_namespaces(ctx: &ProbeContext) -> Result<Vec<NetworkNamespace>, ProbeError> {
+    let out = ctx.run_checked("ip", &["-j", "netns", "list"])?;
+    if out.stdout.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let entries: Vec<serde_json::Value> = serde_json::from_str(&out.stdout)
+        .map_err(|e| ProbeError::ParseFailure { source: namespaces_source(), message: e.to_string() })?;
+    Ok(entries
+        .iter()
+        .map(|ns| NetworkNamespace {
+            name: ns["name"].as_str().unwrap_or_default().to_string(),
+            id: ns["id"].as_u64().map(|id| id as u32),
+        })
+        .collect())
+}
+
+#[cfg(target_os = "linux")]
+fn routes_source(ctx: &ProbeContext, file: &str) -> String {
+    ctx.proc_path("net").join(file).display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn read_ipv4_routes(ctx: &ProbeContext) -> Result<Vec<Route>, ProbeError> {
+    crate::routing::read_ipv4_routes(ctx)
+}
+
+#[cfg(target_os = "linux")]
+fn read_ipv6_routes(ctx: &ProbeContext) -> Result<Vec<Route>, ProbeError> {
+    crate::routing::read_ipv6_routes(ctx)
+}
+
+#[cfg(target_os = "linux")]
+fn dns_source(ctx: &ProbeContext) -> String {
+    ctx.etc_path("resolv.conf").display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn read_dns(ctx: &ProbeContext) -> Result<DnsConfig, ProbeError> {
+    crate::routing::read_dns(ctx)
+}
+
+#[cfg(target_os = "linux")]
+fn netdev_source(ctx: &ProbeContext) -> String {
+    ctx.proc_path("net/dev").display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn read_network_stats(ctx: &ProbeContext) -> Result<NetworkStatsSample, ProbeError> {
+    let path = ctx.proc_path("net/dev");
+    let text = std::fs::read_to_string(&path).map_err(|e| ProbeError::from_io(&path, &e))?;
+    // Two header lines, then `name: rx fields… tx fields…`.
+    Ok(NetworkStatsSample {
+        interfaces: text.lines().skip(2).filter_map(parse_netdev_line).collect(),
+    })
+}
+
+/// `  eth0: bytes packets errs drop fifo frame compressed multicast` for
+/// receive, then `bytes packets errs drop fifo colls carrier compressed`
+/// for transmit.
+#[cfg(target_os = "linux")]
+fn parse_netdev_line(line: &str) -> Option<InterfaceCounters> {
+    let (name, counters) = line.split_once(':')?;
+    let fields: Vec<u64> = counters.split_whitespace().map(|f| f.parse().ok()).collect::<Option<_>>()?;
+    if fields.len() < 16 {
+        return None;
+    }
+    Some(InterfaceCounters {
+        name: name.trim().to_string(),
+        rx_bytes: fields[0],
+        rx_packets: fields[1],
+        rx_errors: fields[2],
+        rx_dropped: fields[3],
+        tx_bytes: fields[8],
+        tx_packets: fields[9],
+        tx_errors: fields[10],
+        tx_dropped: fields[11],
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn links_source() -> String {
+    "none".to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_interface(_ctx: &ProbeContext, name: &str, _link: &LinkAddresses) -> NetworkInterface {
+    NetworkInterface {
+        iface: name.to_string(),
+        ..Default::default()
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_links(_ctx: &ProbeContext) -> Result<HashMap<String, LinkAddresses>, ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn netdev_source(_ctx: &ProbeContext) -> String {
+    "sysinfo networks".to_string()
+}
+
+/// sysinfo has no drop counters; those stay at zero.
+#[cfg(not(target_os = "linux"))]
+fn read_network_stats(_ctx: &ProbeContext) -> Result<NetworkStatsSample, ProbeError> {
+    let networks = Networks::new_with_refreshed_list();
+    let mut interfaces: Vec<InterfaceCounters> = networks
+        .iter()
+        .map(|(name, data)| InterfaceCounters {
+            name: name.to_string(),
+            rx_bytes: data.total_received(),
+            rx_packets: data.total_packets_received(),
+            rx_errors: data.total_errors_on_received(),
+            tx_bytes: data.total_transmitted(),
+            tx_packets: data.total_packets_transmitted(),
+            tx_errors: data.total_errors_on_transmitted(),
+            ..Default::default()
+        })
+        .collect();
+    interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(NetworkStatsSample { interfaces })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn routes_source(_ctx: &ProbeContext, _file: &str) -> String {
+    "none".to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_ipv4_routes(_ctx: &ProbeContext) -> Result<Vec<Route>, ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_ipv6_routes(_ctx: &ProbeContext) -> Result<Vec<Route>, ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn dns_source(_ctx: &ProbeContext) -> String {
+    "none".to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_dns(_ctx: &ProbeContext) -> Result<DnsConfig, ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn namespaces_source() -> String {
+    "none".to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_namespaces(_ctx: &ProbeContext) -> Result<Vec<NetworkNamespace>, ProbeError> {
+    Err(ProbeError::unsupported())
+}
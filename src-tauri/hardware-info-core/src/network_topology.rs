@@ -0,0 +1,59 @@
+//! How virtual interfaces hang together: bridge ports, bond slaves and
+//! VLANs, from the per-interface directories in `/sys/class/net`.
+
+use std::path::Path;
+
+use crate::context::ProbeContext;
+use crate::read_sysfs_file;
+use crate::types::{BondInfo, VlanInfo};
+
+/// Ports of a bridge or slaves of a bond, sorted; empty for anything else.
+pub(crate) fn read_ports(dir: &Path) -> Vec<String> {
+    // A bridge has one `brif` entry per port.
+    let mut ports: Vec<String> = std::fs::read_dir(dir.join("brif"))
+        .map(|entries| entries.flatten().map(|e| e.file_name().to_string_lossy().to_string()).collect())
+        .unwrap_or_default();
+    ports.extend(read_sysfs_file(dir.join("bonding/slaves")).split_whitespace().map(str::to_string));
+    ports.sort();
+    ports
+}
+
+pub(crate) fn read_bond(dir: &Path) -> Option<BondInfo> {
+    let bonding = dir.join("bonding");
+    if !bonding.is_dir() {
+        return None;
+    }
+    let attr = |rel: &str| read_sysfs_file(bonding.join(rel));
+    Some(BondInfo {
+        // `active-backup 1`: the name, then the numeric mode.
+        mode: attr("mode").split_whitespace().next().unwrap_or_default().to_string(),
+        active_slave: attr("active_slave"),
+        mii_status: attr("mii_status"),
+    })
+}
+
+/// Prefers what `ip -d` reported. Otherwise the 8021q module lists every
+/// VLAN with its id and parent in `/proc/net/vlan/config`, readable by root
+/// only; the `lower_*` link names the parent when that file is unreadable.
+pub(crate) fn read_vlan(ctx: &ProbeContext, dir: &Path, name: &str, from_ip: Option<VlanInfo>) -> VlanInfo {
+    if let Some(vlan) = from_ip {
+        return vlan;
+    }
+    let config = std::fs::read_to_string(ctx.proc_path("net/vlan/config")).unwrap_or_default();
+    // eth0.100  | 100  | eth0
+    let entry = config.lines().find_map(|line| {
+        let fields: Vec<&str> = line.split('|').map(str::trim).collect();
+        (fields.len() == 3 && fields[0] == name).then(|| (fields[1].parse().unwrap_or(0), fields[2].to_string()))
+    });
+    let (id, parent) = entry.unwrap_or_default();
+    VlanInfo { id, parent: if parent.is_empty() { lower_device(dir) } else { parent } }
+}
+
+fn lower_device(dir: &Path) -> String {
+    std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .find_map(|e| Some(e.file_name().to_string_lossy().strip_prefix("lower_")?.to_string()))
+        .unwrap_or_default()
+}
@@ -0,0 +1,59 @@
+use crate::context::ProbeContext;
+use crate::types::{OpticalInfo, ProbeError};
+#[cfg(target_os = "linux")]
+use crate::types::OpticalDevice;
+
+/// CD/DVD/Blu-ray drives, from `/proc/sys/dev/cdrom/info` on Linux.
+#[cfg(target_os = "linux")]
+pub fn collect_optical(ctx: &ProbeContext) -> OpticalInfo {
+    let source = ctx.proc_path("sys/dev/cdrom/info");
+    let devices = ctx.probe("optical", source.display(), || read_optical_devices(ctx));
+    OpticalInfo { devices }
+}
+
+#[cfg(target_os = "linux")]
+fn read_optical_devices(ctx: &ProbeContext) -> Result<Vec<OpticalDevice>, ProbeError> {
+    let mut devices = Vec::new();
+    // Try to read from procfs. The file only exists once the cdrom driver is
+    // loaded, so its absence just means there is no drive.
+    let path = ctx.proc_path("sys/dev/cdrom/info");
+    let info = match std::fs::read_to_string(&path) {
+        Ok(info) => Some(info),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(ProbeError::from_io(&path, &e)),
+    };
+    if let Some(info) = info {
+        let mut drive_name = String::new();
+        // The file format is a bit weird, with labels followed by values for each drive
+        for line in info.lines() {
+            if line.starts_with("drive name:") {
+                drive_name = line.split(':').nth(1).unwrap_or("").trim().to_string();
+            }
+            if !drive_name.is_empty() && line.starts_with("drive model:") {
+                let model = line.split(':').nth(1).unwrap_or("").trim().to_string();
+                devices.push(OpticalDevice {
+                    name: drive_name.clone(),
+                    model,
+                    vendor: "Unknown".to_string(),
+                });
+                drive_name = String::new(); // Reset for multiple drives
+            }
+        }
+    }
+
+    // Fallback: check /dev/sr0 if procfs was empty/failed to parse correctly
+    if devices.is_empty() && ctx.dev_path("sr0").exists() {
+        devices.push(OpticalDevice {
+            name: "sr0".to_string(),
+            model: "CD/DVD Drive".to_string(),
+            vendor: "Unknown".to_string(),
+        });
+    }
+
+    Ok(devices)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn collect_optical(ctx: &ProbeContext) -> OpticalInfo {
+    ctx.probe("optical", "none", || Err(ProbeError::unsupported()))
+}
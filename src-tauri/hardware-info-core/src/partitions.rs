@@ -0,0 +1,390 @@
+//! GPT and MBR partition table decoding.
+//!
+//! Reads anything laid out like a disk: a block device under `/dev` or a raw
+//! image file. Only the primary GPT header is used; one that fails its
+//! checksum is reported rather than silently replaced by the backup copy.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use crate::types::{Partition, PartitionTable, ProbeError};
+
+/// Caps on the GPT entry array, so a corrupt header cannot ask for
+/// gigabytes. Partitioning tools create 128 entries of 128 bytes.
+const MAX_GPT_ENTRIES: u32 = 4096;
+const MAX_GPT_ENTRY_SIZE: usize = 4096;
+
+/// Largest logical block size accepted; Linux allows up to 64 KiB.
+const MAX_SECTOR_SIZE: u32 = 65536;
+
+/// Cap on the extended boot record chain, which may loop on corrupt disks.
+const MAX_LOGICAL_PARTITIONS: u32 = 256;
+
+/// Decodes the partition table at `path`, with LBAs of `sector_size`
+/// bytes. `Ok(None)` when the disk has neither a GPT nor an MBR.
+///
+/// `sector_size` must be a power of two from 512 to 64 KiB.
+pub fn read_partition_table(
+    path: impl AsRef<Path>,
+    sector_size: u32,
+) -> Result<Option<PartitionTable>, ProbeError> {
+    let path = path.as_ref();
+    if !sector_size.is_power_of_two() || !(512..=MAX_SECTOR_SIZE).contains(&sector_size) {
+        return Err(ProbeError::ParseFailure {
+            source: path.display().to_string(),
+            message: format!("unsupported sector size {}", sector_size),
+        });
+    }
+    let file = File::open(path).map_err(|e| ProbeError::from_io(path, &e))?;
+    let mut disk = Disk { file, path, sector_size };
+
+    let mbr = disk.read(0, 512)?;
+    let entries = mbr_entries(&mbr);
+    // Boot indicators other than 0x00/0x80 mean a boot sector or random
+    // data, not a partition table.
+    if mbr[510..512] != [0x55, 0xAA] || entries.iter().any(|e| e.boot & 0x7F != 0) {
+        return Ok(None);
+    }
+    // A GPT disk carries a protective MBR with a single 0xEE entry.
+    if entries.iter().any(|e| e.system_id == 0xEE) {
+        return read_gpt(&mut disk).map(Some);
+    }
+    read_mbr(&mut disk, &mbr, &entries).map(Some)
+}
+
+struct Disk<'a> {
+    file: File,
+    path: &'a Path,
+    sector_size: u32,
+}
+
+impl Disk<'_> {
+    fn read(&mut self, lba: u64, len: usize) -> Result<Vec<u8>, ProbeError> {
+        let offset = lba
+            .checked_mul(self.sector_size as u64)
+            .ok_or_else(|| self.corrupt("LBA beyond the end of any disk"))?;
+        let mut buf = vec![0; len];
+        self.file
+            .seek(SeekFrom::Start(offset))
+            .and_then(|_| self.file.read_exact(&mut buf))
+            .map_err(|e| ProbeError::from_io(self.path, &e))?;
+        Ok(buf)
+    }
+
+    fn corrupt(&self, message: &str) -> ProbeError {
+        ProbeError::ParseFailure {
+            source: self.path.display().to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+// ——— GPT ———
+
+fn read_gpt(disk: &mut Disk) -> Result<PartitionTable, ProbeError> {
+    let header = disk.read(1, disk.sector_size as usize)?;
+    if &header[0..8] != b"EFI PART" {
+        return Err(disk.corrupt("protective MBR but no GPT header at LBA 1"));
+    }
+    let header_size = le32(&header, 12) as usize;
+    if header_size < 92 || header_size > header.len() {
+        return Err(disk.corrupt("GPT header size out of range"));
+    }
+    // The checksum covers the header with its own CRC field zeroed.
+    let mut checked = header[..header_size].to_vec();
+    checked[16..20].fill(0);
+    if crc32(&checked) != le32(&header, 16) {
+        return Err(disk.corrupt("GPT header checksum mismatch"));
+    }
+
+    let entries_lba = le64(&header, 72);
+    let count = le32(&header, 80);
+    let entry_size = le32(&header, 84) as usize;
+    // The spec requires 128 × 2^n bytes; a multiple of 8 is what the
+    // kernel's own parser insists on.
+    if count > MAX_GPT_ENTRIES || !(128..=MAX_GPT_ENTRY_SIZE).contains(&entry_size) || !entry_size.is_multiple_of(8) {
+        return Err(disk.corrupt("GPT partition entry array out of range"));
+    }
+    let entries = disk.read(entries_lba, count as usize * entry_size)?;
+    if crc32(&entries) != le32(&header, 88) {
+        return Err(disk.corrupt("GPT partition entry checksum mismatch"));
+    }
+
+    let sector_size = disk.sector_size;
+    let partitions = entries
+        .chunks_exact(entry_size)
+        .enumerate()
+        // Unused slots have an all-zero type GUID.
+        .filter(|(_, entry)| entry[0..16].iter().any(|&b| b != 0))
+        .filter_map(|(i, entry)| gpt_partition(i as u32 + 1, entry, sector_size))
+        .collect();
+
+    Ok(PartitionTable {
+        scheme: "GPT".to_string(),
+        disk_id: guid(&header[56..72]),
+        sector_size,
+        partitions,
+    })
+}
+
+/// `None` for an entry whose extent is inverted or overflows, which no
+/// partitioning tool writes.
+fn gpt_partition(number: u32, entry: &[u8], sector_size: u32) -> Option<Partition> {
+    let type_id = guid(&entry[0..16]);
+    let start_lba = le64(entry, 32);
+    let end_lba = le64(entry, 40);
+    let size = end_lba
+        .checked_add(1)
+        .and_then(|end| end.checked_sub(start_lba))
+        .filter(|&sectors| sectors > 0)
+        .and_then(|sectors| sectors.checked_mul(sector_size as u64))?;
+    // Name is UTF-16LE, NUL-padded to 36 code units.
+    let label: Vec<u16> = entry[56..128]
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .take_while(|&c| c != 0)
+        .collect();
+
+    Some(Partition {
+        number,
+        start_lba,
+        end_lba,
+        size,
+        type_name: gpt_type_name(&type_id).to_string(),
+        type_id,
+        label: String::from_utf16_lossy(&label),
+        uuid: guid(&entry[16..32]),
+        flags: gpt_flags(le64(entry, 48)),
+    })
+}
+
+/// Bits 0–2 are defined for every partition. The top bits are type
+/// specific, but Microsoft basic data and the Linux Discoverable Partitions
+/// types agree on read-only (60) and no-automount (63).
+fn gpt_flags(attributes: u64) -> Vec<String> {
+    [
+        (0, "required"),
+        (1, "no-block-io"),
+        (2, "legacy-boot"),
+        (60, "read-only"),
+        (62, "hidden"),
+        (63, "no-automount"),
+    ]
+    .into_iter()
+    .filter(|(bit, _)| attributes & (1 << bit) != 0)
+    .map(|(_, name)| name.to_string())
+    .collect()
+}
+
+fn gpt_type_name(type_id: &str) -> &'static str {
+    match type_id {
+        "c12a7328-f81f-11d2-ba4b-00a0c93ec93b" => "EFI System",
+        "21686148-6449-6e6f-744e-656564454649" => "BIOS boot",
+        "0fc63daf-8483-4772-8e79-3d69d8477de4" => "Linux filesystem",
+        "4f68bce3-e8cd-4db1-96e7-fbcaf984b709" => "Linux root (x86-64)",
+        "b921b045-1df0-41c3-af44-4c6f280d3fae" => "Linux root (ARM-64)",
+        "933ac7e1-2eb4-4f13-b844-0e14e2aef915" => "Linux home",
+        "bc13c2ff-59e6-4262-a352-b275fd6f7172" => "Linux extended boot",
+        "0657fd6d-a4ab-43c4-84e5-0933c84b4f4f" => "Linux swap",
+        "e6d6d379-f507-44c2-a23c-238f2a3df928" => "Linux LVM",
+        "a19d880f-05fc-4d3b-a006-743f0f84911e" => "Linux RAID",
+        "ca7d7ccb-63ed-4c53-861c-1742536059cc" => "Linux LUKS",
+        "ebd0a0a2-b9e5-4433-87c0-68b6b72699c7" => "Microsoft basic data",
+        "e3c9e316-0b5c-4db8-817d-f92df00215ae" => "Microsoft reserved",
+        "de94bba4-06d1-4d40-a16a-bfd50179d6ac" => "Windows recovery environment",
+        "48465300-0000-11aa-aa11-00306543ecac" => "Apple HFS/HFS+",
+        "7c3457ef-0000-11aa-aa11-00306543ecac" => "Apple APFS",
+        _ => "",
+    }
+}
+
+// ——— MBR ———
+
+struct MbrEntry {
+    boot: u8,
+    system_id: u8,
+    start: u32,
+    sectors: u32,
+}
+
+fn mbr_entries(sector: &[u8]) -> Vec<MbrEntry> {
+    sector[446..510]
+        .chunks_exact(16)
+        .map(|e| MbrEntry {
+            boot: e[0],
+            system_id: e[4],
+            start: le32(e, 8),
+            sectors: le32(e, 12),
+        })
+        .collect()
+}
+
+fn read_mbr(disk: &mut Disk, mbr: &[u8], entries: &[MbrEntry]) -> Result<PartitionTable, ProbeError> {
+    let signature = le32(mbr, 440);
+    let mut partitions = Vec::new();
+    let mut logical = Vec::new();
+
+    for (i, entry) in entries.iter().enumerate() {
+        if entry.system_id == 0 {
+            continue;
+        }
+        partitions.push(mbr_partition(i as u32 + 1, entry, 0, signature, disk.sector_size));
+        if is_extended(entry.system_id) {
+            logical = read_logical_partitions(disk, entry.start as u64, signature)?;
+        }
+    }
+    partitions.extend(logical);
+
+    Ok(PartitionTable {
+        scheme: "MBR".to_string(),
+        disk_id: format!("{:08x}", signature),
+        sector_size: disk.sector_size,
+        partitions,
+    })
+}
+
+/// Follows the extended boot record chain. Each EBR holds one logical
+/// partition (relative to the EBR) and a link to the next EBR (relative to
+/// the start of the extended partition).
+fn read_logical_partitions(disk: &mut Disk, extended_start: u64, signature: u32) -> Result<Vec<Partition>, ProbeError> {
+    let mut partitions = Vec::new();
+    let mut ebr_lba = extended_start;
+
+    for number in 5..5 + MAX_LOGICAL_PARTITIONS {
+        let ebr = disk.read(ebr_lba, 512)?;
+        if ebr[510..512] != [0x55, 0xAA] {
+            break;
+        }
+        let entries = mbr_entries(&ebr);
+        if entries[0].system_id != 0 {
+            partitions.push(mbr_partition(number, &entries[0], ebr_lba, signature, disk.sector_size));
+        }
+        let next = &entries[1];
+        if next.system_id == 0 || next.start == 0 {
+            break;
+        }
+        ebr_lba = extended_start + next.start as u64;
+    }
+    Ok(partitions)
+}
+
+fn mbr_partition(number: u32, entry: &MbrEntry, base_lba: u64, signature: u32, sector_size: u32) -> Partition {
+    let start_lba = base_lba + entry.start as u64;
+    Partition {
+        number,
+        start_lba,
+        end_lba: (start_lba + entry.sectors as u64).saturating_sub(1),
+        size: entry.sectors as u64 * sector_size as u64,
+        type_id: format!("0x{:02x}", entry.system_id),
+        type_name: mbr_type_name(entry.system_id).to_string(),
+        label: String::new(),
+        // Same PARTUUID blkid derives for MBR disks.
+        uuid: format!("{:08x}-{:02x}", signature, number),
+        flags: if entry.boot == 0x80 { vec!["boot".to_string()] } else { Vec::new() },
+    }
+}
+
+fn is_extended(system_id: u8) -> bool {
+    matches!(system_id, 0x05 | 0x0f | 0x85)
+}
+
+/// Names as `fdisk` prints them.
+fn mbr_type_name(system_id: u8) -> &'static str {
+    match system_id {
+        0x01 => "FAT12",
+        0x04 => "FAT16 <32M",
+        0x05 => "Extended",
+        0x06 => "FAT16",
+        0x07 => "HPFS/NTFS/exFAT",
+        0x0b => "W95 FAT32",
+        0x0c => "W95 FAT32 (LBA)",
+        0x0e => "W95 FAT16 (LBA)",
+        0x0f => "W95 Ext'd (LBA)",
+        0x27 => "Hidden NTFS WinRE",
+        0x82 => "Linux swap",
+        0x83 => "Linux",
+        0x85 => "Linux extended",
+        0x8e => "Linux LVM",
+        0xef => "EFI (FAT-12/16/32)",
+        0xfd => "Linux raid autodetect",
+        _ => "",
+    }
+}
+
+// ——— udev ———
+
+/// Scheme for udev's `ID_PART_TABLE_TYPE`, named as the decoders above name
+/// it.
+pub(crate) fn udev_scheme(table_type: &str) -> &'static str {
+    match table_type {
+        "gpt" => "GPT",
+        "dos" => "MBR",
+        _ => "",
+    }
+}
+
+/// Fills in type, label, UUID and flags from the `ID_PART_ENTRY_*`
+/// properties udev's blkid builtin records, in the form decoding the table
+/// itself gives them.
+pub(crate) fn apply_udev_entry(partition: &mut Partition, scheme: &str, properties: &HashMap<String, String>) {
+    let property = |key: &str| properties.get(key).map(String::as_str).unwrap_or_default();
+    let flags = u64::from_str_radix(property("ID_PART_ENTRY_FLAGS").trim_start_matches("0x"), 16).unwrap_or(0);
+    partition.uuid = property("ID_PART_ENTRY_UUID").to_string();
+    partition.label = property("ID_PART_ENTRY_NAME").to_string();
+    match scheme {
+        "GPT" => {
+            partition.type_id = property("ID_PART_ENTRY_TYPE").to_string();
+            partition.type_name = gpt_type_name(&partition.type_id).to_string();
+            partition.flags = gpt_flags(flags);
+        }
+        "MBR" => {
+            let Ok(system_id) = u8::from_str_radix(property("ID_PART_ENTRY_TYPE").trim_start_matches("0x"), 16) else {
+                return;
+            };
+            partition.type_id = format!("0x{:02x}", system_id);
+            partition.type_name = mbr_type_name(system_id).to_string();
+            if flags & 0x80 != 0 {
+                partition.flags = vec!["boot".to_string()];
+            }
+        }
+        _ => {}
+    }
+}
+
+// ——— Byte helpers ———
+
+fn le32(b: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([b[offset], b[offset + 1], b[offset + 2], b[offset + 3]])
+}
+
+fn le64(b: &[u8], offset: usize) -> u64 {
+    (le32(b, offset + 4) as u64) << 32 | le32(b, offset) as u64
+}
+
+/// GUIDs store their first three fields little-endian and the rest as is.
+fn guid(b: &[u8]) -> String {
+    let tail: String = b[10..16].iter().map(|x| format!("{:02x}", x)).collect();
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{}",
+        le32(b, 0),
+        u16::from_le_bytes([b[4], b[5]]),
+        u16::from_le_bytes([b[6], b[7]]),
+        b[8],
+        b[9],
+        tail
+    )
+}
+
+/// CRC-32 (IEEE 802.3), as GPT uses for its header and entry array.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in bytes {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
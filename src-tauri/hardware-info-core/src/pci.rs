@@ -0,0 +1,115 @@
+#[cfg(target_os = "linux")]
+use std::path::{Path, PathBuf};
+
+use crate::context::ProbeContext;
+#[cfg(target_os = "linux")]
+use crate::ids::IdsDatabase;
+#[cfg(target_os = "linux")]
+use crate::{read_link_name, read_sysfs_file};
+#[cfg(target_os = "linux")]
+use crate::types::PciDevice;
+use crate::types::{PciInfo, ProbeError};
+
+/// Every device on the PCI bus, with names resolved from `pci.ids`.
+///
+/// On Linux this walks `/sys/bus/pci/devices`, so it does not need pciutils.
+pub fn collect_pci(ctx: &ProbeContext) -> PciInfo {
+    PciInfo {
+        devices: ctx.probe("pci", pci_source(ctx), || read_pci_devices(ctx)),
+    }
+}
+
+// ——— Platform-specific helpers (Linux) ———
+
+#[cfg(target_os = "linux")]
+const BUNDLED_PCI_IDS: &str = include_str!("../data/pci.ids");
+
+#[cfg(target_os = "linux")]
+fn pci_source(ctx: &ProbeContext) -> String {
+    ctx.sys_path("bus/pci/devices").display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn read_pci_devices(ctx: &ProbeContext) -> Result<Vec<PciDevice>, ProbeError> {
+    let dir = ctx.sys_path("bus/pci/devices");
+    let mut addresses: Vec<String> = std::fs::read_dir(&dir)
+        .map_err(|e| ProbeError::from_io(&dir, &e))?
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    addresses.sort();
+
+    let ids = IdsDatabase::load(ctx, "pci.ids", BUNDLED_PCI_IDS);
+    Ok(addresses
+        .into_iter()
+        .map(|address| read_pci_device(&dir.join(&address), address, &ids))
+        .collect())
+}
+
+#[cfg(target_os = "linux")]
+fn read_pci_device(dir: &Path, address: String, ids: &IdsDatabase) -> PciDevice {
+    // class is 0xCCSSPP: base class, subclass, programming interface
+    let class = read_hex(dir.join("class"));
+    let base = (class >> 16) as u8;
+    let sub = (class >> 8) as u8;
+    let vendor = read_hex(dir.join("vendor")) as u16;
+    let device = read_hex(dir.join("device")) as u16;
+    let sub_vendor = read_hex(dir.join("subsystem_vendor")) as u16;
+    let sub_device = read_hex(dir.join("subsystem_device")) as u16;
+
+    // Same fallbacks lspci prints for IDs the database does not know.
+    let vendor_name = ids
+        .vendor(vendor)
+        .map(String::from)
+        .unwrap_or_else(|| format!("Vendor {:04x}", vendor));
+    let device_name = ids
+        .device(vendor, device)
+        .map(String::from)
+        .unwrap_or_else(|| format!("Device {:04x}", device));
+    let (subsystem_vendor, subsystem) = if sub_vendor == 0 {
+        (String::new(), String::new())
+    } else {
+        (
+            ids.vendor(sub_vendor).unwrap_or_default().to_string(),
+            ids.subsystem(vendor, device, sub_vendor, sub_device).unwrap_or_default().to_string(),
+        )
+    };
+
+    PciDevice {
+        address,
+        class_id: format!("{:02x}{:02x}", base, sub),
+        prog_if: format!("{:02x}", class as u8),
+        class_name: ids.class(base).unwrap_or_default().to_string(),
+        subclass_name: ids.subclass(base, sub).unwrap_or_default().to_string(),
+        vendor_id: format!("{:04x}", vendor),
+        device_id: format!("{:04x}", device),
+        subsystem_vendor_id: format!("{:04x}", sub_vendor),
+        subsystem_device_id: format!("{:04x}", sub_device),
+        revision: format!("{:02x}", read_hex(dir.join("revision"))),
+        vendor: vendor_name,
+        device: device_name,
+        subsystem_vendor,
+        subsystem,
+        driver: read_link_name(dir.join("driver")).unwrap_or_default(),
+        iommu_group: read_link_name(dir.join("iommu_group")).and_then(|g| g.parse().ok()),
+        // -1 when the platform has no NUMA affinity for the slot
+        numa_node: read_sysfs_file(dir.join("numa_node")).parse().ok(),
+    }
+}
+
+/// Parses a sysfs attribute like `0x10de`; 0 when missing.
+#[cfg(target_os = "linux")]
+fn read_hex(path: PathBuf) -> u32 {
+    let text = read_sysfs_file(path);
+    u32::from_str_radix(text.trim_start_matches("0x"), 16).unwrap_or(0)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn pci_source(_ctx: &ProbeContext) -> String {
+    "none".to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_pci_devices(_ctx: &ProbeContext) -> Result<Vec<crate::types::PciDevice>, ProbeError> {
+    Err(ProbeError::unsupported())
+}
@@ -0,0 +1,178 @@
+#[cfg(target_os = "linux")]
+use std::path::Path;
+
+use crate::context::ProbeContext;
+#[cfg(target_os = "linux")]
+use crate::ids::IdsDatabase;
+#[cfg(target_os = "linux")]
+use crate::{read_link_name, read_sysfs_file};
+#[cfg(target_os = "linux")]
+use crate::types::{UsbDevice, UsbInterface, UsbNode};
+use crate::types::{PeripheralInfo, ProbeError};
+
+/// Attached USB devices, from `/sys/bus/usb/devices` on Linux with names
+/// resolved from `usb.ids`, both as a flat list and as a hub/port tree.
+#[cfg(target_os = "linux")]
+pub fn collect_peripherals(ctx: &ProbeContext) -> PeripheralInfo {
+    let source = ctx.sys_path("bus/usb/devices");
+    ctx.probe("peripherals.usb", source.display(), || read_usb(ctx))
+}
+
+#[cfg(target_os = "linux")]
+const BUNDLED_USB_IDS: &str = include_str!("../data/usb.ids");
+
+#[cfg(target_os = "linux")]
+fn read_usb(ctx: &ProbeContext) -> Result<PeripheralInfo, ProbeError> {
+    let dir = ctx.sys_path("bus/usb/devices");
+    // Entries are devices ("usb1", "1-6.2") and their interfaces ("1-6.2:1.0").
+    let (interface_names, device_names): (Vec<String>, Vec<String>) = std::fs::read_dir(&dir)
+        .map_err(|e| ProbeError::from_io(&dir, &e))?
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .partition(|name| name.contains(':'));
+
+    let ids = IdsDatabase::load(ctx, "usb.ids", BUNDLED_USB_IDS);
+    let mut usb_devices: Vec<UsbDevice> = device_names
+        .into_iter()
+        .map(|name| read_usb_device(&dir.join(&name), name, &ids))
+        .collect();
+    usb_devices.sort_by(|a, b| (&a.bus, &a.device).cmp(&(&b.bus, &b.device)));
+
+    let mut interfaces: Vec<(String, UsbInterface)> = interface_names
+        .into_iter()
+        .filter_map(|name| {
+            let owner = interface_owner(&name)?;
+            Some((owner, read_usb_interface(&dir.join(&name), &ids)))
+        })
+        .collect();
+    interfaces.sort_by_key(|(_, iface)| iface.number);
+
+    let usb_tree = usb_children(None, &usb_devices, &interfaces);
+    Ok(PeripheralInfo { usb_devices, usb_tree })
+}
+
+#[cfg(target_os = "linux")]
+fn read_usb_device(dir: &Path, port_path: String, ids: &IdsDatabase) -> UsbDevice {
+    let attr = |name: &str| read_sysfs_file(dir.join(name));
+    let vendor_id = attr("idVendor");
+    let product_id = attr("idProduct");
+    let manufacturer = attr("manufacturer");
+    let product = attr("product");
+    let device_class = attr("bDeviceClass");
+
+    let vendor_num = u16::from_str_radix(&vendor_id, 16).ok();
+    let product_num = u16::from_str_radix(&product_id, 16).ok();
+    let vendor = vendor_num
+        .and_then(|v| ids.vendor(v))
+        .map(String::from)
+        .unwrap_or_else(|| manufacturer.clone());
+    let name = vendor_num
+        .zip(product_num)
+        .and_then(|(v, p)| ids.device(v, p))
+        .map(String::from)
+        .unwrap_or_else(|| product.clone());
+    let class_name = u8::from_str_radix(&device_class, 16)
+        .ok()
+        .and_then(|c| ids.class(c))
+        .unwrap_or_default()
+        .to_string();
+
+    UsbDevice {
+        name,
+        vendor,
+        vendor_id,
+        product_id,
+        // Zero-padded like lsusb prints them
+        bus: format!("{:03}", attr("busnum").parse::<u32>().unwrap_or(0)),
+        device: format!("{:03}", attr("devnum").parse::<u32>().unwrap_or(0)),
+        manufacturer,
+        product,
+        serial: attr("serial"),
+        device_class,
+        class_name,
+        speed_mbps: attr("speed").parse().unwrap_or(0.0),
+        max_power_ma: attr("bMaxPower").trim_end_matches("mA").parse().unwrap_or(0),
+        usb_version: attr("version"),
+        port_path,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_usb_interface(dir: &Path, ids: &IdsDatabase) -> UsbInterface {
+    let attr = |name: &str| read_sysfs_file(dir.join(name));
+    let class = attr("bInterfaceClass");
+    let subclass = attr("bInterfaceSubClass");
+    let class_num = u8::from_str_radix(&class, 16).ok();
+    let subclass_num = u8::from_str_radix(&subclass, 16).ok();
+
+    UsbInterface {
+        number: u32::from_str_radix(&attr("bInterfaceNumber"), 16).unwrap_or(0),
+        class_name: class_num.and_then(|c| ids.class(c)).unwrap_or_default().to_string(),
+        subclass_name: class_num
+            .zip(subclass_num)
+            .and_then(|(c, s)| ids.subclass(c, s))
+            .unwrap_or_default()
+            .to_string(),
+        class,
+        subclass,
+        protocol: attr("bInterfaceProtocol"),
+        driver: read_link_name(dir.join("driver")).unwrap_or_default(),
+    }
+}
+
+/// Device an interface entry belongs to: `1-6.2:1.0` → `1-6.2`. Root hub
+/// interfaces are named after port 0 of their bus: `1-0:1.0` → `usb1`.
+#[cfg(target_os = "linux")]
+fn interface_owner(name: &str) -> Option<String> {
+    let (device, _) = name.split_once(':')?;
+    Some(match device.strip_suffix("-0") {
+        Some(bus) => format!("usb{}", bus),
+        None => device.to_string(),
+    })
+}
+
+/// Hub a device hangs off: `1-6.2` → `1-6`, `1-6` → `usb1`, `usb1` → none.
+#[cfg(target_os = "linux")]
+fn usb_parent(port_path: &str) -> Option<String> {
+    if port_path.starts_with("usb") {
+        return None;
+    }
+    Some(match port_path.rsplit_once('.') {
+        Some((parent, _)) => parent.to_string(),
+        None => format!("usb{}", port_path.split('-').next().unwrap_or_default()),
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn usb_children(parent: Option<&str>, devices: &[UsbDevice], interfaces: &[(String, UsbInterface)]) -> Vec<UsbNode> {
+    let mut nodes: Vec<UsbNode> = devices
+        .iter()
+        .filter(|d| usb_parent(&d.port_path).as_deref() == parent)
+        .map(|d| {
+            // The port is the last number of the path; root hubs have none.
+            let port = match parent {
+                Some(_) => d.port_path.rsplit(['.', '-']).next().and_then(|p| p.parse().ok()).unwrap_or(0),
+                None => 0,
+            };
+            let major: u32 = d.usb_version.split('.').next().and_then(|v| v.parse().ok()).unwrap_or(0);
+            UsbNode {
+                device: d.clone(),
+                port,
+                speed_downgraded: major >= 3 && d.speed_mbps > 0.0 && d.speed_mbps < 5000.0,
+                interfaces: interfaces
+                    .iter()
+                    .filter(|(owner, _)| *owner == d.port_path)
+                    .map(|(_, iface)| iface.clone())
+                    .collect(),
+                children: usb_children(Some(&d.port_path), devices, interfaces),
+            }
+        })
+        .collect();
+    nodes.sort_by_key(|n| (n.device.bus.clone(), n.port));
+    nodes
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn collect_peripherals(ctx: &ProbeContext) -> PeripheralInfo {
+    ctx.probe("peripherals.usb", "none", || Err(ProbeError::unsupported()))
+}
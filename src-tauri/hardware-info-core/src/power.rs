@@ -0,0 +1,195 @@
+//! Processor power draw from the RAPL energy counters under
+//! `/sys/class/powercap` and from the `amd_energy` hwmon driver, with the
+//! configured power limits.
+
+#[cfg(target_os = "linux")]
+use std::path::Path;
+use std::time::Duration;
+
+use crate::context::ProbeContext;
+#[cfg(target_os = "linux")]
+use crate::read_sysfs_file;
+#[cfg(target_os = "linux")]
+use crate::types::PowerLimit;
+use crate::types::{CpuPower, PowerDomain, ProbeError};
+
+/// Energy counters and limits of every domain at one instant. Two samples
+/// taken apart give watts through [`collect_cpu_power`].
+#[derive(Clone, Debug, Default)]
+pub struct CpuPowerSample {
+    domains: Vec<DomainReading>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct DomainReading {
+    domain: PowerDomain,
+    /// Microjoules; `None` when unreadable.
+    energy: Option<u64>,
+    /// Where the counter wraps to zero; 0 when it does not.
+    max_energy: u64,
+}
+
+/// Reads the counters, from powercap and hwmon on Linux. A counter that
+/// cannot be read (they are root-only since Linux 5.10) fails the probe,
+/// but its domain and limits are still returned.
+pub fn sample_cpu_power(ctx: &ProbeContext) -> CpuPowerSample {
+    let mut sample = CpuPowerSample::default();
+    ctx.probe("cpu.power", power_source(ctx), || {
+        let (domains, error) = read_domains(ctx);
+        sample.domains = domains;
+        error.map_or(Ok(()), Err)
+    });
+    sample
+}
+
+/// Each domain of `after` with its average draw since `before`.
+pub fn collect_cpu_power(before: &CpuPowerSample, after: &CpuPowerSample, elapsed: Duration) -> CpuPower {
+    let secs = elapsed.as_secs_f64();
+    let domains = after
+        .domains
+        .iter()
+        .map(|now| {
+            let prev = before.domains.iter().find(|d| d.domain.zone == now.domain.zone);
+            let watts = match (prev.and_then(|p| p.energy), now.energy) {
+                (Some(prev), Some(now_uj)) if secs > 0.0 => {
+                    let delta = if now_uj >= prev {
+                        Some(now_uj - prev)
+                    } else if now.max_energy > 0 {
+                        // Wrapped past `max_energy`.
+                        now.max_energy.checked_sub(prev).map(|d| d + now_uj)
+                    } else {
+                        // Reset, e.g. by a driver reload; nothing to measure.
+                        None
+                    };
+                    delta.map(|d| d as f64 / 1_000_000.0 / secs)
+                }
+                _ => None,
+            };
+            PowerDomain { watts, ..now.domain.clone() }
+        })
+        .collect();
+    CpuPower { domains }
+}
+
+// ——— Platform-specific helpers (Linux) ———
+
+#[cfg(target_os = "linux")]
+fn power_source(ctx: &ProbeContext) -> String {
+    ctx.sys_path("class/powercap").display().to_string()
+}
+
+/// Powercap RAPL zones, then amd_energy channels, with the first counter
+/// that could not be read. Neither source existing is normal in virtual
+/// machines and on ARM.
+#[cfg(target_os = "linux")]
+fn read_domains(ctx: &ProbeContext) -> (Vec<DomainReading>, Option<ProbeError>) {
+    let mut zones: Vec<String> = std::fs::read_dir(ctx.sys_path("class/powercap"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|e| e.file_name().into_string().ok())
+        // Skips the `intel-rapl` control type itself, and the
+        // `intel-rapl-mmio` zones that repeat package-0 on recent laptops.
+        .filter(|name| name.starts_with("intel-rapl:"))
+        .collect();
+    zones.sort();
+
+    let mut error = None;
+    let mut domains: Vec<DomainReading> = zones
+        .into_iter()
+        .map(|zone| read_zone(&ctx.sys_path("class/powercap").join(&zone), zone, &mut error))
+        .collect();
+    domains.extend(read_amd_energy(ctx, &mut error));
+    (domains, error)
+}
+
+/// Microjoules from an energy counter; a failed read is kept in `error`
+/// unless one is there already.
+#[cfg(target_os = "linux")]
+fn read_energy(path: &Path, error: &mut Option<ProbeError>) -> Option<u64> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => text.trim().parse().ok(),
+        Err(e) => {
+            error.get_or_insert_with(|| ProbeError::from_io(path, &e));
+            None
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_zone(dir: &Path, zone: String, error: &mut Option<ProbeError>) -> DomainReading {
+    let attr = |name: &str| read_sysfs_file(dir.join(name));
+    // Zero is how drivers say they do not know.
+    let micro = |name: &str| attr(name).parse::<u64>().ok().filter(|&v| v > 0).map(|v| v as f64 / 1_000_000.0);
+    let limits = (0..)
+        .map(|n| format!("constraint_{}_", n))
+        .take_while(|prefix| dir.join(format!("{}name", prefix)).exists())
+        .map(|prefix| PowerLimit {
+            name: attr(&format!("{}name", prefix)),
+            watts: micro(&format!("{}power_limit_uw", prefix)).unwrap_or(0.0),
+            time_window: micro(&format!("{}time_window_us", prefix)),
+            max_watts: micro(&format!("{}max_power_uw", prefix)),
+        })
+        .collect();
+    DomainReading {
+        domain: PowerDomain {
+            name: attr("name"),
+            zone,
+            enabled: attr("enabled") == "1",
+            watts: None,
+            limits,
+        },
+        energy: read_energy(&dir.join("energy_uj"), error),
+        max_energy: attr("max_energy_range_uj").parse().unwrap_or(0),
+    }
+}
+
+/// `energy*_input` channels of amd_energy chips, which count per socket and
+/// per core on Zen processors.
+#[cfg(target_os = "linux")]
+fn read_amd_energy(ctx: &ProbeContext, error: &mut Option<ProbeError>) -> Vec<DomainReading> {
+    let mut chips: Vec<_> = std::fs::read_dir(ctx.sys_path("class/hwmon"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|e| read_sysfs_file(e.path().join("name")) == "amd_energy")
+        .collect();
+    chips.sort_by_key(|e| e.file_name());
+
+    let mut readings = Vec::new();
+    for chip in chips {
+        let hwmon = chip.file_name().to_string_lossy().into_owned();
+        let mut channels: Vec<u32> = std::fs::read_dir(chip.path())
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|e| e.file_name().to_str()?.strip_prefix("energy")?.strip_suffix("_input")?.parse().ok())
+            .collect();
+        channels.sort_unstable();
+        for channel in channels {
+            let attr = |suffix: &str| read_sysfs_file(chip.path().join(format!("energy{}_{}", channel, suffix)));
+            readings.push(DomainReading {
+                domain: PowerDomain {
+                    name: attr("label"),
+                    zone: format!("{}/energy{}", hwmon, channel),
+                    enabled: true,
+                    watts: None,
+                    limits: Vec::new(),
+                },
+                energy: read_energy(&chip.path().join(format!("energy{}_input", channel)), error),
+                max_energy: 0,
+            });
+        }
+    }
+    readings
+}
+
+#[cfg(not(target_os = "linux"))]
+fn power_source(_ctx: &ProbeContext) -> String {
+    "none".to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_domains(_ctx: &ProbeContext) -> (Vec<DomainReading>, Option<ProbeError>) {
+    (Vec::new(), Some(ProbeError::unsupported()))
+}
@@ -0,0 +1,116 @@
+//! The kernel's routing tables from `/proc/net/route` and
+//! `/proc/net/ipv6_route`, and the resolver configuration from
+//! `/etc/resolv.conf`.
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::context::ProbeContext;
+use crate::types::{DnsConfig, ProbeError, Route};
+
+const RTF_GATEWAY: u32 = 0x0002;
+const RTF_REJECT: u32 = 0x0200;
+const RTF_ANYCAST: u32 = 0x0010_0000;
+const RTF_LOCAL: u32 = 0x8000_0000;
+
+/// The address systemd-resolved's stub listens on.
+const RESOLVED_STUB: &str = "127.0.0.53";
+
+/// Upstream servers systemd-resolved writes for programs that bypass the
+/// stub.
+const RESOLVED_UPSTREAM: &str = "systemd/resolve/resolv.conf";
+
+/// IPv4 routes of the main table.
+pub(crate) fn read_ipv4_routes(ctx: &ProbeContext) -> Result<Vec<Route>, ProbeError> {
+    let path = ctx.proc_path("net/route");
+    let text = std::fs::read_to_string(&path).map_err(|e| ProbeError::from_io(&path, &e))?;
+    // Header: Iface Destination Gateway Flags RefCnt Use Metric Mask …
+    Ok(text.lines().skip(1).filter_map(parse_route_line).collect())
+}
+
+/// IPv6 routes of every table the kernel lists.
+pub(crate) fn read_ipv6_routes(ctx: &ProbeContext) -> Result<Vec<Route>, ProbeError> {
+    let path = ctx.proc_path("net/ipv6_route");
+    let text = std::fs::read_to_string(&path).map_err(|e| ProbeError::from_io(&path, &e))?;
+    Ok(text.lines().filter_map(parse_ipv6_route_line).collect())
+}
+
+/// Addresses are the raw network-order words printed as native-endian hex.
+fn parse_route_line(line: &str) -> Option<Route> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let hex = |i: usize| -> Option<u32> { u32::from_str_radix(fields.get(i)?, 16).ok() };
+    let addr = |word: u32| Ipv4Addr::from(word.to_ne_bytes());
+
+    let flags = hex(3)?;
+    Some(Route {
+        family: "ipv4".to_string(),
+        destination: format!("{}/{}", addr(hex(1)?), hex(7)?.count_ones()),
+        gateway: if flags & RTF_GATEWAY != 0 { addr(hex(2)?).to_string() } else { String::new() },
+        iface: fields.first()?.to_string(),
+        metric: fields.get(6)?.parse().ok()?,
+        table: "main".to_string(),
+    })
+}
+
+/// `dest plen src plen next_hop metric refcnt use flags iface`, all hex.
+fn parse_ipv6_route_line(line: &str) -> Option<Route> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let hex = |i: usize| -> Option<u32> { u32::from_str_radix(fields.get(i)?, 16).ok() };
+    let addr = |i: usize| -> Option<Ipv6Addr> { Some(Ipv6Addr::from(u128::from_str_radix(fields.get(i)?, 16).ok()?)) };
+
+    let destination = addr(0)?;
+    let metric = hex(5)?;
+    let flags = hex(8)?;
+    // The kernel's catch-all unreachable entry, not a configured route.
+    if flags & RTF_REJECT != 0 && metric == u32::MAX {
+        return None;
+    }
+    let gateway = addr(4)?;
+    // Addresses of this host and multicast live in the local table.
+    let local = flags & (RTF_LOCAL | RTF_ANYCAST) != 0 || destination.is_multicast();
+    Some(Route {
+        family: "ipv6".to_string(),
+        destination: format!("{}/{}", destination, hex(1)?),
+        gateway: if gateway.is_unspecified() { String::new() } else { gateway.to_string() },
+        iface: fields.get(9)?.to_string(),
+        metric,
+        table: if local { "local" } else { "main" }.to_string(),
+    })
+}
+
+/// Name servers and search domains. Behind systemd-resolved's stub the
+/// upstream servers come from its own copy of the file.
+pub(crate) fn read_dns(ctx: &ProbeContext) -> Result<DnsConfig, ProbeError> {
+    let path = ctx.etc_path("resolv.conf");
+    let text = std::fs::read_to_string(&path).map_err(|e| ProbeError::from_io(&path, &e))?;
+    let mut dns = parse_resolv_conf(&text);
+    dns.source = path.display().to_string();
+
+    if !dns.servers.is_empty() && dns.servers.iter().all(|s| s == RESOLVED_STUB) {
+        dns.stub_resolver = "systemd-resolved".to_string();
+        let upstream_path = ctx.run_path(RESOLVED_UPSTREAM);
+        if let Ok(text) = std::fs::read_to_string(&upstream_path) {
+            let upstream = parse_resolv_conf(&text);
+            dns.servers = upstream.servers;
+            if dns.search_domains.is_empty() {
+                dns.search_domains = upstream.search_domains;
+            }
+            dns.source = upstream_path.display().to_string();
+        }
+    }
+    Ok(dns)
+}
+
+/// `nameserver` lines in order; the last `search` or `domain` line wins, as
+/// in the resolver itself.
+fn parse_resolv_conf(text: &str) -> DnsConfig {
+    let mut dns = DnsConfig::default();
+    for line in text.lines() {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("nameserver") => dns.servers.extend(words.next().map(str::to_string)),
+            Some("search") | Some("domain") => dns.search_domains = words.map(str::to_string).collect(),
+            _ => {}
+        }
+    }
+    dns
+}
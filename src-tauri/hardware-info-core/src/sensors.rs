@@ -0,0 +1,206 @@
+//! Hardware monitoring: every hwmon chip's temperatures, fans, voltages,
+//! currents, power and energy readings, and the kernel's thermal zones with
+//! their trip points.
+
+#[cfg(target_os = "linux")]
+use std::path::Path;
+
+#[cfg(not(target_os = "linux"))]
+use sysinfo::Components;
+
+use crate::context::ProbeContext;
+#[cfg(target_os = "linux")]
+use crate::types::TripPoint;
+use crate::types::{ProbeError, Sensor, SensorChip, SensorInfo, ThermalZone};
+#[cfg(target_os = "linux")]
+use crate::{read_link_name, read_sysfs_file};
+
+/// Every sensor chip and thermal zone with its current readings.
+pub fn collect_sensors(ctx: &ProbeContext) -> SensorInfo {
+    SensorInfo {
+        chips: ctx.probe("sensors.hwmon", hwmon_source(ctx), || read_chips(ctx)),
+        thermal_zones: ctx.probe("sensors.thermal", thermal_source(ctx), || read_thermal_zones(ctx)),
+    }
+}
+
+// ——— Platform-specific helpers (Linux) ———
+
+/// Attribute prefixes of the hwmon sysfs ABI with their kind, unit, and the
+/// divisor from the kernel's integer units (millidegrees, millivolts,
+/// milliamperes, microwatts, microjoules).
+#[cfg(target_os = "linux")]
+const SENSOR_TYPES: [(&str, &str, &str, f64); 6] = [
+    ("temp", "temperature", "°C", 1000.0),
+    ("fan", "fan", "RPM", 1.0),
+    ("in", "voltage", "V", 1000.0),
+    ("curr", "current", "A", 1000.0),
+    ("power", "power", "W", 1_000_000.0),
+    ("energy", "energy", "J", 1_000_000.0),
+];
+
+/// Per-channel flags that read `1` while raised.
+#[cfg(target_os = "linux")]
+const ALARM_ATTRS: [&str; 6] = ["alarm", "min_alarm", "max_alarm", "lcrit_alarm", "crit_alarm", "fault"];
+
+#[cfg(target_os = "linux")]
+fn hwmon_source(ctx: &ProbeContext) -> String {
+    ctx.sys_path("class/hwmon").display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn read_chips(ctx: &ProbeContext) -> Result<Vec<SensorChip>, ProbeError> {
+    let class = ctx.sys_path("class/hwmon");
+    let entries = std::fs::read_dir(&class).map_err(|e| ProbeError::from_io(&class, &e))?;
+    let mut chips: Vec<SensorChip> = entries.flatten().map(|e| read_chip(&e.path())).collect();
+    chips.sort_by_key(|chip| class_index(&chip.hwmon));
+    Ok(chips)
+}
+
+#[cfg(target_os = "linux")]
+fn read_chip(dir: &Path) -> SensorChip {
+    // Drivers older than the hwmon class ABI keep their attributes on the
+    // parent device.
+    let attrs = if dir.join("name").exists() { dir.to_path_buf() } else { dir.join("device") };
+    SensorChip {
+        hwmon: dir.file_name().unwrap_or_default().to_string_lossy().to_string(),
+        name: read_sysfs_file(attrs.join("name")),
+        device: read_link_name(dir.join("device")).unwrap_or_default(),
+        sensors: read_sensors(&attrs),
+    }
+}
+
+/// One sensor per channel that has a reading, ordered by kind and then
+/// channel number.
+#[cfg(target_os = "linux")]
+fn read_sensors(dir: &Path) -> Vec<Sensor> {
+    let mut channels: Vec<(usize, u32, String)> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|e| {
+            let file = e.file_name().to_string_lossy().to_string();
+            let (channel, attr) = file.split_once('_')?;
+            if attr != "input" && attr != "average" {
+                return None;
+            }
+            SENSOR_TYPES.iter().enumerate().find_map(|(kind, (prefix, ..))| {
+                let number = channel.strip_prefix(prefix)?.parse().ok()?;
+                Some((kind, number, channel.to_string()))
+            })
+        })
+        .collect();
+    channels.sort();
+    channels.dedup();
+
+    channels
+        .into_iter()
+        .map(|(kind, _, channel)| {
+            let (_, kind, unit, divisor) = SENSOR_TYPES[kind];
+            let attr = |suffix: &str| read_sysfs_file(dir.join(format!("{}_{}", channel, suffix)));
+            let scaled = |suffix: &str| attr(suffix).parse::<f64>().ok().map(|v| v / divisor);
+            Sensor {
+                kind: kind.to_string(),
+                label: attr("label"),
+                unit: unit.to_string(),
+                // Power meters often report only a running average.
+                value: scaled("input").or_else(|| scaled("average")),
+                min: scaled("min"),
+                max: scaled("max"),
+                crit: scaled("crit"),
+                alarms: ALARM_ATTRS.iter().filter(|a| attr(a) == "1").map(|a| a.to_string()).collect(),
+                channel,
+            }
+        })
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn thermal_source(ctx: &ProbeContext) -> String {
+    ctx.sys_path("class/thermal").display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn read_thermal_zones(ctx: &ProbeContext) -> Result<Vec<ThermalZone>, ProbeError> {
+    let class = ctx.sys_path("class/thermal");
+    let entries = std::fs::read_dir(&class).map_err(|e| ProbeError::from_io(&class, &e))?;
+    // The class also holds cooling_device* entries.
+    let mut zones: Vec<ThermalZone> = entries
+        .flatten()
+        .filter(|e| e.file_name().to_string_lossy().starts_with("thermal_zone"))
+        .map(|e| read_thermal_zone(&e.path()))
+        .collect();
+    zones.sort_by_key(|zone| class_index(&zone.zone));
+    Ok(zones)
+}
+
+#[cfg(target_os = "linux")]
+fn read_thermal_zone(dir: &Path) -> ThermalZone {
+    let attr = |name: &str| read_sysfs_file(dir.join(name));
+    let celsius = |name: &str| attr(name).parse::<f64>().ok().map(|t| t / 1000.0);
+
+    let trip_points = (0..)
+        .map_while(|i| {
+            let trip_type = attr(&format!("trip_point_{}_type", i));
+            (!trip_type.is_empty()).then(|| TripPoint {
+                trip_type,
+                temperature: celsius(&format!("trip_point_{}_temp", i)).unwrap_or_default(),
+                hysteresis: celsius(&format!("trip_point_{}_hyst", i)),
+            })
+        })
+        .collect();
+
+    ThermalZone {
+        zone: dir.file_name().unwrap_or_default().to_string_lossy().to_string(),
+        zone_type: attr("type"),
+        // Reading `temp` fails while the zone is disabled.
+        temperature: celsius("temp"),
+        policy: attr("policy"),
+        trip_points,
+    }
+}
+
+/// `hwmon10` sorts after `hwmon9`.
+#[cfg(target_os = "linux")]
+fn class_index(name: &str) -> u32 {
+    name.trim_start_matches(|c: char| !c.is_ascii_digit()).parse().unwrap_or(u32::MAX)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn hwmon_source(_ctx: &ProbeContext) -> String {
+    "sysinfo components".to_string()
+}
+
+/// Temperatures only, as one chip; other platforms expose no fans or rails
+/// through sysinfo.
+#[cfg(not(target_os = "linux"))]
+fn read_chips(_ctx: &ProbeContext) -> Result<Vec<SensorChip>, ProbeError> {
+    let components = Components::new_with_refreshed_list();
+    let sensors = components
+        .iter()
+        .enumerate()
+        .map(|(i, c)| Sensor {
+            kind: "temperature".to_string(),
+            channel: format!("temp{}", i + 1),
+            label: c.label().to_string(),
+            unit: "°C".to_string(),
+            value: Some(c.temperature() as f64),
+            crit: c.critical().map(|t| t as f64),
+            ..Default::default()
+        })
+        .collect();
+    Ok(vec![SensorChip {
+        name: "sysinfo".to_string(),
+        sensors,
+        ..Default::default()
+    }])
+}
+
+#[cfg(not(target_os = "linux"))]
+fn thermal_source(_ctx: &ProbeContext) -> String {
+    "none".to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_thermal_zones(_ctx: &ProbeContext) -> Result<Vec<ThermalZone>, ProbeError> {
+    Err(ProbeError::unsupported())
+}
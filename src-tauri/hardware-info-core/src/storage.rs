@@ -0,0 +1,489 @@
+#[cfg(target_os = "linux")]
+use std::collections::HashMap;
+#[cfg(target_os = "linux")]
+use std::path::Path;
+use std::time::Duration;
+
+use sysinfo::Disks;
+
+use crate::context::ProbeContext;
+#[cfg(target_os = "linux")]
+use crate::partitions::{apply_udev_entry, read_partition_table, udev_scheme};
+#[cfg(target_os = "linux")]
+use crate::read_sysfs_file;
+#[cfg(target_os = "linux")]
+use crate::storage_stack;
+#[cfg(target_os = "linux")]
+use crate::types::{Partition, PartitionTable};
+use crate::types::{DiskIo, DiskLayoutEntry, FilesystemEntry, ProbeError, StorageInfo, StorageNode};
+
+/// What [`collect_storage_with`] includes.
+#[derive(Clone, Debug, Default)]
+pub struct StorageOptions {
+    /// List loop, RAM, zram and other virtual block devices alongside the
+    /// physical disks.
+    pub include_virtual: bool,
+    /// Decode partition tables from the disks' device nodes, which usually
+    /// needs root. Otherwise only the extents the kernel lists in sysfs are
+    /// reported, unless the node is a plain file such as a disk image.
+    pub read_partition_tables: bool,
+}
+
+/// Physical disks and mounted filesystems with their usage.
+pub fn collect_storage(ctx: &ProbeContext) -> StorageInfo {
+    collect_storage_with(ctx, &StorageOptions::default())
+}
+
+/// Same as [`collect_storage`], with control over which disks are listed.
+pub fn collect_storage_with(ctx: &ProbeContext, options: &StorageOptions) -> StorageInfo {
+    let disks = Disks::new_with_refreshed_list();
+    #[cfg(target_os = "linux")]
+    let read_layout = || read_disk_layout(ctx, options);
+    #[cfg(not(target_os = "linux"))]
+    let read_layout = || read_disk_layout(&disks);
+
+    StorageInfo {
+        disk_layout: ctx.probe("storage.disks", disks_source(ctx), read_layout),
+        stack: ctx.probe("storage.stack", disks_source(ctx), || read_stack(ctx, options)),
+        filesystems: read_filesystems(&disks),
+    }
+}
+
+/// Cumulative I/O counters of the disks [`collect_storage`] lists, at one
+/// instant. Two samples taken apart give rates through [`collect_disk_io`].
+#[derive(Clone, Debug, Default)]
+pub struct DiskStatsSample {
+    disks: Vec<DiskCounters>,
+}
+
+/// One `/proc/diskstats` line; times are in milliseconds.
+#[derive(Clone, Debug, Default)]
+struct DiskCounters {
+    name: String,
+    reads: u64,
+    sectors_read: u64,
+    read_ms: u64,
+    writes: u64,
+    sectors_written: u64,
+    write_ms: u64,
+    io_ms: u64,
+    weighted_io_ms: u64,
+}
+
+/// Reads the counters from `/proc/diskstats`; empty on other platforms.
+pub fn sample_disk_stats(ctx: &ProbeContext) -> DiskStatsSample {
+    ctx.probe("storage.io", diskstats_source(ctx), || read_disk_stats(ctx))
+}
+
+/// Per-disk rates between two samples taken `elapsed` apart.
+pub fn collect_disk_io(before: &DiskStatsSample, after: &DiskStatsSample, elapsed: Duration) -> Vec<DiskIo> {
+    let secs = elapsed.as_secs_f64();
+    if secs <= 0.0 {
+        return Vec::new();
+    }
+    let ms = secs * 1000.0;
+
+    after
+        .disks
+        .iter()
+        .filter_map(|now| {
+            let prev = before.disks.iter().find(|d| d.name == now.name)?;
+            // Saturating: counters restart when a disk is detached and re-added.
+            let delta = |field: fn(&DiskCounters) -> u64| field(now).saturating_sub(field(prev)) as f64;
+            let reads = delta(|d| d.reads);
+            let writes = delta(|d| d.writes);
+            Some(DiskIo {
+                name: now.name.clone(),
+                // Sectors here are always 512 bytes.
+                read_bytes_per_sec: delta(|d| d.sectors_read) * 512.0 / secs,
+                write_bytes_per_sec: delta(|d| d.sectors_written) * 512.0 / secs,
+                read_iops: reads / secs,
+                write_iops: writes / secs,
+                read_latency_ms: if reads > 0.0 { delta(|d| d.read_ms) / reads } else { 0.0 },
+                write_latency_ms: if writes > 0.0 { delta(|d| d.write_ms) / writes } else { 0.0 },
+                queue_depth: delta(|d| d.weighted_io_ms) / ms,
+                utilization_pct: (delta(|d| d.io_ms) / ms * 100.0).min(100.0),
+            })
+        })
+        .collect()
+}
+
+fn read_filesystems(disks: &Disks) -> Vec<FilesystemEntry> {
+    let mut filesystems: Vec<FilesystemEntry> = Vec::new();
+
+    for disk in disks.iter() {
+        let mount = disk.mount_point().to_string_lossy().to_string();
+        let total = disk.total_space();
+        let available = disk.available_space();
+        let used = total.saturating_sub(available);
+        let usage_pct = if total > 0 {
+            (used as f64 / total as f64) * 100.0
+        } else {
+            0.0
+        };
+
+        filesystems.push(FilesystemEntry {
+            mount,
+            fs_type: disk
+                .file_system()
+                .to_string_lossy()
+                .to_string(),
+            used,
+            size: total,
+            usage_pct,
+        });
+    }
+
+    filesystems
+}
+
+// ——— Platform-specific helpers (Linux) ———
+
+/// Name prefixes of virtual devices, for captures where `/sys/block`
+/// entries are plain directories rather than links into `/sys/devices`.
+#[cfg(target_os = "linux")]
+const VIRTUAL_PREFIXES: [&str; 3] = ["loop", "ram", "zram"];
+
+#[cfg(target_os = "linux")]
+fn disks_source(ctx: &ProbeContext) -> String {
+    ctx.sys_path("block").display().to_string()
+}
+
+/// Sorted names of every entry in `/sys/block`.
+#[cfg(target_os = "linux")]
+pub(crate) fn block_device_names(ctx: &ProbeContext) -> Result<Vec<String>, ProbeError> {
+    let dir = ctx.sys_path("block");
+    let mut names: Vec<String> = std::fs::read_dir(&dir)
+        .map_err(|e| ProbeError::from_io(&dir, &e))?
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+/// Loop, RAM, zram, md, device-mapper and other devices with no hardware
+/// behind them.
+#[cfg(target_os = "linux")]
+pub(crate) fn is_virtual_block_device(block_dir: &Path, name: &str) -> bool {
+    block_device_path(block_dir, name).contains("/devices/virtual/")
+        || VIRTUAL_PREFIXES.iter().any(|p| name.starts_with(p))
+}
+
+/// Where a `/sys/block` entry links to, e.g.
+/// `../devices/pci0000:00/0000:00:17.0/ata1/host0/target0:0:0/0:0:0:0/block/sda`.
+#[cfg(target_os = "linux")]
+fn block_device_path(block_dir: &Path, name: &str) -> String {
+    std::fs::read_link(block_dir.join(name))
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "linux")]
+fn read_disk_layout(ctx: &ProbeContext, options: &StorageOptions) -> Result<Vec<DiskLayoutEntry>, ProbeError> {
+    let dir = ctx.sys_path("block");
+    Ok(block_device_names(ctx)?
+        .into_iter()
+        .filter_map(|name| {
+            let is_virtual = is_virtual_block_device(&dir, &name);
+            if is_virtual && !options.include_virtual {
+                return None;
+            }
+            let device_path = block_device_path(&dir, &name);
+            Some(read_disk(ctx, options, &dir.join(&name), name, &device_path, is_virtual))
+        })
+        .collect())
+}
+
+#[cfg(target_os = "linux")]
+fn diskstats_source(ctx: &ProbeContext) -> String {
+    ctx.proc_path("diskstats").display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn read_disk_stats(ctx: &ProbeContext) -> Result<DiskStatsSample, ProbeError> {
+    let path = ctx.proc_path("diskstats");
+    let text = std::fs::read_to_string(&path).map_err(|e| ProbeError::from_io(&path, &e))?;
+    // The same disks as the inventory: no partitions, no virtual devices.
+    let block = ctx.sys_path("block");
+    let names: Vec<String> = block_device_names(ctx)?
+        .into_iter()
+        .filter(|name| !is_virtual_block_device(&block, name))
+        .collect();
+
+    Ok(DiskStatsSample {
+        disks: text
+            .lines()
+            .filter_map(parse_diskstats_line)
+            .filter(|d| names.contains(&d.name))
+            .collect(),
+    })
+}
+
+/// `259 0 nvme0n1 reads merged sectors ms writes merged sectors ms
+/// in-flight io_ms weighted_ms …`; newer kernels append discard and flush
+/// counters.
+#[cfg(target_os = "linux")]
+fn parse_diskstats_line(line: &str) -> Option<DiskCounters> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let n = |i: usize| -> Option<u64> { fields.get(i)?.parse().ok() };
+    Some(DiskCounters {
+        name: fields.get(2)?.to_string(),
+        reads: n(3)?,
+        sectors_read: n(5)?,
+        read_ms: n(6)?,
+        writes: n(7)?,
+        sectors_written: n(9)?,
+        write_ms: n(10)?,
+        io_ms: n(12)?,
+        weighted_io_ms: n(13)?,
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn read_stack(ctx: &ProbeContext, options: &StorageOptions) -> Result<Vec<StorageNode>, ProbeError> {
+    storage_stack::read_stack(ctx, options)
+}
+
+#[cfg(target_os = "linux")]
+fn read_disk(
+    ctx: &ProbeContext,
+    options: &StorageOptions,
+    dir: &Path,
+    name: String,
+    device_path: &str,
+    is_virtual: bool,
+) -> DiskLayoutEntry {
+    let attr = |rel: &str| read_sysfs_file(dir.join(rel));
+    let interface_type = disk_transport(&name, device_path);
+    let rotational = attr("queue/rotational") == "1";
+    // Always counted in 512-byte units, whatever the sector size.
+    let sectors: u64 = attr("size").parse().unwrap_or(0);
+    let logical_sector_size: u32 = attr("queue/logical_block_size").parse().unwrap_or(0);
+
+    // NVMe namespaces keep identity on the controller; SCSI and ATA disks
+    // on the SCSI device, with the serial only in the VPD page.
+    let (serial, firmware_revision) = if interface_type == "NVMe" {
+        (attr("device/serial"), attr("device/firmware_rev"))
+    } else {
+        (read_vpd_serial(&dir.join("device/vpd_pg80")), attr("device/rev"))
+    };
+    let wwn = [attr("wwid"), attr("device/wwid")]
+        .into_iter()
+        .find(|w| !w.is_empty())
+        .unwrap_or_default();
+
+    let disk_type = if is_virtual {
+        "Virtual"
+    } else if rotational {
+        "HDD"
+    } else {
+        "SSD"
+    };
+
+    DiskLayoutEntry {
+        disk_type: disk_type.to_string(),
+        size: sectors * 512,
+        interface_type: interface_type.to_string(),
+        model: attr("device/model"),
+        vendor: attr("device/vendor"),
+        serial,
+        wwn,
+        firmware_revision,
+        rotational,
+        removable: attr("removable") == "1",
+        logical_sector_size,
+        physical_sector_size: attr("queue/physical_block_size").parse().unwrap_or(0),
+        // An empty card reader or tray has nothing to read.
+        partition_table: if sectors > 0 {
+            read_disk_partitions(ctx, options, dir, &name, logical_sector_size.max(512))
+        } else {
+            None
+        },
+        name,
+    }
+}
+
+/// Decodes the table from the device node when asked to, or when the node
+/// is a plain file that can be read without touching a disk. Otherwise, or
+/// when that fails, the extents the kernel lists in sysfs.
+#[cfg(target_os = "linux")]
+fn read_disk_partitions(
+    ctx: &ProbeContext,
+    options: &StorageOptions,
+    dir: &Path,
+    name: &str,
+    sector_size: u32,
+) -> Option<PartitionTable> {
+    use std::os::unix::fs::FileTypeExt;
+
+    let dev = ctx.dev_path(name);
+    let is_image = std::fs::metadata(&dev).is_ok_and(|m| !m.file_type().is_block_device());
+    if !options.read_partition_tables && !is_image {
+        return read_sysfs_partitions(ctx, dir, sector_size);
+    }
+    ctx.probe("storage.partitions", dev.display(), || read_partition_table(&dev, sector_size))
+        .or_else(|| read_sysfs_partitions(ctx, dir, sector_size))
+}
+
+/// Partitions as `/sys/block/<disk>/<disk>N` entries, with the scheme,
+/// types, labels and UUIDs udev recorded when it probed the disk. Without
+/// udev's database, number and extent only.
+#[cfg(target_os = "linux")]
+fn read_sysfs_partitions(ctx: &ProbeContext, dir: &Path, sector_size: u32) -> Option<PartitionTable> {
+    let disk = read_udev_properties(ctx, dir);
+    let property = |key: &str| disk.get(key).cloned().unwrap_or_default();
+    let scheme = udev_scheme(&property("ID_PART_TABLE_TYPE"));
+    let mut partitions: Vec<Partition> = std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.join("partition").exists())
+        .map(|p| {
+            let attr = |name: &str| read_sysfs_file(p.join(name)).parse::<u64>().unwrap_or(0);
+            // 512-byte units, like the disk's own size
+            let start_lba = attr("start") * 512 / sector_size as u64;
+            let size = attr("size") * 512;
+            let mut partition = Partition {
+                number: attr("partition") as u32,
+                start_lba,
+                end_lba: (start_lba + size / sector_size as u64).saturating_sub(1),
+                size,
+                ..Default::default()
+            };
+            apply_udev_entry(&mut partition, scheme, &read_udev_properties(ctx, &p));
+            partition
+        })
+        .collect();
+    if partitions.is_empty() {
+        return None;
+    }
+    partitions.sort_by_key(|p| p.number);
+
+    Some(PartitionTable {
+        scheme: scheme.to_string(),
+        // Empty, not a made-up id, when the scheme is unknown.
+        disk_id: if scheme.is_empty() { String::new() } else { property("ID_PART_TABLE_UUID") },
+        sector_size,
+        partitions,
+    })
+}
+
+/// `E:` properties of a block device from udev's database at
+/// `/run/udev/data/b<major>:<minor>`, readable without root. Values udev
+/// got from blkid keep its `\xNN` escapes; those are undone here.
+#[cfg(target_os = "linux")]
+fn read_udev_properties(ctx: &ProbeContext, dir: &Path) -> HashMap<String, String> {
+    let dev = read_sysfs_file(dir.join("dev"));
+    if dev.is_empty() {
+        return HashMap::new();
+    }
+    let data = std::fs::read_to_string(ctx.run_path(format!("udev/data/b{}", dev))).unwrap_or_default();
+    data.lines()
+        .filter_map(|line| line.strip_prefix("E:")?.split_once('='))
+        .map(|(key, value)| (key.to_string(), unescape_udev(value)))
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn unescape_udev(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = match bytes[i] {
+            b'\\' if bytes.get(i + 1) == Some(&b'x') => {
+                value.get(i + 2..i + 4).and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            }
+            _ => None,
+        };
+        match escaped {
+            Some(byte) => {
+                out.push(byte);
+                i += 4;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Bus the disk is attached through, judged from its sysfs device path.
+#[cfg(target_os = "linux")]
+fn disk_transport(name: &str, device_path: &str) -> &'static str {
+    if name.starts_with("nvme") || device_path.contains("/nvme") {
+        "NVMe"
+    } else if device_path.contains("/usb") {
+        // Checked before ATA: USB bridges to SATA drives still sit on USB.
+        "USB"
+    } else if name.starts_with("vd") || device_path.contains("/virtio") {
+        "virtio"
+    } else if name.starts_with("mmcblk") {
+        "MMC"
+    } else if device_path.contains("/ata") {
+        "SATA"
+    } else if name.starts_with("sd") {
+        "SCSI"
+    } else {
+        ""
+    }
+}
+
+/// Serial number from a SCSI "Unit Serial Number" VPD page (0x80): a
+/// four-byte header with the big-endian length in bytes 2–3, then ASCII.
+#[cfg(target_os = "linux")]
+fn read_vpd_serial(path: &Path) -> String {
+    let Ok(page) = std::fs::read(path) else {
+        return String::new();
+    };
+    if page.len() < 4 || page[1] != 0x80 {
+        return String::new();
+    }
+    let len = u16::from_be_bytes([page[2], page[3]]) as usize;
+    let end = (4 + len).min(page.len());
+    String::from_utf8_lossy(&page[4..end]).trim().to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn disks_source(_ctx: &ProbeContext) -> String {
+    "sysinfo disks".to_string()
+}
+
+/// Without a block-device view, list each mounted volume once.
+#[cfg(not(target_os = "linux"))]
+fn read_disk_layout(disks: &Disks) -> Result<Vec<DiskLayoutEntry>, ProbeError> {
+    let mut disk_layout: Vec<DiskLayoutEntry> = Vec::new();
+
+    for disk in disks.iter() {
+        let name = disk.name().to_string_lossy().to_string();
+        if !disk_layout.iter().any(|d| d.name == name) {
+            disk_layout.push(DiskLayoutEntry {
+                name,
+                disk_type: format!("{:?}", disk.kind()),
+                size: disk.total_space(),
+                removable: disk.is_removable(),
+                ..Default::default()
+            });
+        }
+    }
+
+    Ok(disk_layout)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_stack(_ctx: &ProbeContext, _options: &StorageOptions) -> Result<Vec<StorageNode>, ProbeError> {
+    Err(ProbeError::unsupported())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn diskstats_source(_ctx: &ProbeContext) -> String {
+    "none".to_string()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_disk_stats(_ctx: &ProbeContext) -> Result<DiskStatsSample, ProbeError> {
+    Err(ProbeError::unsupported())
+}
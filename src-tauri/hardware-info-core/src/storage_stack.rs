@@ -0,0 +1,224 @@
+//! The block device stack: which devices are built on which, from the
+//! `holders` links in sysfs, with md RAID, device-mapper and mount details
+//! attached to each node.
+
+use std::path::Path;
+
+use crate::context::ProbeContext;
+use crate::read_sysfs_file;
+use crate::storage::{block_device_names, is_virtual_block_device, StorageOptions};
+use crate::types::{ProbeError, RaidInfo, RaidMember, StorageNode};
+
+/// Deeper than any real stack; guards against a holder loop.
+const MAX_DEPTH: usize = 16;
+
+/// A line of `/proc/self/mountinfo` or `/proc/swaps`.
+struct Mount {
+    /// `major:minor`; empty for swap, whose table only has the path.
+    dev: String,
+    /// Device path as mounted, e.g. `/dev/mapper/vg0-root`.
+    source: String,
+    point: String,
+    fs_type: String,
+}
+
+/// Every device that sits on nothing else, with its stack beneath it.
+pub(crate) fn read_stack(ctx: &ProbeContext, options: &StorageOptions) -> Result<Vec<StorageNode>, ProbeError> {
+    let block = ctx.sys_path("block");
+    let mounts = read_mounts(ctx);
+
+    Ok(block_device_names(ctx)?
+        .into_iter()
+        .filter(|name| child_names(&block.join(name).join("slaves")).is_empty())
+        .filter(|name| options.include_virtual || !is_virtual_block_device(&block, name))
+        .map(|name| read_node(&block, &block.join(&name), &name, &mounts, 0))
+        .collect())
+}
+
+fn read_node(block: &Path, dir: &Path, name: &str, mounts: &[Mount], depth: usize) -> StorageNode {
+    let attr = |rel: &str| read_sysfs_file(dir.join(rel));
+    let mapper_name = attr("dm/name");
+    let matching: Vec<&Mount> = mounts
+        .iter()
+        .filter(|m| mounts_device(m, &attr("dev"), name, &mapper_name))
+        .collect();
+
+    let mut children = Vec::new();
+    if depth < MAX_DEPTH {
+        // Partitions are subdirectories of their disk rather than holders.
+        let mut parts: Vec<(u32, String)> = child_names(dir)
+            .into_iter()
+            .filter_map(|child| {
+                let number = read_sysfs_file(dir.join(&child).join("partition")).parse().ok()?;
+                Some((number, child))
+            })
+            .collect();
+        parts.sort();
+        for (_, part) in parts {
+            children.push(read_node(block, &dir.join(&part), &part, mounts, depth + 1));
+        }
+        for holder in child_names(&dir.join("holders")) {
+            children.push(read_node(block, &block.join(&holder), &holder, mounts, depth + 1));
+        }
+    }
+
+    StorageNode {
+        name: name.to_string(),
+        kind: node_kind(block, dir, name, &attr("dm/uuid")).to_string(),
+        // 512-byte units, whatever the sector size
+        size: attr("size").parse::<u64>().unwrap_or(0) * 512,
+        fs_type: matching.first().map(|m| m.fs_type.clone()).unwrap_or_default(),
+        mountpoints: matching.iter().map(|m| m.point.clone()).collect(),
+        raid: dir.join("md").is_dir().then(|| read_raid(&dir.join("md"))),
+        mapper_name,
+        children,
+    }
+}
+
+fn node_kind(block: &Path, dir: &Path, name: &str, dm_uuid: &str) -> &'static str {
+    if dir.join("partition").exists() {
+        "partition"
+    } else if dir.join("md").is_dir() {
+        "raid"
+    } else if dir.join("dm").is_dir() {
+        // The owning subsystem prefixes the uuid: CRYPT-LUKS2-…, LVM-…
+        match dm_uuid.split('-').next() {
+            Some("CRYPT") => "crypt",
+            Some("LVM") => "lvm",
+            Some("mpath") => "multipath",
+            _ => "dm",
+        }
+    } else if name.starts_with("loop") {
+        "loop"
+    } else if is_virtual_block_device(block, name) {
+        "virtual"
+    } else {
+        "disk"
+    }
+}
+
+fn read_raid(md: &Path) -> RaidInfo {
+    let attr = |rel: &str| read_sysfs_file(md.join(rel));
+    // Members appear as dev-<name> directories.
+    let mut members: Vec<RaidMember> = child_names(md)
+        .into_iter()
+        .filter_map(|entry| {
+            let name = entry.strip_prefix("dev-")?.to_string();
+            Some(RaidMember {
+                state: attr(&format!("{}/state", entry)),
+                // "none" for spares
+                slot: attr(&format!("{}/slot", entry)).parse().ok(),
+                name,
+            })
+        })
+        .collect();
+    members.sort_by_key(|m| (m.slot.unwrap_or(u32::MAX), m.name.clone()));
+
+    let sync_action = attr("sync_action");
+    RaidInfo {
+        level: attr("level"),
+        array_state: attr("array_state"),
+        sync_progress: if sync_action == "idle" {
+            None
+        } else {
+            sync_progress(&attr("sync_completed"))
+        },
+        sync_action,
+        raid_disks: attr("raid_disks").parse().unwrap_or(0),
+        degraded: attr("degraded").parse().unwrap_or(0),
+        members,
+    }
+}
+
+/// `sync_completed` reads `done / total` in sectors, or `none`.
+fn sync_progress(text: &str) -> Option<f64> {
+    let (done, total) = text.split_once('/')?;
+    let done: f64 = done.trim().parse().ok()?;
+    let total: f64 = total.trim().parse().ok()?;
+    (total > 0.0).then(|| done / total * 100.0)
+}
+
+/// Matches on `major:minor` where the table has it, else on the device
+/// path, which also catches btrfs (mounted with an anonymous device number).
+fn mounts_device(mount: &Mount, dev: &str, name: &str, mapper_name: &str) -> bool {
+    (!dev.is_empty() && mount.dev == dev)
+        || mount.source == format!("/dev/{}", name)
+        || (!mapper_name.is_empty() && mount.source == format!("/dev/mapper/{}", mapper_name))
+}
+
+fn read_mounts(ctx: &ProbeContext) -> Vec<Mount> {
+    let read = |name: &str, rel: &str| {
+        let path = ctx.proc_path(rel);
+        ctx.probe(name, path.display(), || {
+            std::fs::read_to_string(&path).map_err(|e| ProbeError::from_io(&path, &e))
+        })
+    };
+    let mountinfo = read("storage.mounts", "self/mountinfo");
+    let swaps = read("storage.swaps", "swaps");
+
+    let mut mounts: Vec<Mount> = mountinfo.lines().filter_map(parse_mountinfo_line).collect();
+    // Header: Filename Type Size Used Priority
+    mounts.extend(swaps.lines().skip(1).filter_map(|line| {
+        Some(Mount {
+            dev: String::new(),
+            source: unescape(line.split_whitespace().next()?),
+            point: "[SWAP]".to_string(),
+            fs_type: "swap".to_string(),
+        })
+    }));
+    mounts
+}
+
+/// `36 25 8:1 / /data rw,relatime shared:1 - ext4 /dev/sda1 rw`: the
+/// optional fields before ` - ` vary, so split there first.
+fn parse_mountinfo_line(line: &str) -> Option<Mount> {
+    let (left, right) = line.split_once(" - ")?;
+    let left: Vec<&str> = left.split(' ').collect();
+    let mut right = right.split(' ');
+    Some(Mount {
+        dev: left.get(2)?.to_string(),
+        point: unescape(left.get(4)?),
+        fs_type: right.next()?.to_string(),
+        source: unescape(right.next()?),
+    })
+}
+
+/// Mount tables write space, tab, newline and backslash as octal escapes
+/// such as `\040`. An escape is a single byte, so a run of them can spell
+/// one UTF-8 character; the path is decoded only once all are undone.
+fn unescape(field: &str) -> String {
+    let bytes = field.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = match bytes[i] {
+            b'\\' => field.get(i + 1..i + 4).and_then(|o| u8::from_str_radix(o, 8).ok()),
+            _ => None,
+        };
+        match escaped {
+            Some(byte) => {
+                out.push(byte);
+                i += 4;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Sorted entry names of `dir`; empty when it does not exist.
+fn child_names(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.file_name().to_string_lossy().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
@@ -0,0 +1,203 @@
+use sysinfo::System;
+
+use crate::context::ProbeContext;
+#[cfg(target_os = "linux")]
+use crate::read_sysfs_file;
+use crate::types::{BaseboardInfo, BiosInfo, OsInfo, ProbeError, RuntimeInfo, StaticData, UuidInfo, VersionsInfo};
+
+/// Motherboard, firmware, operating system and network identity.
+pub fn collect_static_data(ctx: &ProbeContext, sys: &System) -> StaticData {
+    StaticData {
+        baseboard: ctx.probe("baseboard", dmi_source(ctx), || read_baseboard_info(ctx)),
+        bios: ctx.probe("bios", dmi_source(ctx), || read_bios_info(ctx)),
+        os: read_os_info(ctx, sys),
+        uuid: ctx.probe("uuid", uuid_source(ctx), || read_uuid_info(ctx)),
+        versions: VersionsInfo {
+            node: "N/A (Rust backend)".to_string(),
+        },
+    }
+}
+
+/// System uptime and the current Unix timestamp, both in seconds.
+pub fn collect_runtime() -> RuntimeInfo {
+    let uptime = System::uptime();
+    let current = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    RuntimeInfo { uptime, current }
+}
+
+// ——— Platform-specific helpers (Linux) ———
+
+#[cfg(target_os = "linux")]
+fn dmi_source(ctx: &ProbeContext) -> String {
+    ctx.sys_path("class/dmi/id").display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn uuid_source(ctx: &ProbeContext) -> String {
+    ctx.sys_path("class/net").display().to_string()
+}
+
+#[cfg(target_os = "linux")]
+fn check_dmi(ctx: &ProbeContext) -> Result<(), ProbeError> {
+    let dir = ctx.sys_path("class/dmi/id");
+    std::fs::metadata(&dir).map(|_| ()).map_err(|e| ProbeError::from_io(&dir, &e))
+}
+
+#[cfg(target_os = "linux")]
+fn read_baseboard_info(ctx: &ProbeContext) -> Result<BaseboardInfo, ProbeError> {
+    check_dmi(ctx)?;
+
+    // Try sysfs first (no root needed)
+    let manufacturer = read_sysfs_file(ctx.sys_path("class/dmi/id/board_vendor"));
+    let model = read_sysfs_file(ctx.sys_path("class/dmi/id/board_name"));
+    let version = read_sysfs_file(ctx.sys_path("class/dmi/id/board_version"));
+    // serial requires root via sysfs too; report it separately so the rest
+    // of the board still counts as a success
+    let path = ctx.sys_path("class/dmi/id/board_serial");
+    let serial = ctx.probe("baseboard.serial", path.display(), || {
+        std::fs::read_to_string(&path)
+            .map(|s| s.trim().to_string())
+            .map_err(|e| ProbeError::from_io(&path, &e))
+    });
+
+    Ok(BaseboardInfo {
+        manufacturer,
+        model,
+        version,
+        serial,
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn read_bios_info(ctx: &ProbeContext) -> Result<BiosInfo, ProbeError> {
+    check_dmi(ctx)?;
+
+    Ok(BiosInfo {
+        vendor: read_sysfs_file(ctx.sys_path("class/dmi/id/bios_vendor")),
+        version: read_sysfs_file(ctx.sys_path("class/dmi/id/bios_version")),
+        release_date: read_sysfs_file(ctx.sys_path("class/dmi/id/bios_date")),
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn read_uuid_info(ctx: &ProbeContext) -> Result<UuidInfo, ProbeError> {
+    let mut macs: Vec<String> = Vec::new();
+
+    for name in crate::network::interface_names(ctx)? {
+        // Try reading the MAC from sysfs
+        let mac = read_sysfs_file(ctx.sys_path(format!("class/net/{}/address", name)));
+        if !mac.is_empty() && mac != "00:00:00:00:00:00" {
+            macs.push(mac);
+        }
+    }
+
+    Ok(UuidInfo { macs })
+}
+
+// ——— Platform-specific helpers (Windows) ———
+
+#[cfg(target_os = "windows")]
+fn dmi_source(_ctx: &ProbeContext) -> String {
+    "Win32_BaseBoard / Win32_BIOS".to_string()
+}
+
+#[cfg(target_os = "windows")]
+fn uuid_source(_ctx: &ProbeContext) -> String {
+    "Win32_NetworkAdapterConfiguration".to_string()
+}
+
+#[cfg(target_os = "windows")]
+fn read_baseboard_info(_ctx: &ProbeContext) -> Result<BaseboardInfo, ProbeError> {
+    use crate::windows::{exec_powershell, PsBoard};
+
+    let script = "@(Get-CimInstance Win32_BaseBoard) | Select-Object Manufacturer, Product, Version, SerialNumber | ConvertTo-Json -Compress";
+    let boards: Vec<PsBoard> = exec_powershell(script)?;
+
+    Ok(boards
+        .first()
+        .map(|b| BaseboardInfo {
+            manufacturer: b.Manufacturer.clone().unwrap_or_default(),
+            model: b.Product.clone().unwrap_or_default(),
+            version: b.Version.clone().unwrap_or_default(),
+            serial: b.SerialNumber.clone().unwrap_or_default(),
+        })
+        .unwrap_or_default())
+}
+
+#[cfg(target_os = "windows")]
+fn read_bios_info(_ctx: &ProbeContext) -> Result<BiosInfo, ProbeError> {
+    use crate::windows::{exec_powershell, PsBios};
+
+    let script = "@(Get-CimInstance Win32_BIOS) | Select-Object Manufacturer, SMBIOSBIOSVersion, ReleaseDate | ConvertTo-Json -Compress";
+    let bioses: Vec<PsBios> = exec_powershell(script)?;
+
+    // ReleaseDate in WMI is often YYYYMMDDHHMMSS... encoded string.
+    // But ConvertTo-Json might output different structure?
+    // Usually it's a string like "/Date(123456)/" or raw string.
+    // We'll just take it as string for now.
+    Ok(bioses
+        .first()
+        .map(|b| BiosInfo {
+            vendor: b.Manufacturer.clone().unwrap_or_default(),
+            version: b.SMBIOSBIOSVersion.clone().unwrap_or_default(),
+            release_date: b.ReleaseDate.clone().unwrap_or_default(),
+        })
+        .unwrap_or_default())
+}
+
+#[cfg(target_os = "windows")]
+fn read_uuid_info(_ctx: &ProbeContext) -> Result<UuidInfo, ProbeError> {
+    use crate::windows::{exec_powershell, PsNetAdapter};
+
+    // We need MAC addresses of IPEnabled adapters
+    let script = "@(Get-CimInstance Win32_NetworkAdapterConfiguration -Filter 'IPEnabled=True') | Select-Object MACAddress | ConvertTo-Json -Compress";
+    let adapters: Vec<PsNetAdapter> = exec_powershell(script)?;
+    let macs = adapters.into_iter().filter_map(|a| a.MACAddress).collect();
+
+    Ok(UuidInfo { macs })
+}
+
+// ——— Common helpers ———
+
+fn read_os_info(ctx: &ProbeContext, sys: &System) -> OsInfo {
+    let hostname = System::host_name().unwrap_or_default();
+    let kernel = System::kernel_version().unwrap_or_default();
+    let name = System::name().unwrap_or_default();
+    let os_version = System::os_version().unwrap_or_default();
+    let long_os_version = System::long_os_version().unwrap_or_default();
+    let arch = System::cpu_arch().unwrap_or_default();
+
+    // Check for UEFI
+    #[cfg(target_os = "linux")]
+    let uefi = ctx.sys_path("firmware/efi").exists();
+    #[cfg(not(target_os = "linux"))]
+    let uefi = false; // Simplified
+    #[cfg(not(target_os = "linux"))]
+    let _ = ctx;
+
+    let platform = if cfg!(target_os = "linux") {
+        "linux".to_string()
+    } else if cfg!(target_os = "windows") {
+        "win32".to_string()
+    } else if cfg!(target_os = "macos") {
+        "darwin".to_string()
+    } else {
+        std::env::consts::OS.to_string()
+    };
+
+    let _ = sys;
+
+    OsInfo {
+        platform,
+        distro: long_os_version.clone(),
+        release: os_version,
+        hostname: hostname.clone(),
+        kernel,
+        arch,
+        fqdn: name,
+        uefi,
+    }
+}
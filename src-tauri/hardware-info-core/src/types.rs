@@ -0,0 +1,996 @@
+use serde::Serialize;
+
+// ——— Data structs (mirror the original JS API shape) ———
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuCache {
+    /// Sizes of the caches serving cpu0, in bytes.
+    pub l1d: u64,
+    pub l1i: u64,
+    pub l2: u64,
+    pub l3: u64,
+    /// Every cache instance across all CPUs, by level then type.
+    pub instances: Vec<CacheInstance>,
+    pub totals: Vec<CacheTotal>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheInstance {
+    pub level: u32,
+    /// `Data`, `Instruction` or `Unified`.
+    #[serde(rename = "type")]
+    pub cache_type: String,
+    pub size: u64,
+    pub line_size: u32,
+    /// 0 when fully associative or not reported.
+    pub ways: u32,
+    pub sets: u32,
+    /// Logical CPUs sharing this instance.
+    pub cpus: Vec<u32>,
+}
+
+/// Combined size of all instances of one level and type.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheTotal {
+    pub level: u32,
+    #[serde(rename = "type")]
+    pub cache_type: String,
+    pub size: u64,
+    pub instances: usize,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuInfo {
+    pub brand: String,
+    pub vendor: String,
+    pub family: String,
+    pub model: String,
+    pub stepping: String,
+    pub physical_cores: usize,
+    pub cores: usize,
+    pub speed: f64,
+    pub speed_max: f64,
+    pub cache: CpuCache,
+    pub topology: CpuTopology,
+    /// Performance and efficiency core groups, fastest first; empty unless
+    /// the processor mixes core types.
+    pub core_types: Vec<CpuCoreType>,
+    pub features: CpuFeatures,
+    pub frequency_scaling: FrequencyScaling,
+    /// Draw over the report's sample interval; empty from
+    /// [`collect_cpu_info`](crate::collect_cpu_info) alone.
+    pub power: CpuPower,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuCoreType {
+    /// `performance`, `efficiency`, or `mid` for the tiers between on
+    /// processors with three or more.
+    pub kind: String,
+    pub cores: usize,
+    pub cpus: Vec<u32>,
+    /// Hardware frequency limits of these cores, in GHz; 0 when unknown.
+    pub speed_min: f64,
+    pub speed_max: f64,
+}
+
+/// How logical CPUs are laid out: packages hold dies, dies hold clusters of
+/// cores, and each core runs one or more hardware threads.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuTopology {
+    pub packages: Vec<CpuPackage>,
+    /// Every logical CPU the kernel knows of, online or not.
+    pub cpus: Vec<LogicalCpu>,
+    pub numa_nodes: Vec<NumaNode>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuPackage {
+    pub id: u32,
+    pub dies: Vec<CpuDie>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuDie {
+    pub id: u32,
+    pub clusters: Vec<CpuCluster>,
+}
+
+/// Cores sharing an L2 cache or similar; one core per cluster where the
+/// hardware has no such grouping.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuCluster {
+    pub id: u32,
+    pub cores: Vec<CpuCore>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuCore {
+    /// Unique within the package only.
+    pub id: u32,
+    /// Logical CPU numbers of the core's SMT siblings.
+    pub threads: Vec<u32>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LogicalCpu {
+    pub cpu: u32,
+    pub online: bool,
+    pub numa_node: Option<u32>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NumaNode {
+    pub id: u32,
+    pub cpus: Vec<u32>,
+    /// Memory local to the node, in bytes.
+    pub memory_total: u64,
+    /// Relative access cost to each node by id; 10 is local.
+    pub distances: Vec<u32>,
+}
+
+/// The kernel's cpufreq setup; empty where frequency is not scaled, as in
+/// most virtual machines.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FrequencyScaling {
+    /// `intel_pstate`, `amd-pstate-epp`, `acpi-cpufreq`, `cpufreq-dt`, …
+    pub driver: String,
+    /// Turbo/boost enabled; `None` when the driver has no switch for it.
+    pub boost: Option<bool>,
+    pub policies: Vec<CpufreqPolicy>,
+}
+
+/// One `cpufreq/policy*` directory. Frequencies are in GHz, 0 when not
+/// reported.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpufreqPolicy {
+    pub policy: u32,
+    /// CPUs this policy governs.
+    pub cpus: Vec<u32>,
+    pub governor: String,
+    pub available_governors: Vec<String>,
+    /// What the hardware allows.
+    pub hardware_min: f64,
+    pub hardware_max: f64,
+    /// What the governor is currently allowed to use.
+    pub scaling_min: f64,
+    pub scaling_max: f64,
+    pub current: f64,
+    /// Guaranteed non-turbo frequency, from `base_frequency`.
+    pub base: f64,
+    /// `energy_performance_preference` of HWP and amd-pstate in active
+    /// mode; empty elsewhere.
+    pub energy_performance_preference: String,
+    pub available_preferences: Vec<String>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuFeatures {
+    /// Every flag the kernel reports, in its order.
+    pub flags: Vec<String>,
+    /// The recognised instruction set extensions among `flags`.
+    pub extensions: Vec<FeatureCategory>,
+    /// Empty where the kernel does not report it, as on ARM.
+    pub microcode: String,
+    /// `vmx` (Intel VT-x) or `svm` (AMD-V); empty when the processor lacks
+    /// it or a hypervisor hides it.
+    pub virtualization: String,
+    /// Running as a guest under a hypervisor.
+    pub hypervisor: bool,
+    pub vulnerabilities: Vec<CpuVulnerability>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureCategory {
+    pub category: String,
+    pub flags: Vec<String>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuVulnerability {
+    /// File name under `vulnerabilities/`, e.g. `spectre_v2`.
+    pub name: String,
+    /// `not affected`, `mitigated`, `vulnerable` or `unknown`.
+    pub status: String,
+    /// The kernel's full line, which names the mitigation in use.
+    pub detail: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuCurrentSpeed {
+    pub avg: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Per logical CPU, in GHz.
+    pub cores: Vec<f64>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentLoad {
+    pub current_load: f64,
+    /// Per logical CPU, in percent.
+    pub cpus: Vec<f64>,
+}
+
+/// Where CPU time went between two samples, in percent of the time of all
+/// logical CPUs together.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuTimes {
+    pub user: f64,
+    pub nice: f64,
+    pub system: f64,
+    pub idle: f64,
+    pub iowait: f64,
+    pub irq: f64,
+    pub softirq: f64,
+    /// Time a hypervisor ran something else while this guest wanted the CPU.
+    pub steal: f64,
+}
+
+/// Energy domains of the processor and memory, with the power each drew
+/// between two samples.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuPower {
+    pub domains: Vec<PowerDomain>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerDomain {
+    /// `package-0`, `core`, `uncore`, `dram`, `psys`, or an amd_energy
+    /// label such as `Esocket0`.
+    pub name: String,
+    /// Where it comes from: a powercap zone such as `intel-rapl:0:1`, or
+    /// `hwmon3/energy1` for amd_energy.
+    pub zone: String,
+    pub enabled: bool,
+    /// Average draw in watts; `None` on the first sample, after a counter
+    /// reset, or when the energy counter is unreadable (root-only since
+    /// Linux 5.10, and reported by the `cpu.power` probe).
+    pub watts: Option<f64>,
+    pub limits: Vec<PowerLimit>,
+}
+
+/// A powercap constraint: `long_term` is PL1, `short_term` PL2.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerLimit {
+    pub name: String,
+    pub watts: f64,
+    /// Averaging window in seconds; `None` for instantaneous limits.
+    pub time_window: Option<f64>,
+    pub max_watts: Option<f64>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuTemperature {
+    pub main: f64,
+    pub max: f64,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MemorySlot {
+    pub slot: usize,
+    pub size: u64,
+    pub clock_speed: u64,
+    #[serde(rename = "type")]
+    pub mem_type: String,
+    pub form_factor: String,
+    pub manufacturer: String,
+    pub part_num: String,
+    pub serial_num: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryInfo {
+    pub total: u64,
+    pub used: u64,
+    pub available: u64,
+    pub active: u64,
+    pub swaptotal: u64,
+    pub swapused: u64,
+    pub swapfree: u64,
+    pub layout: Vec<MemorySlot>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GpuController {
+    pub model: String,
+    pub vendor: String,
+    pub vram: u64,
+    pub bus: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphicsInfo {
+    pub controllers: Vec<GpuController>,
+}
+
+/// One function on the PCI bus. IDs are lowercase hex without `0x`, as
+/// `lspci -n` prints them; names come from `pci.ids`.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PciDevice {
+    /// Domain, bus, device and function, e.g. `0000:01:00.0`.
+    pub address: String,
+    /// Base class and subclass, e.g. `0300`.
+    pub class_id: String,
+    pub prog_if: String,
+    pub class_name: String,
+    pub subclass_name: String,
+    pub vendor_id: String,
+    pub device_id: String,
+    pub subsystem_vendor_id: String,
+    pub subsystem_device_id: String,
+    pub revision: String,
+    pub vendor: String,
+    pub device: String,
+    pub subsystem_vendor: String,
+    pub subsystem: String,
+    /// Kernel driver bound to the device; empty when none is.
+    pub driver: String,
+    pub iommu_group: Option<u32>,
+    pub numa_node: Option<u32>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PciInfo {
+    pub devices: Vec<PciDevice>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskLayoutEntry {
+    /// Kernel name, e.g. `sda` or `nvme0n1`.
+    pub name: String,
+    /// `HDD`, `SSD` or `Virtual`.
+    #[serde(rename = "type")]
+    pub disk_type: String,
+    /// Capacity in bytes.
+    pub size: u64,
+    /// Transport: `SATA`, `NVMe`, `USB`, `virtio`, `SCSI` or `MMC`.
+    pub interface_type: String,
+    pub model: String,
+    pub vendor: String,
+    pub serial: String,
+    pub wwn: String,
+    pub firmware_revision: String,
+    pub rotational: bool,
+    pub removable: bool,
+    pub logical_sector_size: u32,
+    pub physical_sector_size: u32,
+    /// `None` when the disk has no partition table.
+    pub partition_table: Option<PartitionTable>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PartitionTable {
+    /// `GPT` or `MBR`; empty when only the kernel's view was readable.
+    pub scheme: String,
+    /// GPT disk GUID, or the MBR disk signature as eight hex digits.
+    pub disk_id: String,
+    /// Bytes per LBA the table was decoded with.
+    pub sector_size: u32,
+    pub partitions: Vec<Partition>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Partition {
+    /// Table slot; MBR logical partitions start at 5.
+    pub number: u32,
+    pub start_lba: u64,
+    /// Last sector, inclusive.
+    pub end_lba: u64,
+    /// Size in bytes.
+    pub size: u64,
+    /// Type GUID (GPT) or `0x83`-style system ID (MBR).
+    pub type_id: String,
+    /// e.g. `EFI System`, `Linux filesystem`, `Microsoft basic data`.
+    pub type_name: String,
+    /// GPT partition name.
+    pub label: String,
+    /// GPT unique partition GUID, or `<signature>-<number>` on MBR.
+    pub uuid: String,
+    /// e.g. `boot`, `required`, `legacy-boot`, `hidden`, `read-only`.
+    pub flags: Vec<String>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FilesystemEntry {
+    pub mount: String,
+    #[serde(rename = "type")]
+    pub fs_type: String,
+    pub used: u64,
+    pub size: u64,
+    #[serde(rename = "use")]
+    pub usage_pct: f64,
+}
+
+/// One block device in the storage stack. Children are the devices built
+/// on top of it, so a tree reads disk → partition → RAID / crypt / LV.
+/// Like `lsblk`, a device with several parents (a RAID array) appears under
+/// each of them.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageNode {
+    /// Kernel name, e.g. `sda1`, `md0` or `dm-2`.
+    pub name: String,
+    /// `/dev/mapper` name of a device-mapper target, e.g. `vg0-root`.
+    pub mapper_name: String,
+    /// `disk`, `partition`, `raid`, `crypt`, `lvm`, `multipath`, `dm`,
+    /// `loop` or `virtual`.
+    pub kind: String,
+    /// Size in bytes.
+    pub size: u64,
+    /// Type of the mounted filesystem, or `swap`.
+    pub fs_type: String,
+    /// Where it is mounted; `[SWAP]` for active swap.
+    pub mountpoints: Vec<String>,
+    pub raid: Option<RaidInfo>,
+    pub children: Vec<StorageNode>,
+}
+
+/// md array state from `/sys/block/md*/md`.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RaidInfo {
+    /// e.g. `raid1`, `raid5`.
+    pub level: String,
+    /// `array_state`, e.g. `clean`, `active`, `read-auto`.
+    pub array_state: String,
+    /// `idle`, or what the array is doing: `resync`, `recover`, `check`…
+    pub sync_action: String,
+    /// Percent done while `sync_action` is not `idle`.
+    pub sync_progress: Option<f64>,
+    /// Member slots the array is meant to have.
+    pub raid_disks: u32,
+    /// Slots currently missing or failed.
+    pub degraded: u32,
+    pub members: Vec<RaidMember>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RaidMember {
+    /// Kernel name of the member, e.g. `sda1`.
+    pub name: String,
+    /// e.g. `in_sync`, `faulty`, `spare`, `write_mostly`.
+    pub state: String,
+    /// Role in the array; `None` for spares.
+    pub slot: Option<u32>,
+}
+
+/// Activity of one disk over a sampling interval, from `/proc/diskstats`.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskIo {
+    /// Kernel name, matching [`DiskLayoutEntry::name`].
+    pub name: String,
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    pub read_iops: f64,
+    pub write_iops: f64,
+    /// Average time a read took to complete, in milliseconds.
+    pub read_latency_ms: f64,
+    pub write_latency_ms: f64,
+    /// Average number of requests in flight (`aqu-sz` in iostat).
+    pub queue_depth: f64,
+    /// Share of the interval the disk was busy, 0–100 (`%util`).
+    pub utilization_pct: f64,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageInfo {
+    pub disk_layout: Vec<DiskLayoutEntry>,
+    /// Physical disks with everything stacked on them, down to mount points.
+    pub stack: Vec<StorageNode>,
+    pub filesystems: Vec<FilesystemEntry>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkInterface {
+    pub iface: String,
+    /// Kernel interface index; 0 where the OS does not say.
+    pub ifindex: u32,
+    /// `physical`, `wireless`, `loopback`, `bridge`, `bond`, `vlan`, `veth`,
+    /// `tun`, `tap`, another link kind such as `wireguard`, or `virtual`.
+    pub kind: String,
+    pub ip4: Vec<InterfaceAddress>,
+    pub ip6: Vec<InterfaceAddress>,
+    pub mac: String,
+    pub mtu: u32,
+    /// Kernel operational state: `up`, `down`, `dormant`, `unknown`, ….
+    pub operstate: String,
+    /// Whether a cable or association is present; `None` while the
+    /// interface is administratively down.
+    pub carrier: Option<bool>,
+    /// Negotiated link speed; `None` when down or not reported.
+    pub speed_mbps: Option<u32>,
+    /// `full`, `half` or `unknown`; empty when not reported.
+    pub duplex: String,
+    pub driver: String,
+    /// Bus of the underlying hardware, `pci` or `usb`; empty for software
+    /// interfaces.
+    pub parent_bus: String,
+    /// PCI address or USB interface of the underlying hardware.
+    pub parent_device: String,
+    /// Association and radio details; `None` unless `kind` is `wireless`.
+    pub wireless: Option<WirelessInfo>,
+    /// Bridge or bond this interface is a port of.
+    pub master: String,
+    /// Ports of a bridge or slaves of a bond.
+    pub ports: Vec<String>,
+    pub bond: Option<BondInfo>,
+    pub vlan: Option<VlanInfo>,
+    pub veth_peer: Option<VethPeer>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BondInfo {
+    /// e.g. `active-backup` or `802.3ad`.
+    pub mode: String,
+    /// Slave carrying traffic in active-backup style modes.
+    pub active_slave: String,
+    pub mii_status: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VlanInfo {
+    pub id: u16,
+    /// Interface the tagged frames go out on.
+    pub parent: String,
+}
+
+/// The other end of a veth pair.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VethPeer {
+    /// Empty when the peer lives in another network namespace.
+    pub name: String,
+    pub ifindex: u32,
+    /// Namespace id as this namespace numbers it; `None` for this one.
+    pub netns_id: Option<u32>,
+    /// Name of that namespace, when it has one under `/run/netns`.
+    pub netns: String,
+}
+
+/// A named network namespace, as `ip netns` lists them.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkNamespace {
+    pub name: String,
+    /// Id assigned in this namespace; `None` until one is needed.
+    pub id: Option<u32>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WirelessInfo {
+    /// The radio behind the interface, e.g. `phy0`.
+    pub phy: String,
+    /// Empty while not associated.
+    pub ssid: String,
+    pub bssid: String,
+    pub frequency_mhz: Option<u32>,
+    pub channel: Option<u32>,
+    /// `2.4 GHz`, `5 GHz`, `6 GHz` or `60 GHz`.
+    pub band: String,
+    pub channel_width_mhz: Option<u32>,
+    pub signal_dbm: Option<i32>,
+    pub noise_dbm: Option<i32>,
+    /// Link quality as a percentage of the driver's scale.
+    pub link_quality_pct: Option<f64>,
+    pub tx_bitrate_mbps: Option<f64>,
+    pub rx_bitrate_mbps: Option<f64>,
+    /// Bands the radio can use, in the same notation as `band`.
+    pub supported_bands: Vec<String>,
+    /// Two-letter country of the regulatory domain in force, or `00` for
+    /// the world domain.
+    pub regulatory_domain: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct InterfaceAddress {
+    pub address: String,
+    pub prefix_len: u8,
+    /// `global`, `link`, `host`, …; empty where the OS does not say.
+    pub scope: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkInfo {
+    pub interfaces: Vec<NetworkInterface>,
+    pub routes: Vec<Route>,
+    /// Lowest-metric default route of each family.
+    pub ipv4_gateway: Option<Route>,
+    pub ipv6_gateway: Option<Route>,
+    pub dns: DnsConfig,
+    pub namespaces: Vec<NetworkNamespace>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Route {
+    /// `ipv4` or `ipv6`.
+    pub family: String,
+    /// CIDR notation; `0.0.0.0/0` and `::/0` are the default routes.
+    pub destination: String,
+    /// Next hop; empty for on-link routes.
+    pub gateway: String,
+    pub iface: String,
+    pub metric: u32,
+    /// `main` or `local`.
+    pub table: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsConfig {
+    pub servers: Vec<String>,
+    pub search_domains: Vec<String>,
+    /// Local stub forwarding the queries, e.g. `systemd-resolved`; empty
+    /// when `servers` are queried directly.
+    pub stub_resolver: String,
+    /// File the servers were read from.
+    pub source: String,
+}
+
+/// Traffic on one interface over a sampling interval, with the kernel's
+/// running totals at the end of it.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkIo {
+    pub iface: String,
+    pub rx_bytes_per_sec: f64,
+    pub tx_bytes_per_sec: f64,
+    pub rx_packets_per_sec: f64,
+    pub tx_packets_per_sec: f64,
+    /// Errors and drops during the interval.
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    /// Since boot, or since the interface was created.
+    pub rx_bytes_total: u64,
+    pub tx_bytes_total: u64,
+    pub rx_packets_total: u64,
+    pub tx_packets_total: u64,
+    pub rx_errors_total: u64,
+    pub tx_errors_total: u64,
+    pub rx_dropped_total: u64,
+    pub tx_dropped_total: u64,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioDevice {
+    pub name: String,
+    pub manufacturer: String,
+    pub status: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioInfo {
+    pub devices: Vec<AudioDevice>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UsbDevice {
+    /// Product name from `usb.ids`, else the device's own product string.
+    pub name: String,
+    /// Vendor name from `usb.ids`, else the device's manufacturer string.
+    pub vendor: String,
+    pub vendor_id: String,
+    pub product_id: String,
+    pub bus: String,
+    pub device: String,
+    /// Strings the device reports about itself.
+    pub manufacturer: String,
+    pub product: String,
+    pub serial: String,
+    /// `bDeviceClass` as two hex digits, and its name from `usb.ids`.
+    pub device_class: String,
+    pub class_name: String,
+    /// Negotiated speed in Mbit/s, e.g. 480 for high speed.
+    pub speed_mbps: f64,
+    pub max_power_ma: u32,
+    /// USB specification version the device claims (`bcdUSB`), e.g. `3.20`.
+    pub usb_version: String,
+    /// Bus and hub ports leading to the device, e.g. `1-6.2`.
+    pub port_path: String,
+}
+
+/// One interface of a USB device and the driver bound to it.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UsbInterface {
+    /// `bInterfaceNumber`.
+    pub number: u32,
+    /// Class, subclass and protocol as two hex digits each.
+    pub class: String,
+    pub subclass: String,
+    pub protocol: String,
+    pub class_name: String,
+    pub subclass_name: String,
+    /// Empty when no driver is bound.
+    pub driver: String,
+}
+
+/// A device in the USB tree with everything plugged into it.
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UsbNode {
+    pub device: UsbDevice,
+    /// Port on the parent hub; 0 for root hubs.
+    pub port: u32,
+    /// A USB 3 device running below SuperSpeed, typically because it sits
+    /// behind a USB 2 hub or cable.
+    pub speed_downgraded: bool,
+    pub interfaces: Vec<UsbInterface>,
+    pub children: Vec<UsbNode>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PeripheralInfo {
+    pub usb_devices: Vec<UsbDevice>,
+    /// The same devices arranged by root hub, hub and port.
+    pub usb_tree: Vec<UsbNode>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OpticalDevice {
+    pub name: String,
+    pub model: String,
+    pub vendor: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OpticalInfo {
+    pub devices: Vec<OpticalDevice>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BaseboardInfo {
+    pub manufacturer: String,
+    pub model: String,
+    pub version: String,
+    pub serial: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BiosInfo {
+    pub vendor: String,
+    pub version: String,
+    pub release_date: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OsInfo {
+    pub platform: String,
+    pub distro: String,
+    pub release: String,
+    pub hostname: String,
+    pub kernel: String,
+    pub arch: String,
+    pub fqdn: String,
+    pub uefi: bool,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UuidInfo {
+    pub macs: Vec<String>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionsInfo {
+    pub node: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StaticData {
+    pub baseboard: BaseboardInfo,
+    pub bios: BiosInfo,
+    pub os: OsInfo,
+    pub uuid: UuidInfo,
+    pub versions: VersionsInfo,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SensorInfo {
+    /// One entry per `/sys/class/hwmon/hwmon*` chip, in hwmon order.
+    pub chips: Vec<SensorChip>,
+    pub thermal_zones: Vec<ThermalZone>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SensorChip {
+    /// e.g. `hwmon2`.
+    pub hwmon: String,
+    /// Driver-chosen chip name, e.g. `coretemp` or `nct6798`.
+    pub name: String,
+    /// Device the chip belongs to, e.g. `coretemp.0` or `nvme0`.
+    pub device: String,
+    pub sensors: Vec<Sensor>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Sensor {
+    /// `temperature`, `fan`, `voltage`, `current`, `power` or `energy`.
+    pub kind: String,
+    /// Attribute prefix, e.g. `temp1` or `fan2`.
+    pub channel: String,
+    /// The chip's own label, empty when it has none.
+    pub label: String,
+    /// `°C`, `RPM`, `V`, `A`, `W` or `J`; values and thresholds are scaled
+    /// from the kernel's milli/micro units.
+    pub unit: String,
+    pub value: Option<f64>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub crit: Option<f64>,
+    /// Alarm and fault attributes that are set, e.g. `max_alarm` or `fault`.
+    pub alarms: Vec<String>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ThermalZone {
+    /// e.g. `thermal_zone0`.
+    pub zone: String,
+    /// What the zone measures, e.g. `acpitz` or `x86_pkg_temp`.
+    #[serde(rename = "type")]
+    pub zone_type: String,
+    /// °C; `None` while the zone is disabled.
+    pub temperature: Option<f64>,
+    /// Governor, e.g. `step_wise`.
+    pub policy: String,
+    pub trip_points: Vec<TripPoint>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TripPoint {
+    /// `active`, `passive`, `hot` or `critical`.
+    #[serde(rename = "type")]
+    pub trip_type: String,
+    /// °C.
+    pub temperature: f64,
+    pub hysteresis: Option<f64>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeInfo {
+    pub uptime: u64,
+    pub current: u64,
+}
+
+// ——— Diagnostics ———
+
+/// Why a probe came back empty or incomplete.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ProbeError {
+    /// The external tool is not installed.
+    MissingTool { tool: String },
+    /// A file or tool needs more privileges than the app has.
+    PermissionDenied { path: String },
+    /// The data source does not exist on this machine.
+    NotFound { path: String },
+    /// The source was read but its contents could not be understood.
+    ParseFailure { source: String, message: String },
+    /// A tool ran but exited unsuccessfully.
+    CommandFailed { tool: String, exit_code: Option<i32>, stderr: String },
+    /// Any other I/O error.
+    Io { path: String, message: String },
+    /// Not implemented on this operating system.
+    Unsupported { platform: String },
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ProbeReport {
+    /// Dotted probe name, e.g. `memory.layout`.
+    pub probe: String,
+    /// File, directory or command the probe read.
+    pub source: String,
+    pub duration_ms: f64,
+    pub error: Option<ProbeError>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostics {
+    pub probes: Vec<ProbeReport>,
+}
+
+// ——— Full response types ———
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HardwareInfo {
+    pub static_data: StaticData,
+    pub cpu: CpuInfo,
+    pub cpu_current_speed: CpuCurrentSpeed,
+    pub current_load: CurrentLoad,
+    pub cpu_times: CpuTimes,
+    pub cpu_temperature: CpuTemperature,
+    pub sensors: SensorInfo,
+    pub graphics: GraphicsInfo,
+    pub pci: PciInfo,
+    pub network: NetworkInfo,
+    pub network_io: Vec<NetworkIo>,
+    pub storage: StorageInfo,
+    pub disk_io: Vec<DiskIo>,
+    pub memory: MemoryInfo,
+    pub audio: AudioInfo,
+    pub peripherals: PeripheralInfo,
+    pub optical: OpticalInfo,
+    pub runtime: RuntimeInfo,
+    pub diagnostics: Diagnostics,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveInfo {
+    pub cpu_current_speed: CpuCurrentSpeed,
+    pub current_load: CurrentLoad,
+    pub cpu_times: CpuTimes,
+    pub cpu_power: CpuPower,
+    pub cpu_temperature: CpuTemperature,
+    pub sensors: SensorInfo,
+    pub memory: MemoryInfo,
+    pub disk_io: Vec<DiskIo>,
+    pub network_io: Vec<NetworkIo>,
+    pub runtime: RuntimeInfo,
+}
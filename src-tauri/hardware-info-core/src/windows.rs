@@ -0,0 +1,85 @@
+//! PowerShell/CIM plumbing shared by the Windows collectors.
+
+use serde::Deserialize;
+
+use crate::types::ProbeError;
+
+// ——— PowerShell Helper ———
+
+pub(crate) fn exec_powershell<T: serde::de::DeserializeOwned>(script: &str) -> Result<T, ProbeError> {
+    use std::os::windows::process::CommandExt;
+
+    // CREATE_NO_WINDOW flag to prevent console popups
+    const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+    let output = std::process::Command::new("powershell")
+        .args(["-NoProfile", "-Command", script])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .map_err(|e| ProbeError::from_spawn("powershell", &e))?;
+
+    if !output.status.success() {
+        return Err(ProbeError::CommandFailed {
+            tool: "powershell".to_string(),
+            exit_code: output.status.code(),
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // Handle single object vs array: ConvertTo-Json might return single obj if only 1 result.
+    // We can force array in PS or handle it here.
+    // Better to use `ConvertTo-Json -AsArray` if available (PS 7+), but standard PS 5.1 doesn't have it easily?
+    // Actually, wrap in @() in PS: @(Get-CimInstance...)
+
+    serde_json::from_str::<T>(&stdout).map_err(|e| ProbeError::ParseFailure {
+        source: "powershell".to_string(),
+        message: e.to_string(),
+    })
+}
+
+// ——— Wrapper Structs for PowerShell JSON ———
+
+#[derive(Deserialize, Debug)]
+#[allow(non_snake_case)]
+pub(crate) struct PsGpu {
+    pub(crate) Name: Option<String>,
+    pub(crate) VideoProcessor: Option<String>,
+    pub(crate) AdapterRAM: Option<u64>,
+}
+
+#[derive(Deserialize, Debug)]
+#[allow(non_snake_case)]
+pub(crate) struct PsMem {
+    pub(crate) Capacity: Option<u64>,
+    pub(crate) Speed: Option<u32>,
+    pub(crate) Manufacturer: Option<String>,
+    pub(crate) PartNumber: Option<String>,
+    pub(crate) SerialNumber: Option<String>,
+    pub(crate) FormFactor: Option<u16>,
+    pub(crate) MemoryType: Option<u16>,
+}
+
+#[derive(Deserialize, Debug)]
+#[allow(non_snake_case)]
+pub(crate) struct PsBoard {
+    pub(crate) Manufacturer: Option<String>,
+    pub(crate) Product: Option<String>,
+    pub(crate) Version: Option<String>,
+    pub(crate) SerialNumber: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[allow(non_snake_case)]
+pub(crate) struct PsBios {
+    pub(crate) Manufacturer: Option<String>,
+    pub(crate) SMBIOSBIOSVersion: Option<String>,
+    pub(crate) ReleaseDate: Option<String>, // PowerShell date format might be weird
+}
+
+#[derive(Deserialize, Debug)]
+#[allow(non_snake_case)]
+pub(crate) struct PsNetAdapter {
+    pub(crate) MACAddress: Option<String>,
+    pub(crate) IPEnabled: Option<bool>,
+}
@@ -0,0 +1,191 @@
+//! Wi-Fi details for interfaces the kernel marks as wireless: signal and
+//! link quality from `/proc/net/wireless`, association and radio details
+//! from `iw`, which speaks nl80211 to the driver.
+//!
+//! Going through `iw` rather than opening an nl80211 socket is deliberate:
+//! it keeps a netlink dependency out of the crate, and the commands can be
+//! recorded and replayed like every other tool. `iw` warns that its output
+//! may change between releases, so the parsers below only pick out
+//! long-standing lines, and they are tested against recorded output. A
+//! missing `iw` leaves just the `/proc` figures.
+
+use crate::command::CommandOutput;
+use crate::context::ProbeContext;
+use crate::read_link_name;
+use crate::types::{ProbeError, WirelessInfo};
+
+/// cfg80211 scales link quality to 0–70 for the wireless-extensions view.
+const QUALITY_SCALE: f64 = 70.0;
+
+/// What `/proc/net/wireless` shows when the driver has no noise figure.
+const NOISE_UNKNOWN: i32 = -256;
+
+/// Everything known about wireless interface `name`. Each source fills what
+/// it can; without `iw` only the `/proc` figures are present.
+pub(crate) fn read_wireless(ctx: &ProbeContext, name: &str) -> WirelessInfo {
+    let mut info = WirelessInfo {
+        phy: read_link_name(ctx.sys_path("class/net").join(name).join("phy80211")).unwrap_or_default(),
+        ..Default::default()
+    };
+
+    let proc_wireless = ctx.proc_path("net/wireless");
+    ctx.probe("network.signal", proc_wireless.display(), || {
+        let text = std::fs::read_to_string(&proc_wireless).map_err(|e| ProbeError::from_io(&proc_wireless, &e))?;
+        apply_proc_wireless(&text, name, &mut info);
+        Ok(())
+    });
+    ctx.probe("network.wireless", "iw", || {
+        apply_iw_link(&run_iw(ctx, &["dev", name, "link"])?.stdout, &mut info);
+        apply_iw_info(&run_iw(ctx, &["dev", name, "info"])?.stdout, &mut info);
+        if !info.phy.is_empty() {
+            info.supported_bands = parse_iw_bands(&run_iw(ctx, &["phy", &info.phy, "info"])?.stdout);
+        }
+        info.regulatory_domain = parse_iw_reg(&run_iw(ctx, &["reg", "get"])?.stdout, &info.phy);
+        Ok(())
+    });
+
+    if let Some(freq) = info.frequency_mhz {
+        info.band = band_name(freq).to_string();
+        info.channel = info.channel.or_else(|| channel_number(freq));
+    }
+    info
+}
+
+/// `iw` is optional: when it is not installed the details are absent, as
+/// with a missing file, rather than the probe failing.
+fn run_iw(ctx: &ProbeContext, args: &[&str]) -> Result<CommandOutput, ProbeError> {
+    ctx.run_checked("iw", args).map_err(|e| match e {
+        ProbeError::MissingTool { tool } => ProbeError::NotFound { path: tool },
+        other => other,
+    })
+}
+
+/// `wlp4s0: 0000   58.  -52.  -256  …`: status, then link quality, signal
+/// level and noise, each with a trailing dot when the value is fresh.
+fn apply_proc_wireless(text: &str, name: &str, info: &mut WirelessInfo) {
+    let Some(fields) = text.lines().find_map(|line| {
+        let (iface, rest) = line.split_once(':')?;
+        (iface.trim() == name).then(|| rest.split_whitespace().collect::<Vec<_>>())
+    }) else {
+        return;
+    };
+    let value = |i: usize| -> Option<f64> { fields.get(i)?.trim_end_matches('.').parse().ok() };
+
+    info.link_quality_pct = value(1).map(|q| (q / QUALITY_SCALE * 100.0).min(100.0));
+    info.signal_dbm = value(2).map(|v| v as i32);
+    info.noise_dbm = value(3).map(|v| v as i32).filter(|&n| n != NOISE_UNKNOWN);
+}
+
+/// `iw dev <if> link`: `Not connected.` or the current association.
+fn apply_iw_link(text: &str, info: &mut WirelessInfo) {
+    for line in text.lines().map(str::trim) {
+        if let Some(rest) = line.strip_prefix("Connected to ") {
+            info.bssid = rest.split_whitespace().next().unwrap_or_default().to_string();
+        } else if let Some(ssid) = line.strip_prefix("SSID: ") {
+            info.ssid = ssid.to_string();
+        } else if let Some(freq) = line.strip_prefix("freq: ") {
+            // Older iw prints `5180`, newer `5180.0`.
+            info.frequency_mhz = leading_number(freq).map(|f| f as u32);
+        } else if let Some(signal) = line.strip_prefix("signal: ") {
+            info.signal_dbm = leading_number(signal).map(|s| s as i32);
+        } else if let Some(rate) = line.strip_prefix("rx bitrate: ") {
+            info.rx_bitrate_mbps = leading_number(rate);
+        } else if let Some(rate) = line.strip_prefix("tx bitrate: ") {
+            info.tx_bitrate_mbps = leading_number(rate);
+        }
+    }
+}
+
+/// `iw dev <if> info`: the operating channel and width, even when only
+/// part of the association shows in `link`.
+fn apply_iw_info(text: &str, info: &mut WirelessInfo) {
+    for line in text.lines().map(str::trim) {
+        if let Some(ssid) = line.strip_prefix("ssid ") {
+            if info.ssid.is_empty() {
+                info.ssid = ssid.to_string();
+            }
+        } else if let Some(index) = line.strip_prefix("wiphy ") {
+            if info.phy.is_empty() {
+                info.phy = format!("phy{}", index);
+            }
+        } else if let Some(rest) = line.strip_prefix("channel ") {
+            // channel 36 (5180 MHz), width: 80 MHz, center1: 5210 MHz
+            info.channel = leading_number(rest).map(|c| c as u32);
+            if let Some((_, freq)) = rest.split_once('(') {
+                info.frequency_mhz = info.frequency_mhz.or(leading_number(freq).map(|f| f as u32));
+            }
+            if let Some((_, width)) = rest.split_once("width: ") {
+                info.channel_width_mhz = leading_number(width).map(|w| w as u32);
+            }
+        }
+    }
+}
+
+/// `Band 1:` … headings of `iw phy <phy> info`, numbered from the nl80211
+/// band enum plus one.
+fn parse_iw_bands(text: &str) -> Vec<String> {
+    text.lines()
+        .filter_map(|line| line.trim().strip_prefix("Band ")?.strip_suffix(':')?.parse::<u32>().ok())
+        .filter_map(|band| match band {
+            1 => Some("2.4 GHz"),
+            2 => Some("5 GHz"),
+            3 => Some("60 GHz"),
+            4 => Some("6 GHz"),
+            _ => None,
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+/// `iw reg get` lists the `global` domain and, for self-managed radios, a
+/// `phy#0` block of their own; the radio's block wins.
+fn parse_iw_reg(text: &str, phy: &str) -> String {
+    let own_block = phy.strip_prefix("phy").map(|index| format!("phy#{}", index));
+    let mut block = "";
+    let mut global = String::new();
+    for line in text.lines() {
+        if let Some(rest) = line.strip_prefix("country ") {
+            let country = rest.split(':').next().unwrap_or_default().to_string();
+            if own_block.as_deref().is_some_and(|own| block.starts_with(own)) {
+                return country;
+            }
+            if block == "global" {
+                global = country;
+            }
+        } else if !line.is_empty() && !line.starts_with(char::is_whitespace) {
+            block = line;
+        }
+    }
+    global
+}
+
+fn band_name(freq: u32) -> &'static str {
+    match freq {
+        2400..=2500 => "2.4 GHz",
+        4900..=5925 => "5 GHz",
+        5926..=7125 => "6 GHz",
+        57000..=71000 => "60 GHz",
+        _ => "",
+    }
+}
+
+/// IEEE 802.11 channel numbering for each band.
+fn channel_number(freq: u32) -> Option<u32> {
+    match freq {
+        2484 => Some(14),
+        2412..=2472 => Some((freq - 2407) / 5),
+        // Channel 2 is the odd one out in 6 GHz.
+        5935 => Some(2),
+        5955..=7115 => Some((freq - 5950) / 5),
+        5000..=5925 => Some((freq - 5000) / 5),
+        4910..=4990 => Some((freq - 4000) / 5),
+        58320..=70200 => Some((freq - 56160) / 2160),
+        _ => None,
+    }
+}
+
+/// The number a value such as `-52 dBm` or `866.7 MBit/s VHT-MCS 9` starts
+/// with.
+fn leading_number(text: &str) -> Option<f64> {
+    text.split_whitespace().next()?.parse().ok()
+}
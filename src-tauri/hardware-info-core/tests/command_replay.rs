@@ -0,0 +1,68 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use std::io;
+use std::sync::Arc;
+
+use hardware_info_core::sysinfo::System;
+use hardware_info_core::{
+    collect_audio, collect_memory, CommandOutput, CommandRunner, ProbeContext, RecordingRunner, ReplayRunner,
+};
+
+#[test]
+fn aplay_cards_match_golden() {
+    let ctx = common::replay("workstation");
+    common::assert_golden("audio_aplay", &collect_audio(&ctx));
+}
+
+#[test]
+fn dmidecode_slots_match_golden() {
+    // The bundle has dmidecode failing without root and pkexec succeeding,
+    // so this also covers the fallback path.
+    let ctx = common::replay("workstation");
+    let memory = collect_memory(&ctx, &System::new());
+    common::assert_golden("memory_dmidecode", &memory.layout);
+}
+
+#[test]
+fn unrecorded_commands_look_missing() {
+    let ctx = common::replay("workstation");
+    let err = ctx.run("nvidia-smi", &[]).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+}
+
+#[derive(Debug)]
+struct FakeRunner;
+
+impl CommandRunner for FakeRunner {
+    fn run(&self, program: &str, args: &[&str]) -> io::Result<CommandOutput> {
+        match program {
+            "echo" => Ok(CommandOutput {
+                stdout: args.join(" "),
+                stderr: String::new(),
+                exit_code: Some(0),
+            }),
+            _ => Err(io::Error::new(io::ErrorKind::PermissionDenied, "nope")),
+        }
+    }
+}
+
+#[test]
+fn recorded_bundle_replays_identically() {
+    let recorder = Arc::new(RecordingRunner::new(Arc::new(FakeRunner)));
+    let ctx = ProbeContext::default().with_runner(recorder.clone());
+    let live_ok = ctx.run("echo", &["a", "b"]).unwrap();
+    let live_err = ctx.run("dmidecode", &["-t", "17"]).unwrap_err();
+
+    let path = std::env::temp_dir().join(format!("hwinfo-bundle-{}.json", std::process::id()));
+    recorder.bundle().save(&path).unwrap();
+    let bundle = hardware_info_core::CommandBundle::load(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+
+    let replay = ReplayRunner::new(bundle);
+    assert_eq!(replay.run("echo", &["a", "b"]).unwrap(), live_ok);
+    assert_eq!(replay.run("dmidecode", &["-t", "17"]).unwrap_err().kind(), live_err.kind());
+    // Arguments are part of the key.
+    assert_eq!(replay.run("echo", &["a"]).unwrap_err().kind(), io::ErrorKind::NotFound);
+}
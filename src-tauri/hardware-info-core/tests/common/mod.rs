@@ -0,0 +1,94 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use hardware_info_core::{CommandBundle, ProbeContext, ReplayRunner};
+use serde::Serialize;
+
+pub fn fixture_dir(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+/// A context whose sys/proc/dev roots point into `tests/fixtures/<name>`.
+pub fn fixture(name: &str) -> ProbeContext {
+    ProbeContext::from_root(materialize(name))
+}
+
+/// Fixtures store the `:` of sysfs names such as `0000:01:00.0` as `%3A`,
+/// so the repository can still be checked out on Windows. The tests
+/// themselves run only on Linux, and this copy relies on Unix symlinks:
+/// it puts `tests/fixtures/<name>` under the target directory with the real
+/// names (in link targets too), once per test binary.
+fn materialize(name: &str) -> PathBuf {
+    static TREES: OnceLock<Mutex<HashMap<String, PathBuf>>> = OnceLock::new();
+    let mut trees = TREES.get_or_init(Default::default).lock().unwrap();
+    trees
+        .entry(name.to_string())
+        .or_insert_with(|| {
+            // One copy per test binary, so binaries never share a tree.
+            let dest = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+                .join("fixtures")
+                .join(env!("CARGO_CRATE_NAME"))
+                .join(name);
+            let _ = std::fs::remove_dir_all(&dest);
+            copy_tree(&fixture_dir(name), &dest);
+            dest
+        })
+        .clone()
+}
+
+fn copy_tree(src: &Path, dest: &Path) {
+    std::fs::create_dir_all(dest).expect("create fixture dir");
+    for entry in std::fs::read_dir(src).expect("read fixture dir").flatten() {
+        let target = dest.join(entry.file_name().to_string_lossy().replace("%3A", ":"));
+        let kind = entry.file_type().expect("file type");
+        if kind.is_symlink() {
+            let link = std::fs::read_link(entry.path()).expect("read link");
+            let link = link.to_string_lossy().replace("%3A", ":");
+            std::os::unix::fs::symlink(link, &target).expect("copy link");
+        } else if kind.is_dir() {
+            copy_tree(&entry.path(), &target);
+        } else {
+            std::fs::copy(entry.path(), &target).expect("copy file");
+        }
+    }
+}
+
+/// Context whose external commands are answered from
+/// `tests/fixtures/commands/<name>.json`.
+pub fn replay(name: &str) -> ProbeContext {
+    let path = fixture_dir("commands").join(format!("{}.json", name));
+    let bundle = CommandBundle::load(&path).expect("command bundle");
+    ProbeContext::from_root(fixture_dir("does-not-exist")).with_runner(Arc::new(ReplayRunner::new(bundle)))
+}
+
+/// A fixture tree whose external commands are answered from
+/// `tests/fixtures/commands/<commands>.json`.
+pub fn fixture_with_commands(name: &str, commands: &str) -> ProbeContext {
+    let path = fixture_dir("commands").join(format!("{}.json", commands));
+    let bundle = CommandBundle::load(&path).expect("command bundle");
+    fixture(name).with_runner(Arc::new(ReplayRunner::new(bundle)))
+}
+
+/// Compares `actual` with `tests/golden/<name>.json`. Run with
+/// `UPDATE_GOLDEN=1` to rewrite the file after an intended change.
+pub fn assert_golden(name: &str, actual: &impl Serialize) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{}.json", name));
+    let actual = serde_json::to_value(actual).expect("serialize");
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        let text = serde_json::to_string_pretty(&actual).expect("serialize");
+        std::fs::write(&path, text + "\n").expect("write golden file");
+        return;
+    }
+
+    let text = std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("missing golden file {}", path.display()));
+    let expected: serde_json::Value = serde_json::from_str(&text).expect("golden file is JSON");
+    assert_eq!(actual, expected, "output differs from {}", path.display());
+}
@@ -0,0 +1,43 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::sysinfo::System;
+use hardware_info_core::{collect_cpu_info, CpuInfo};
+
+fn cpu_info(fixture: &str) -> CpuInfo {
+    collect_cpu_info(&common::fixture(fixture), &System::new())
+}
+
+#[test]
+fn intel_hybrid_types_come_from_the_pmus() {
+    let types = cpu_info("desktop").core_types;
+
+    let kinds: Vec<&str> = types.iter().map(|t| t.kind.as_str()).collect();
+    assert_eq!(kinds, ["performance", "efficiency"]);
+    assert_eq!((types[0].cores, types[0].cpus.len()), (8, 16));
+    assert_eq!((types[1].cores, types[1].cpus.as_slice()), (4, &[16, 17, 18, 19][..]));
+    assert_eq!((types[0].speed_min, types[0].speed_max), (0.8, 5.0));
+    assert_eq!(types[1].speed_max, 3.8);
+}
+
+#[test]
+fn arm_tiers_come_from_cpu_capacity() {
+    let types = cpu_info("arm").core_types;
+
+    let tiers: Vec<(&str, &[u32])> = types.iter().map(|t| (t.kind.as_str(), t.cpus.as_slice())).collect();
+    assert_eq!(tiers, [("performance", &[7][..]), ("mid", &[4, 5, 6][..]), ("efficiency", &[0, 1, 2, 3][..])]);
+    assert_eq!(types[1].cores, 3);
+    assert_eq!((types[0].speed_min, types[0].speed_max), (0.8448, 2.9952));
+}
+
+#[test]
+fn uniform_processors_have_no_core_types() {
+    assert!(cpu_info("hypervisor").core_types.is_empty());
+}
+
+#[test]
+fn speed_max_is_the_hardware_limit() {
+    assert_eq!(cpu_info("desktop").speed_max, 5.0);
+    assert_eq!(cpu_info("arm").speed_max, 2.9952);
+}
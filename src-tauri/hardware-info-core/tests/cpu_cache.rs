@@ -0,0 +1,47 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::sysinfo::System;
+use hardware_info_core::{collect_cpu_info, CpuCache};
+
+/// Eight SMT performance cores with private L1 and L2, four efficiency
+/// cores sharing one L2, and an L3 across all twenty CPUs.
+fn cache() -> CpuCache {
+    collect_cpu_info(&common::fixture("desktop"), &System::new()).cache
+}
+
+#[test]
+fn cache_matches_golden() {
+    common::assert_golden("cpu_cache", &cache());
+}
+
+#[test]
+fn shared_instances_are_listed_once() {
+    let cache = cache();
+
+    let l2: Vec<&[u32]> = cache.instances.iter().filter(|c| c.level == 2).map(|c| c.cpus.as_slice()).collect();
+    assert_eq!(l2.len(), 9);
+    assert_eq!((l2[0], l2[8]), (&[0, 1][..], &[16, 17, 18, 19][..]));
+    let l3 = cache.instances.last().unwrap();
+    assert_eq!((l3.level, l3.cpus.len(), l3.ways, l3.sets, l3.line_size), (3, 20, 12, 32768, 64));
+}
+
+#[test]
+fn totals_sum_each_level_and_type() {
+    let cache = cache();
+
+    let totals: Vec<(u32, &str, u64, usize)> =
+        cache.totals.iter().map(|t| (t.level, t.cache_type.as_str(), t.size / 1024, t.instances)).collect();
+    assert_eq!(
+        totals,
+        [(1, "Data", 512, 12), (1, "Instruction", 512, 12), (2, "Unified", 12288, 9), (3, "Unified", 24576, 1)]
+    );
+}
+
+#[test]
+fn cpu0_sizes_include_level_one() {
+    let cache = cache();
+    assert_eq!((cache.l1d, cache.l1i), (48 * 1024, 32 * 1024));
+    assert_eq!((cache.l2, cache.l3), (1280 * 1024, 24 * 1024 * 1024));
+}
@@ -0,0 +1,78 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::sysinfo::System;
+use hardware_info_core::{collect_cpu_info, CpuFeatures, ProbeContext};
+
+fn features(fixture: &str) -> CpuFeatures {
+    collect_cpu_info(&common::fixture(fixture), &System::new()).features
+}
+
+fn category<'a>(features: &'a CpuFeatures, name: &str) -> Vec<&'a str> {
+    let found = features.extensions.iter().find(|c| c.category == name);
+    found.map(|c| c.flags.iter().map(String::as_str).collect()).unwrap_or_default()
+}
+
+#[test]
+fn x86_extensions_are_grouped() {
+    let features = features("desktop");
+
+    assert_eq!(category(&features, "Cryptography"), ["aes", "vaes", "pclmulqdq", "vpclmulqdq", "sha_ni", "gfni"]);
+    assert!(category(&features, "SIMD").contains(&"avx_vnni"));
+    // Alder Lake ships with AVX-512 fused off.
+    assert!(category(&features, "AVX-512").is_empty());
+    assert_eq!((features.microcode.as_str(), features.virtualization.as_str()), ("0x2c", "vmx"));
+    assert!(!features.hypervisor);
+}
+
+#[test]
+fn arm_features_use_their_own_names() {
+    let features = features("arm");
+
+    assert_eq!(category(&features, "SIMD"), ["asimd", "asimdhp", "asimddp", "asimdrdm"]);
+    assert_eq!(category(&features, "Cryptography"), ["aes", "pmull", "sha1", "sha2"]);
+    assert_eq!((features.microcode.as_str(), features.virtualization.as_str()), ("", ""));
+}
+
+#[test]
+fn vulnerabilities_are_classified() {
+    let features = features("arm");
+
+    let statuses: Vec<(&str, &str)> =
+        features.vulnerabilities.iter().map(|v| (v.name.as_str(), v.status.as_str())).collect();
+    assert_eq!(
+        statuses,
+        [
+            ("meltdown", "not affected"),
+            ("retbleed", "unknown"),
+            ("spec_store_bypass", "vulnerable"),
+            ("spectre_v1", "mitigated"),
+            ("spectre_v2", "mitigated"),
+        ]
+    );
+    assert_eq!(features.vulnerabilities[4].detail, "Mitigation: CSV2, BHB");
+}
+
+#[test]
+fn subsystem_prefix_is_skipped() {
+    let features = features("desktop");
+    let itlb = features.vulnerabilities.iter().find(|v| v.name == "itlb_multihit").unwrap();
+    assert_eq!((itlb.status.as_str(), itlb.detail.as_str()), ("mitigated", "KVM: Mitigation: VMX disabled"));
+}
+
+#[test]
+fn missing_vulnerabilities_directory_is_not_an_error() {
+    let ctx = common::fixture("hypervisor");
+    assert!(collect_cpu_info(&ctx, &System::new()).features.vulnerabilities.is_empty());
+    let probes = ctx.take_diagnostics().probes;
+    assert!(probes.iter().find(|p| p.probe == "cpu.vulnerabilities").unwrap().error.is_none());
+}
+
+#[test]
+fn missing_cpuinfo_is_reported() {
+    let ctx = ProbeContext::from_root(common::fixture_dir("does-not-exist"));
+    collect_cpu_info(&ctx, &System::new());
+    let probes = ctx.take_diagnostics().probes;
+    assert!(probes.iter().find(|p| p.probe == "cpu.features").unwrap().error.is_some());
+}
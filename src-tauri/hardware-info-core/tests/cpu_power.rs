@@ -0,0 +1,99 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use std::time::Duration;
+
+use hardware_info_core::{collect_cpu_power, sample_cpu_power, CpuPower};
+
+/// RAPL counters from the desktop fixture, then from `power-later`, two
+/// seconds apart.
+fn power(elapsed: Duration) -> CpuPower {
+    let before = sample_cpu_power(&common::fixture("desktop"));
+    let after = sample_cpu_power(&common::fixture("power-later"));
+    collect_cpu_power(&before, &after, elapsed)
+}
+
+#[test]
+fn watts_come_from_energy_deltas() {
+    let power = power(Duration::from_secs(2));
+
+    let domains: Vec<(&str, &str, Option<f64>)> =
+        power.domains.iter().map(|d| (d.name.as_str(), d.zone.as_str(), d.watts)).collect();
+    assert_eq!(
+        domains[..2],
+        [("package-0", "intel-rapl:0", Some(45.0)), ("core", "intel-rapl:0:0", Some(30.0))]
+    );
+}
+
+#[test]
+fn wrapped_counters_are_unwound() {
+    let power = power(Duration::from_secs(2));
+
+    let uncore = &power.domains[2];
+    assert_eq!(uncore.name, "uncore");
+    assert_eq!(uncore.watts, Some(1_657_700.0 / 1_000_000.0 / 2.0));
+}
+
+#[test]
+fn package_limits_carry_windows() {
+    let power = power(Duration::from_secs(2));
+    let limits = &power.domains[0].limits;
+
+    let names: Vec<&str> = limits.iter().map(|l| l.name.as_str()).collect();
+    assert_eq!(names, ["long_term", "short_term", "peak_power"]);
+    assert_eq!((limits[0].watts, limits[0].time_window, limits[0].max_watts), (125.0, Some(27.983872), Some(241.0)));
+    assert_eq!((limits[1].watts, limits[1].time_window, limits[1].max_watts), (190.0, Some(0.00244), None));
+    assert_eq!((limits[2].watts, limits[2].time_window), (300.0, None));
+}
+
+#[test]
+fn no_elapsed_time_gives_no_watts() {
+    let power = power(Duration::ZERO);
+    assert!(power.domains.iter().all(|d| d.watts.is_none()));
+}
+
+#[test]
+fn amd_energy_channels_are_domains() {
+    let sample = sample_cpu_power(&common::fixture("epyc"));
+    let power = collect_cpu_power(&Default::default(), &sample, Duration::from_secs(1));
+
+    let domains: Vec<(&str, &str)> = power.domains.iter().map(|d| (d.name.as_str(), d.zone.as_str())).collect();
+    assert_eq!(
+        domains,
+        [("Esocket0", "hwmon0/energy1"), ("Ecore000", "hwmon0/energy2"), ("Ecore001", "hwmon0/energy3")]
+    );
+    // Nothing to compare the first sample against.
+    assert!(power.domains.iter().all(|d| d.watts.is_none() && d.limits.is_empty()));
+}
+
+#[test]
+fn reset_counters_without_a_range_give_no_watts() {
+    let before = sample_cpu_power(&common::fixture("epyc"));
+    let after = sample_cpu_power(&common::fixture("epyc-later"));
+    let power = collect_cpu_power(&before, &after, Duration::from_secs(1));
+
+    // amd_energy counters do not wrap, so going backwards means a reset.
+    let watts: Vec<Option<f64>> = power.domains.iter().map(|d| d.watts).collect();
+    assert_eq!(watts, [None, Some(2.0), Some(1.0)]);
+}
+
+#[test]
+fn mmio_zones_do_not_repeat_the_package() {
+    let power = power(Duration::from_secs(2));
+
+    let packages = power.domains.iter().filter(|d| d.name == "package-0").count();
+    assert_eq!(packages, 1);
+}
+
+#[test]
+fn unreadable_counters_are_reported_but_limits_kept() {
+    let ctx = common::fixture("power-unreadable");
+    let sample = sample_cpu_power(&ctx);
+    let power = collect_cpu_power(&sample, &sample, Duration::from_secs(1));
+
+    assert_eq!(power.domains[0].watts, None);
+    assert_eq!(power.domains[0].limits[0].watts, 15.0);
+    let report = ctx.take_diagnostics().probes.into_iter().find(|p| p.probe == "cpu.power").unwrap();
+    assert!(report.error.is_some());
+}
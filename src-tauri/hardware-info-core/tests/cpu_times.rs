@@ -0,0 +1,39 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::{collect_cpu_times, sample_cpu_times, CpuTimes, ProbeContext};
+
+/// Counters from the desktop fixture, then from `stat-later`.
+fn cpu_times() -> CpuTimes {
+    let ctx = common::fixture("desktop");
+    let before = sample_cpu_times(&ctx);
+    let mut later = common::fixture("desktop");
+    later.procfs_root = common::fixture_dir("stat-later");
+    collect_cpu_times(&before, &sample_cpu_times(&later))
+}
+
+#[test]
+fn breakdown_comes_from_counter_deltas() {
+    let times = cpu_times();
+
+    assert_eq!((times.user, times.nice, times.system, times.idle), (30.0, 0.0, 10.0, 50.0));
+    assert_eq!((times.iowait, times.irq, times.softirq), (5.0, 0.0, 2.5));
+    assert_eq!(times.steal, 2.5);
+}
+
+#[test]
+fn no_elapsed_time_gives_zeros() {
+    let ctx = common::fixture("desktop");
+    let sample = sample_cpu_times(&ctx);
+    let times = collect_cpu_times(&sample, &sample);
+    assert_eq!((times.user, times.idle, times.steal), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn missing_stat_is_reported() {
+    let ctx = ProbeContext::from_root(common::fixture_dir("does-not-exist"));
+    sample_cpu_times(&ctx);
+    let report = ctx.take_diagnostics().probes.into_iter().find(|p| p.probe == "cpu.times").unwrap();
+    assert!(report.error.is_some());
+}
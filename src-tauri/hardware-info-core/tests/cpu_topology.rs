@@ -0,0 +1,51 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::sysinfo::System;
+use hardware_info_core::{collect_cpu_info, CpuTopology};
+
+/// Two packages of two SMT cores each, on two NUMA nodes, with cpu7
+/// offline.
+fn topology() -> CpuTopology {
+    let ctx = common::fixture("hypervisor");
+    collect_cpu_info(&ctx, &System::new()).topology
+}
+
+#[test]
+fn topology_matches_golden() {
+    common::assert_golden("cpu_topology", &topology());
+}
+
+#[test]
+fn smt_siblings_share_a_core() {
+    let topology = topology();
+
+    let packages: Vec<u32> = topology.packages.iter().map(|p| p.id).collect();
+    assert_eq!(packages, [0, 1]);
+    let first = &topology.packages[0].dies[0];
+    let cores: Vec<(u32, &[u32])> =
+        first.clusters.iter().flat_map(|c| &c.cores).map(|c| (c.id, c.threads.as_slice())).collect();
+    assert_eq!(cores, [(0, &[0, 4][..]), (1, &[1, 5][..])]);
+}
+
+#[test]
+fn offline_cpus_are_listed_but_not_placed() {
+    let topology = topology();
+
+    let offline: Vec<u32> = topology.cpus.iter().filter(|c| !c.online).map(|c| c.cpu).collect();
+    assert_eq!(offline, [7]);
+    assert_eq!(topology.cpus[7].numa_node, Some(1));
+    let last_core = &topology.packages[1].dies[0].clusters[1].cores[0];
+    assert_eq!((last_core.id, last_core.threads.as_slice()), (1, &[3][..]));
+}
+
+#[test]
+fn numa_nodes_have_cpus_memory_and_distances() {
+    let nodes = topology().numa_nodes;
+
+    assert_eq!(nodes.len(), 2);
+    assert_eq!(nodes[0].cpus, [0, 1, 4, 5]);
+    assert_eq!(nodes[1].distances, [21, 10]);
+    assert_eq!(nodes[1].memory_total, 64 * 1024 * 1024 * 1024);
+}
@@ -0,0 +1,58 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::sysinfo::System;
+use hardware_info_core::{collect_cpu_info, CpuInfo};
+
+fn cpu_info(fixture: &str) -> CpuInfo {
+    collect_cpu_info(&common::fixture(fixture), &System::new())
+}
+
+#[test]
+fn intel_pstate_policies_carry_limits_and_epp() {
+    let scaling = cpu_info("desktop").frequency_scaling;
+
+    assert_eq!((scaling.driver.as_str(), scaling.boost), ("intel_pstate", Some(true)));
+    assert_eq!(scaling.policies.len(), 20);
+    let first = &scaling.policies[0];
+    assert_eq!((first.cpus.as_slice(), first.governor.as_str()), (&[0][..], "powersave"));
+    assert_eq!(first.available_governors, ["performance", "powersave"]);
+    assert_eq!((first.hardware_min, first.hardware_max, first.base), (0.8, 4.9, 3.6));
+    assert_eq!(first.energy_performance_preference, "balance_performance");
+    assert_eq!(first.available_preferences.len(), 5);
+}
+
+#[test]
+fn policies_sort_numerically_and_show_caps() {
+    let scaling = cpu_info("desktop").frequency_scaling;
+
+    let capped = scaling.policies.last().unwrap();
+    assert_eq!(capped.policy, 19);
+    assert_eq!((capped.hardware_max, capped.scaling_max), (3.8, 3.0));
+}
+
+#[test]
+fn base_and_boost_clocks_come_from_cpufreq() {
+    let cpu = cpu_info("desktop");
+    assert_eq!((cpu.speed, cpu.speed_max), (3.6, 5.0));
+}
+
+#[test]
+fn cluster_policies_cover_several_cpus() {
+    let scaling = cpu_info("arm").frequency_scaling;
+
+    assert_eq!((scaling.driver.as_str(), scaling.boost), ("cpufreq-dt", None));
+    let cpus: Vec<&[u32]> = scaling.policies.iter().map(|p| p.cpus.as_slice()).collect();
+    assert_eq!(cpus, [&[0, 1, 2, 3][..], &[4, 5, 6][..], &[7][..]]);
+    assert_eq!(scaling.policies[1].current, 2.016);
+    assert!(scaling.policies[0].energy_performance_preference.is_empty());
+}
+
+#[test]
+fn no_cpufreq_is_not_an_error() {
+    let ctx = common::fixture("hypervisor");
+    assert!(collect_cpu_info(&ctx, &System::new()).frequency_scaling.policies.is_empty());
+    let probes = ctx.take_diagnostics().probes;
+    assert!(probes.iter().find(|p| p.probe == "cpu.frequency").unwrap().error.is_none());
+}
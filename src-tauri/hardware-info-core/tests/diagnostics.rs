@@ -0,0 +1,91 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::sysinfo::System;
+use hardware_info_core::{
+    collect_audio, collect_live_info_with, collect_memory, collect_optical, collect_static_data, ProbeContext,
+    ProbeError, ProbeReport,
+};
+
+fn report(ctx: &ProbeContext, name: &str) -> ProbeReport {
+    ctx.take_diagnostics()
+        .probes
+        .into_iter()
+        .find(|p| p.probe == name)
+        .unwrap_or_else(|| panic!("no report for {}", name))
+}
+
+#[test]
+fn pkexec_fallback_counts_as_success() {
+    let ctx = common::replay("workstation");
+    collect_memory(&ctx, &System::new());
+    let layout = report(&ctx, "memory.layout");
+    assert_eq!(layout.source, "dmidecode -t 17");
+    assert!(layout.error.is_none(), "{:?}", layout.error);
+}
+
+#[test]
+fn missing_tool_is_reported() {
+    let ctx = common::replay("empty");
+    collect_audio(&ctx);
+    let audio = report(&ctx, "audio");
+    assert!(matches!(&audio.error, Some(ProbeError::MissingTool { tool }) if tool == "aplay"));
+}
+
+#[test]
+fn root_denied_dmidecode_is_permission_denied() {
+    // Neither dmidecode nor pkexec works: the reason given is dmidecode's.
+    let ctx = common::replay("unprivileged");
+    collect_memory(&ctx, &System::new());
+    let layout = report(&ctx, "memory.layout");
+    assert!(matches!(&layout.error, Some(ProbeError::PermissionDenied { path }) if path == "dmidecode"));
+}
+
+#[test]
+fn missing_sysfs_is_not_found() {
+    let ctx = common::replay("empty");
+    collect_static_data(&ctx, &System::new());
+    let bios = report(&ctx, "bios");
+    assert!(matches!(&bios.error, Some(ProbeError::NotFound { path }) if path.ends_with("class/dmi/id")));
+}
+
+#[test]
+fn absent_optional_files_are_not_errors() {
+    // No cdrom driver loaded means no drive, not a failed probe.
+    let ctx = common::replay("empty");
+    let optical = collect_optical(&ctx);
+    assert!(optical.devices.is_empty());
+    assert!(report(&ctx, "optical").error.is_none());
+}
+
+#[test]
+fn diagnostics_are_drained() {
+    let ctx = common::fixture("desktop");
+    collect_optical(&ctx);
+    assert_eq!(ctx.take_diagnostics().probes.len(), 1);
+    assert!(ctx.take_diagnostics().probes.is_empty());
+}
+
+#[test]
+fn errors_serialize_with_a_kind_tag() {
+    let err = ProbeError::CommandFailed {
+        tool: "lspci".to_string(),
+        exit_code: Some(1),
+        stderr: "pcilib: Cannot open /proc/bus/pci".to_string(),
+    };
+    let value = serde_json::to_value(&err).unwrap();
+    assert_eq!(value["kind"], "commandFailed");
+    assert_eq!(value["exitCode"], 1);
+    assert_eq!(err.to_string(), "lspci exited with status 1: pcilib: Cannot open /proc/bus/pci");
+}
+
+#[test]
+fn sampled_probes_are_reported_once() {
+    let ctx = common::fixture("desktop");
+    collect_live_info_with(&ctx);
+    let probes = ctx.take_diagnostics().probes;
+    for name in ["cpu.times", "cpu.power", "storage.io", "network.io"] {
+        assert_eq!(probes.iter().filter(|p| p.probe == name).count(), 1, "{}", name);
+    }
+}
@@ -0,0 +1,53 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use std::time::Duration;
+
+use hardware_info_core::{collect_disk_io, sample_disk_stats, DiskIo};
+
+/// Counters from the desktop fixture, then from `diskstats-later`, two
+/// seconds apart.
+fn disk_io(elapsed: Duration) -> Vec<DiskIo> {
+    let ctx = common::fixture("desktop");
+    let before = sample_disk_stats(&ctx);
+    let mut later = common::fixture("desktop");
+    later.procfs_root = common::fixture_dir("diskstats-later");
+    let after = sample_disk_stats(&later);
+    collect_disk_io(&before, &after, elapsed)
+}
+
+#[test]
+fn rates_come_from_counter_deltas() {
+    let io = disk_io(Duration::from_secs(2));
+    let nvme = io.iter().find(|d| d.name == "nvme0n1").unwrap();
+
+    assert_eq!(nvme.read_bytes_per_sec, 100.0 * 1024.0 * 1024.0);
+    assert_eq!(nvme.write_bytes_per_sec, 5.0 * 1024.0 * 1024.0);
+    assert_eq!((nvme.read_iops, nvme.write_iops), (1000.0, 250.0));
+    assert_eq!((nvme.read_latency_ms, nvme.write_latency_ms), (0.5, 3.0));
+    assert_eq!(nvme.queue_depth, 1.5);
+    assert_eq!(nvme.utilization_pct, 50.0);
+
+    let sda = io.iter().find(|d| d.name == "sda").unwrap();
+    assert_eq!((sda.read_iops, sda.utilization_pct), (0.0, 0.0));
+}
+
+#[test]
+fn only_physical_disks_are_sampled() {
+    let names: Vec<String> = disk_io(Duration::from_secs(2)).into_iter().map(|d| d.name).collect();
+    assert_eq!(names, ["nvme0n1", "sda", "sdb"]);
+}
+
+#[test]
+fn counters_that_restarted_read_as_idle() {
+    // sdb was unplugged and reattached between the samples.
+    let io = disk_io(Duration::from_secs(2));
+    let sdb = io.iter().find(|d| d.name == "sdb").unwrap();
+    assert_eq!((sdb.read_bytes_per_sec, sdb.read_iops, sdb.utilization_pct), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn zero_interval_gives_no_rates() {
+    assert!(disk_io(Duration::ZERO).is_empty());
+}
@@ -0,0 +1,207 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::{collect_network, NetworkInterface, ProbeError};
+
+fn interface<'a>(interfaces: &'a [NetworkInterface], name: &str) -> &'a NetworkInterface {
+    interfaces.iter().find(|i| i.iface == name).unwrap()
+}
+
+#[test]
+fn interfaces_match_golden() {
+    let ctx = common::fixture_with_commands("desktop", "desktop");
+    common::assert_golden("network_interfaces", &collect_network(&ctx).interfaces);
+}
+
+#[test]
+fn every_address_keeps_its_prefix() {
+    let ctx = common::fixture_with_commands("desktop", "desktop");
+    let interfaces = collect_network(&ctx).interfaces;
+    let wired = interface(&interfaces, "enp5s0");
+
+    let ip4: Vec<(&str, u8)> = wired.ip4.iter().map(|a| (a.address.as_str(), a.prefix_len)).collect();
+    assert_eq!(ip4, [("192.168.1.20", 24), ("10.10.0.5", 16)]);
+    let ip6: Vec<(&str, &str)> = wired.ip6.iter().map(|a| (a.address.as_str(), a.scope.as_str())).collect();
+    assert_eq!(ip6, [("2001:db8:1:2::20", "global"), ("fe80::642:1aff:fe0b:cdef", "link")]);
+}
+
+#[test]
+fn kind_and_parent_come_from_sysfs() {
+    let ctx = common::fixture_with_commands("desktop", "desktop");
+    let interfaces = collect_network(&ctx).interfaces;
+    let kinds: Vec<(&str, &str)> = interfaces.iter().map(|i| (i.iface.as_str(), i.kind.as_str())).collect();
+    assert_eq!(
+        kinds,
+        [("enp5s0", "physical"), ("lo", "loopback"), ("tun0", "tun"), ("wlp4s0", "wireless")]
+    );
+
+    let wired = interface(&interfaces, "enp5s0");
+    assert_eq!((wired.driver.as_str(), wired.parent_bus.as_str()), ("igc", "pci"));
+    assert_eq!(wired.parent_device, "0000:05:00.0");
+    assert_eq!((wired.speed_mbps, wired.duplex.as_str(), wired.carrier), (Some(2500), "full", Some(true)));
+
+    // Wireless drivers report no speed or duplex in sysfs.
+    let wireless = interface(&interfaces, "wlp4s0");
+    assert_eq!((wireless.speed_mbps, wireless.duplex.as_str()), (None, ""));
+}
+
+#[test]
+fn missing_ip_tool_leaves_link_details() {
+    let ctx = common::fixture_with_commands("desktop", "empty");
+    let interfaces = collect_network(&ctx).interfaces;
+
+    // sysinfo is not consulted for a capture, so no addresses either.
+    let wired = interface(&interfaces, "enp5s0");
+    assert!(wired.ip4.is_empty());
+    assert_eq!((wired.mac.as_str(), wired.mtu), ("04:42:1a:0b:cd:ef", 1500));
+
+    let probes = ctx.take_diagnostics().probes;
+    let report = |name: &str| probes.iter().find(|p| p.probe == name).unwrap().error.clone();
+    assert!(matches!(report("network.links"), Some(ProbeError::MissingTool { .. })));
+    assert!(report("network.addresses").is_none());
+}
+
+#[test]
+fn wireless_association_comes_from_iw() {
+    let ctx = common::fixture_with_commands("desktop", "desktop");
+    let interfaces = collect_network(&ctx).interfaces;
+    assert!(interface(&interfaces, "enp5s0").wireless.is_none());
+
+    let wifi = interface(&interfaces, "wlp4s0").wireless.as_ref().unwrap();
+    assert_eq!((wifi.ssid.as_str(), wifi.bssid.as_str()), ("Home Network", "3c:84:6a:a1:b2:c3"));
+    assert_eq!((wifi.frequency_mhz, wifi.channel, wifi.band.as_str()), (Some(5180), Some(36), "5 GHz"));
+    assert_eq!(wifi.channel_width_mhz, Some(80));
+    assert_eq!((wifi.tx_bitrate_mbps, wifi.rx_bitrate_mbps), (Some(780.0), Some(866.7)));
+    assert_eq!(wifi.supported_bands, ["2.4 GHz", "5 GHz", "6 GHz"]);
+    assert_eq!(wifi.regulatory_domain, "DE");
+}
+
+#[test]
+fn wireless_without_iw_keeps_proc_figures() {
+    let ctx = common::fixture_with_commands("desktop", "empty");
+    let interfaces = collect_network(&ctx).interfaces;
+    let wifi = interface(&interfaces, "wlp4s0").wireless.as_ref().unwrap();
+
+    assert_eq!((wifi.phy.as_str(), wifi.ssid.as_str()), ("phy0", ""));
+    assert_eq!((wifi.signal_dbm, wifi.noise_dbm), (Some(-54), None));
+    assert_eq!(wifi.link_quality_pct, Some(80.0));
+
+    let report = ctx.take_diagnostics().probes.into_iter().find(|p| p.probe == "network.wireless").unwrap();
+    assert!(matches!(report.error, Some(ProbeError::NotFound { path }) if path == "iw"));
+}
+
+#[test]
+fn routes_match_golden() {
+    let ctx = common::fixture_with_commands("desktop", "desktop");
+    common::assert_golden("network_routes", &collect_network(&ctx).routes);
+}
+
+#[test]
+fn default_gateway_is_the_lowest_metric() {
+    let ctx = common::fixture_with_commands("desktop", "desktop");
+    let network = collect_network(&ctx);
+
+    let v4 = network.ipv4_gateway.unwrap();
+    assert_eq!((v4.gateway.as_str(), v4.iface.as_str(), v4.metric), ("192.168.1.1", "enp5s0", 100));
+    let v6 = network.ipv6_gateway.unwrap();
+    assert_eq!((v6.gateway.as_str(), v6.iface.as_str()), ("fe80::1", "enp5s0"));
+}
+
+#[test]
+fn ipv6_local_routes_are_told_apart() {
+    let ctx = common::fixture_with_commands("desktop", "desktop");
+    let routes = collect_network(&ctx).routes;
+    let table = |destination: &str| routes.iter().find(|r| r.destination == destination).map(|r| r.table.as_str());
+
+    assert_eq!(table("2001:db8:1:2::/64"), Some("main"));
+    assert_eq!(table("2001:db8:1:2::20/128"), Some("local"));
+    assert_eq!(table("ff00::/8"), Some("local"));
+    // The kernel's unreachable placeholder is not listed.
+    assert_eq!(routes.iter().filter(|r| r.destination == "::/0").count(), 1);
+}
+
+#[test]
+fn dns_behind_resolved_reads_the_upstream_servers() {
+    let ctx = common::fixture_with_commands("desktop", "desktop");
+    let dns = collect_network(&ctx).dns;
+
+    assert_eq!(dns.servers, ["192.168.1.1", "2001:db8:1:2::1"]);
+    assert_eq!(dns.search_domains, ["home.arpa"]);
+    assert_eq!(dns.stub_resolver, "systemd-resolved");
+    assert!(dns.source.ends_with("run/systemd/resolve/resolv.conf"));
+}
+
+#[test]
+fn dns_without_a_stub_is_read_directly() {
+    let mut ctx = common::fixture_with_commands("desktop", "desktop");
+    ctx.etc_root = common::fixture_dir("resolv-direct");
+    let dns = collect_network(&ctx).dns;
+
+    assert_eq!(dns.servers, ["10.10.0.53", "10.10.0.54"]);
+    // The later search line replaces the domain line.
+    assert_eq!(dns.search_domains, ["corp.example", "lab.example"]);
+    assert_eq!(dns.stub_resolver, "");
+}
+
+#[test]
+fn topology_matches_golden() {
+    let ctx = common::fixture_with_commands("hypervisor", "hypervisor");
+    common::assert_golden("network_topology", &collect_network(&ctx).interfaces);
+}
+
+#[test]
+fn bond_lists_slaves_mode_and_active_slave() {
+    let ctx = common::fixture_with_commands("hypervisor", "hypervisor");
+    let interfaces = collect_network(&ctx).interfaces;
+    let bond = interface(&interfaces, "bond0");
+
+    assert_eq!(bond.kind, "bond");
+    assert_eq!(bond.ports, ["eno1", "eno2"]);
+    let info = bond.bond.as_ref().unwrap();
+    assert_eq!((info.mode.as_str(), info.active_slave.as_str()), ("active-backup", "eno1"));
+    assert_eq!(interface(&interfaces, "eno2").master, "bond0");
+}
+
+#[test]
+fn vlan_on_bond_is_a_bridge_port() {
+    let ctx = common::fixture_with_commands("hypervisor", "hypervisor");
+    let interfaces = collect_network(&ctx).interfaces;
+
+    let vlan = interface(&interfaces, "bond0.100");
+    let info = vlan.vlan.as_ref().unwrap();
+    assert_eq!((info.id, info.parent.as_str()), (100, "bond0"));
+    assert_eq!(vlan.master, "br100");
+    assert_eq!(interface(&interfaces, "br100").ports, ["bond0.100", "veth7f3a9c1"]);
+}
+
+#[test]
+fn vlan_without_root_comes_from_ip() {
+    // No /proc/net/vlan/config, which only root may read.
+    let ctx = common::fixture_with_commands("hypervisor-unprivileged", "hypervisor");
+    let interfaces = collect_network(&ctx).interfaces;
+    let info = interface(&interfaces, "bond0.100").vlan.clone().unwrap();
+    assert_eq!((info.id, info.parent.as_str()), (100, "bond0"));
+
+    // Without ip either, only the parent is known.
+    let ctx = common::fixture_with_commands("hypervisor-unprivileged", "empty");
+    let interfaces = collect_network(&ctx).interfaces;
+    let info = interface(&interfaces, "bond0.100").vlan.clone().unwrap();
+    assert_eq!((info.id, info.parent.as_str()), (0, "bond0"));
+}
+
+#[test]
+fn veth_peers_are_named_here_or_by_namespace() {
+    let ctx = common::fixture_with_commands("hypervisor", "hypervisor");
+    let network = collect_network(&ctx);
+
+    let local = interface(&network.interfaces, "veth-a").veth_peer.as_ref().unwrap();
+    assert_eq!((local.name.as_str(), local.netns_id), ("veth-b", None));
+
+    let container = interface(&network.interfaces, "veth7f3a9c1").veth_peer.as_ref().unwrap();
+    assert_eq!((container.name.as_str(), container.ifindex), ("", 2));
+    assert_eq!((container.netns_id, container.netns.as_str()), (Some(0), "web"));
+
+    let names: Vec<&str> = network.namespaces.iter().map(|ns| ns.name.as_str()).collect();
+    assert_eq!(names, ["web", "scratch"]);
+}
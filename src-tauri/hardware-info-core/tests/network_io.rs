@@ -0,0 +1,53 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use std::time::Duration;
+
+use hardware_info_core::{collect_network_io, sample_network_stats, NetworkIo};
+
+/// Counters from the desktop fixture, then from `netdev-later`, two
+/// seconds apart.
+fn network_io(elapsed: Duration) -> Vec<NetworkIo> {
+    let ctx = common::fixture("desktop");
+    let before = sample_network_stats(&ctx);
+    let mut later = common::fixture("desktop");
+    later.procfs_root = common::fixture_dir("netdev-later");
+    let after = sample_network_stats(&later);
+    collect_network_io(&before, &after, elapsed)
+}
+
+#[test]
+fn rates_come_from_counter_deltas() {
+    let io = network_io(Duration::from_secs(2));
+    let wired = io.iter().find(|i| i.iface == "enp5s0").unwrap();
+
+    assert_eq!(wired.rx_bytes_per_sec, 24.0 * 1024.0 * 1024.0);
+    assert_eq!(wired.tx_bytes_per_sec, 1024.0 * 1024.0);
+    assert_eq!((wired.rx_packets_per_sec, wired.tx_packets_per_sec), (18000.0, 6000.0));
+    assert_eq!((wired.rx_errors, wired.tx_errors), (0, 1));
+    assert_eq!((wired.rx_dropped, wired.tx_dropped), (6, 0));
+}
+
+#[test]
+fn totals_are_the_later_counters() {
+    let io = network_io(Duration::from_secs(2));
+    let wired = io.iter().find(|i| i.iface == "enp5s0").unwrap();
+
+    assert_eq!((wired.rx_bytes_total, wired.tx_bytes_total), (9926874858, 1236665042));
+    assert_eq!((wired.rx_dropped_total, wired.tx_errors_total), (20, 1));
+}
+
+#[test]
+fn recreated_interface_reads_as_idle() {
+    // The VPN reconnected between the samples, so tun0 starts from zero.
+    let io = network_io(Duration::from_secs(2));
+    let tun = io.iter().find(|i| i.iface == "tun0").unwrap();
+    assert_eq!((tun.rx_bytes_per_sec, tun.tx_packets_per_sec), (0.0, 0.0));
+    assert_eq!(tun.rx_bytes_total, 400);
+}
+
+#[test]
+fn zero_interval_gives_no_rates() {
+    assert!(network_io(Duration::ZERO).is_empty());
+}
@@ -0,0 +1,359 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use hardware_info_core::{collect_storage, collect_storage_with, read_partition_table, ProbeError, StorageOptions};
+
+const EFI_SYSTEM: &str = "c12a7328-f81f-11d2-ba4b-00a0c93ec93b";
+const LINUX_FS: &str = "0fc63daf-8483-4772-8e79-3d69d8477de4";
+const BASIC_DATA: &str = "ebd0a0a2-b9e5-4433-87c0-68b6b72699c7";
+
+struct GptEntry {
+    type_guid: &'static str,
+    uuid: &'static str,
+    first: u64,
+    last: u64,
+    attributes: u64,
+    name: &'static str,
+}
+
+struct MbrEntry {
+    boot: bool,
+    system_id: u8,
+    start: u32,
+    sectors: u32,
+}
+
+fn image_path(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("images");
+    std::fs::create_dir_all(&dir).unwrap();
+    dir.join(name)
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in bytes {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+/// Mixed-endian on-disk layout of a textual GUID.
+fn guid_bytes(guid: &str) -> [u8; 16] {
+    let digits = guid.replace('-', "");
+    let hex: Vec<u8> = (0..16)
+        .map(|i| u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).unwrap())
+        .collect();
+    let mut out = [0u8; 16];
+    out[0..4].copy_from_slice(&[hex[3], hex[2], hex[1], hex[0]]);
+    out[4..6].copy_from_slice(&[hex[5], hex[4]]);
+    out[6..8].copy_from_slice(&[hex[7], hex[6]]);
+    out[8..16].copy_from_slice(&hex[8..16]);
+    out
+}
+
+fn mbr_sector(signature: u32, entries: &[MbrEntry]) -> Vec<u8> {
+    let mut sector = vec![0u8; 512];
+    sector[440..444].copy_from_slice(&signature.to_le_bytes());
+    for (i, e) in entries.iter().enumerate() {
+        let at = 446 + i * 16;
+        sector[at] = if e.boot { 0x80 } else { 0 };
+        sector[at + 4] = e.system_id;
+        sector[at + 8..at + 12].copy_from_slice(&e.start.to_le_bytes());
+        sector[at + 12..at + 16].copy_from_slice(&e.sectors.to_le_bytes());
+    }
+    sector[510] = 0x55;
+    sector[511] = 0xAA;
+    sector
+}
+
+/// A 512-byte-sector GPT image: protective MBR, header at LBA 1 and 128
+/// entries from LBA 2.
+fn gpt_image(path: &Path, disk_guid: &str, entries: &[GptEntry]) {
+    let mut image = mbr_sector(0, &[MbrEntry { boot: false, system_id: 0xEE, start: 1, sectors: u32::MAX }]);
+    image.resize(34 * 512, 0);
+
+    let mut table = vec![0u8; 128 * 128];
+    for (i, e) in entries.iter().enumerate() {
+        let entry = &mut table[i * 128..(i + 1) * 128];
+        entry[0..16].copy_from_slice(&guid_bytes(e.type_guid));
+        entry[16..32].copy_from_slice(&guid_bytes(e.uuid));
+        entry[32..40].copy_from_slice(&e.first.to_le_bytes());
+        entry[40..48].copy_from_slice(&e.last.to_le_bytes());
+        entry[48..56].copy_from_slice(&e.attributes.to_le_bytes());
+        for (j, unit) in e.name.encode_utf16().enumerate() {
+            entry[56 + j * 2..58 + j * 2].copy_from_slice(&unit.to_le_bytes());
+        }
+    }
+
+    let header = &mut image[512..604];
+    header[0..8].copy_from_slice(b"EFI PART");
+    header[8..12].copy_from_slice(&0x0001_0000u32.to_le_bytes());
+    header[12..16].copy_from_slice(&92u32.to_le_bytes());
+    header[24..32].copy_from_slice(&1u64.to_le_bytes());
+    header[40..48].copy_from_slice(&34u64.to_le_bytes());
+    header[56..72].copy_from_slice(&guid_bytes(disk_guid));
+    header[72..80].copy_from_slice(&2u64.to_le_bytes());
+    header[80..84].copy_from_slice(&128u32.to_le_bytes());
+    header[84..88].copy_from_slice(&128u32.to_le_bytes());
+    header[88..92].copy_from_slice(&crc32(&table).to_le_bytes());
+    let crc = crc32(header);
+    header[16..20].copy_from_slice(&crc.to_le_bytes());
+
+    image[1024..1024 + table.len()].copy_from_slice(&table);
+    std::fs::write(path, image).unwrap();
+}
+
+fn desktop_gpt(path: &Path) {
+    gpt_image(
+        path,
+        "8f3b2a1c-5d4e-4f60-9a7b-0c1d2e3f4a5b",
+        &[
+            GptEntry {
+                type_guid: EFI_SYSTEM,
+                uuid: "0b5c6d7e-8f90-4a1b-8c2d-3e4f5a6b7c8d",
+                first: 2048,
+                last: 1050623,
+                attributes: 1,
+                name: "EFI system partition",
+            },
+            GptEntry {
+                type_guid: LINUX_FS,
+                uuid: "1c2d3e4f-5a6b-4c7d-8e9f-0a1b2c3d4e5f",
+                first: 1050624,
+                last: 1953523711,
+                attributes: 0,
+                name: "root",
+            },
+        ],
+    );
+}
+
+#[test]
+fn gpt_entries_are_decoded() {
+    let path = image_path("gpt.img");
+    desktop_gpt(&path);
+    let table = read_partition_table(&path, 512).unwrap().unwrap();
+
+    assert_eq!(table.scheme, "GPT");
+    assert_eq!(table.disk_id, "8f3b2a1c-5d4e-4f60-9a7b-0c1d2e3f4a5b");
+    assert_eq!(table.partitions.len(), 2);
+
+    let esp = &table.partitions[0];
+    assert_eq!((esp.number, esp.start_lba, esp.end_lba), (1, 2048, 1050623));
+    assert_eq!(esp.size, 512 * 1024 * 1024);
+    assert_eq!(esp.type_id, EFI_SYSTEM);
+    assert_eq!(esp.type_name, "EFI System");
+    assert_eq!(esp.label, "EFI system partition");
+    assert_eq!(esp.uuid, "0b5c6d7e-8f90-4a1b-8c2d-3e4f5a6b7c8d");
+    assert_eq!(table.partitions[1].type_name, "Linux filesystem");
+}
+
+#[test]
+fn gpt_attribute_bits_become_flags() {
+    let path = image_path("gpt-flags.img");
+    gpt_image(
+        &path,
+        "8f3b2a1c-5d4e-4f60-9a7b-0c1d2e3f4a5b",
+        &[GptEntry {
+            type_guid: BASIC_DATA,
+            uuid: "2d3e4f5a-6b7c-4d8e-9f0a-1b2c3d4e5f6a",
+            first: 2048,
+            last: 4095,
+            attributes: 1 << 2 | 1 << 62 | 1 << 63,
+            name: "Recovery",
+        }],
+    );
+    let table = read_partition_table(&path, 512).unwrap().unwrap();
+
+    assert_eq!(table.partitions[0].type_name, "Microsoft basic data");
+    assert_eq!(table.partitions[0].flags, ["legacy-boot", "hidden", "no-automount"]);
+}
+
+#[test]
+fn damaged_gpt_header_is_a_parse_failure() {
+    let path = image_path("gpt-damaged.img");
+    desktop_gpt(&path);
+    let mut image = std::fs::read(&path).unwrap();
+    image[512 + 40] ^= 0xFF;
+    std::fs::write(&path, image).unwrap();
+
+    match read_partition_table(&path, 512) {
+        Err(ProbeError::ParseFailure { message, .. }) => assert_eq!(message, "GPT header checksum mismatch"),
+        other => panic!("expected a parse failure, got {:?}", other),
+    }
+}
+
+#[test]
+fn oversized_gpt_entries_are_rejected() {
+    let path = image_path("gpt-entry-size.img");
+    desktop_gpt(&path);
+    patch_gpt_header(&path, 84, &(1u32 << 30).to_le_bytes());
+    match read_partition_table(&path, 512) {
+        Err(ProbeError::ParseFailure { message, .. }) => {
+            assert_eq!(message, "GPT partition entry array out of range")
+        }
+        other => panic!("expected a parse failure, got {:?}", other),
+    }
+
+    // An entry array LBA whose byte offset overflows.
+    desktop_gpt(&path);
+    patch_gpt_header(&path, 72, &u64::MAX.to_le_bytes());
+    assert!(matches!(read_partition_table(&path, 512), Err(ProbeError::ParseFailure { .. })));
+}
+
+/// Overwrites header bytes at `offset` and re-signs the header.
+fn patch_gpt_header(path: &Path, offset: usize, bytes: &[u8]) {
+    let mut image = std::fs::read(path).unwrap();
+    let header = &mut image[512..604];
+    header[offset..offset + bytes.len()].copy_from_slice(bytes);
+    header[16..20].fill(0);
+    let crc = crc32(header);
+    header[16..20].copy_from_slice(&crc.to_le_bytes());
+    std::fs::write(path, image).unwrap();
+}
+
+#[test]
+fn inverted_gpt_extents_are_skipped() {
+    let path = image_path("gpt-inverted.img");
+    gpt_image(
+        &path,
+        "8f3b2a1c-5d4e-4f60-9a7b-0c1d2e3f4a5b",
+        &[
+            GptEntry { type_guid: LINUX_FS, uuid: LINUX_FS, first: 4096, last: 2047, attributes: 0, name: "" },
+            GptEntry { type_guid: LINUX_FS, uuid: LINUX_FS, first: 0, last: u64::MAX, attributes: 0, name: "" },
+            GptEntry { type_guid: LINUX_FS, uuid: LINUX_FS, first: 2048, last: 4095, attributes: 0, name: "ok" },
+        ],
+    );
+    let table = read_partition_table(&path, 512).unwrap().unwrap();
+    assert_eq!(table.partitions.len(), 1);
+    assert_eq!(table.partitions[0].number, 3);
+    assert_eq!(table.partitions[0].size, 2048 * 512);
+}
+
+#[test]
+fn bad_sector_sizes_are_rejected() {
+    let path = image_path("gpt-sector-size.img");
+    desktop_gpt(&path);
+    for size in [0, 4, 520, 1 << 20] {
+        assert!(matches!(read_partition_table(&path, size), Err(ProbeError::ParseFailure { .. })), "{}", size);
+    }
+}
+
+#[test]
+fn mbr_logical_partitions_follow_the_ebr_chain() {
+    let path = image_path("mbr.img");
+    let mut image = mbr_sector(
+        0x1234abcd,
+        &[
+            MbrEntry { boot: true, system_id: 0x83, start: 2048, sectors: 2048 },
+            MbrEntry { boot: false, system_id: 0x05, start: 4096, sectors: 2000 },
+        ],
+    );
+    image.resize(6096 * 512, 0);
+    // Each EBR: a logical partition relative to itself, then the next EBR
+    // relative to the extended partition.
+    let first = mbr_sector(
+        0,
+        &[
+            MbrEntry { boot: false, system_id: 0x82, start: 1, sectors: 999 },
+            MbrEntry { boot: false, system_id: 0x05, start: 1000, sectors: 1000 },
+        ],
+    );
+    let second = mbr_sector(0, &[MbrEntry { boot: false, system_id: 0x8e, start: 1, sectors: 999 }]);
+    image[4096 * 512..4097 * 512].copy_from_slice(&first);
+    image[5096 * 512..5097 * 512].copy_from_slice(&second);
+    std::fs::write(&path, image).unwrap();
+
+    let table = read_partition_table(&path, 512).unwrap().unwrap();
+    assert_eq!(table.scheme, "MBR");
+    assert_eq!(table.disk_id, "1234abcd");
+
+    let summary: Vec<(u32, u64, u64, &str)> = table
+        .partitions
+        .iter()
+        .map(|p| (p.number, p.start_lba, p.end_lba, p.type_name.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (1, 2048, 4095, "Linux"),
+            (2, 4096, 6095, "Extended"),
+            (5, 4097, 5095, "Linux swap"),
+            (6, 5097, 6095, "Linux LVM"),
+        ]
+    );
+    assert_eq!(table.partitions[0].flags, ["boot"]);
+    assert_eq!(table.partitions[2].uuid, "1234abcd-05");
+}
+
+#[test]
+fn blank_disk_has_no_table() {
+    let path = image_path("blank.img");
+    std::fs::write(&path, vec![0u8; 64 * 1024]).unwrap();
+    assert!(read_partition_table(&path, 512).unwrap().is_none());
+}
+
+#[test]
+fn disks_read_the_device_node_or_sysfs() {
+    let ctx = common::fixture("desktop");
+    desktop_gpt(&ctx.dev_path("nvme0n1"));
+    let disks = collect_storage(&ctx).disk_layout;
+
+    let nvme = disks.iter().find(|d| d.name == "nvme0n1").unwrap();
+    let table = nvme.partition_table.as_ref().unwrap();
+    assert_eq!(table.scheme, "GPT");
+    assert_eq!(table.partitions[1].label, "root");
+
+    // No device node or udev record for sda in the fixture: the kernel's view only.
+    let sda = disks.iter().find(|d| d.name == "sda").unwrap();
+    let table = sda.partition_table.as_ref().unwrap();
+    assert_eq!(table.scheme, "");
+    assert_eq!((table.partitions[0].start_lba, table.partitions[0].end_lba), (2048, 7814035455));
+}
+
+#[test]
+fn udev_fills_in_tables_without_opening_the_disk() {
+    let ctx = common::fixture("desktop");
+    let disks = collect_storage(&ctx).disk_layout;
+
+    let nvme = disks.iter().find(|d| d.name == "nvme0n1").unwrap();
+    let table = nvme.partition_table.as_ref().unwrap();
+    assert_eq!((table.scheme.as_str(), table.disk_id.as_str()), ("GPT", "8f3b2a1c-5d4e-4f60-9a7b-0c1d2e3f4a5b"));
+    let esp = &table.partitions[0];
+    assert_eq!(esp.label, "EFI system partition");
+    assert_eq!((esp.type_id.as_str(), esp.type_name.as_str()), ("c12a7328-f81f-11d2-ba4b-00a0c93ec93b", "EFI System"));
+    assert_eq!(esp.uuid, "0b5c6d7e-8f90-4a1b-8c2d-3e4f5a6b7c8d");
+    assert_eq!(esp.flags, ["required"]);
+    assert_eq!(table.partitions[1].label, "root");
+
+    let sdb = disks.iter().find(|d| d.name == "sdb").unwrap();
+    let table = sdb.partition_table.as_ref().unwrap();
+    assert_eq!((table.scheme.as_str(), table.disk_id.as_str()), ("MBR", "5a3c9e10"));
+    let part = &table.partitions[0];
+    assert_eq!((part.type_id.as_str(), part.uuid.as_str()), ("0x0c", "5a3c9e10-01"));
+    assert_eq!(part.flags, ["boot"]);
+}
+
+#[test]
+fn device_nodes_are_opened_only_when_asked() {
+    let probed = |options: &StorageOptions| {
+        let ctx = common::fixture("desktop");
+        collect_storage_with(&ctx, options);
+        ctx.take_diagnostics()
+            .probes
+            .into_iter()
+            .filter(|p| p.probe == "storage.partitions")
+            .map(|p| p.source)
+            .collect::<Vec<_>>()
+    };
+    assert!(probed(&StorageOptions::default()).is_empty());
+
+    let options = StorageOptions { read_partition_tables: true, ..Default::default() };
+    assert!(probed(&options).iter().any(|source| source.ends_with("/dev/sda")));
+}
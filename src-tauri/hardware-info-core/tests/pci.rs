@@ -0,0 +1,67 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::{collect_graphics, collect_pci, graphics_from_pci};
+
+#[test]
+fn pci_devices_match_golden() {
+    let ctx = common::fixture("desktop");
+    common::assert_golden("pci_sysfs", &collect_pci(&ctx));
+}
+
+#[test]
+fn gpus_are_the_display_class_devices() {
+    let ctx = common::fixture("desktop");
+    let controllers = collect_graphics(&ctx).controllers;
+
+    assert_eq!(controllers.len(), 2);
+    assert_eq!(controllers[0].bus, "0000:00:02.0");
+    assert_eq!(controllers[0].model, "AlderLake-S GT1");
+    assert_eq!(controllers[1].vendor, "NVIDIA Corporation");
+    assert_eq!(controllers[1].model, "GA104 [GeForce RTX 3070]");
+}
+
+#[test]
+fn graphics_reuse_a_collected_pci_list() {
+    let ctx = common::fixture("desktop");
+    let pci = collect_pci(&ctx);
+    let controllers = graphics_from_pci(&ctx, &pci).controllers;
+
+    assert_eq!(controllers.len(), 2);
+    let probes = ctx.take_diagnostics().probes;
+    assert_eq!(probes.iter().filter(|p| p.probe == "pci").count(), 1);
+}
+
+#[test]
+fn driver_iommu_and_numa_are_read() {
+    let ctx = common::fixture("desktop");
+    let pci = collect_pci(&ctx);
+    let gpu = pci.devices.iter().find(|d| d.address == "0000:01:00.0").unwrap();
+
+    assert_eq!(gpu.driver, "nvidia");
+    assert_eq!(gpu.iommu_group, Some(14));
+    // numa_node is -1 on a single-socket desktop
+    assert_eq!(gpu.numa_node, None);
+    assert_eq!(gpu.subsystem_vendor, "ASUSTeK Computer Inc.");
+
+    let smbus = pci.devices.iter().find(|d| d.address == "0000:00:1f.5").unwrap();
+    assert_eq!(smbus.driver, "");
+    assert_eq!(smbus.device, "Device 7aa4");
+}
+
+#[test]
+fn bundled_database_names_vendors_and_classes() {
+    let mut ctx = common::fixture("desktop");
+    ctx.hwdata_dirs.clear();
+    let pci = collect_pci(&ctx);
+    let gpu = pci.devices.iter().find(|d| d.address == "0000:01:00.0").unwrap();
+
+    assert_eq!(gpu.vendor, "NVIDIA Corporation");
+    assert_eq!(gpu.device, "Device 2484");
+    assert_eq!(gpu.class_name, "Display controller");
+    assert_eq!(gpu.subclass_name, "VGA compatible controller");
+
+    let ids = ctx.take_diagnostics().probes.into_iter().find(|p| p.probe == "pci.ids").unwrap();
+    assert_eq!(ids.source, "bundled pci.ids");
+}
@@ -0,0 +1,81 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::sysinfo::System;
+use hardware_info_core::{collect_cpu_info, collect_network, collect_optical, collect_static_data, ProbeContext};
+
+#[test]
+fn cpu_ids_and_cache_come_from_fixture() {
+    let ctx = common::fixture("desktop");
+    let cpu = collect_cpu_info(&ctx, &System::new());
+
+    assert_eq!(cpu.family, "6");
+    assert_eq!(cpu.model, "151");
+    assert_eq!(cpu.stepping, "2");
+    assert_eq!(cpu.cache.l2, 1280 * 1024);
+    assert_eq!(cpu.cache.l3, 24 * 1024 * 1024);
+}
+
+#[test]
+fn baseboard_bios_and_firmware_come_from_fixture() {
+    let ctx = common::fixture("desktop");
+    let data = collect_static_data(&ctx, &System::new());
+
+    assert_eq!(data.baseboard.manufacturer, "ASUSTeK COMPUTER INC.");
+    assert_eq!(data.baseboard.model, "ROG STRIX Z690-A GAMING WIFI D4");
+    assert_eq!(data.baseboard.version, "Rev 1.xx");
+    assert_eq!(data.baseboard.serial, "220123456789012");
+    assert_eq!(data.bios.vendor, "American Megatrends Inc.");
+    assert_eq!(data.bios.version, "2103");
+    assert_eq!(data.bios.release_date, "09/26/2022");
+    assert!(data.os.uefi);
+}
+
+#[test]
+fn macs_skip_loopback_and_are_sorted_by_interface() {
+    let ctx = common::fixture("desktop");
+    let data = collect_static_data(&ctx, &System::new());
+
+    assert_eq!(data.uuid.macs, ["04:42:1a:0b:cd:ef", "8c:b8:7e:12:34:56"]);
+}
+
+#[test]
+fn network_interfaces_are_listed_from_sysfs() {
+    let ctx = common::fixture("desktop");
+    let names: Vec<String> = collect_network(&ctx)
+        .interfaces
+        .into_iter()
+        .map(|i| i.iface)
+        .collect();
+
+    assert_eq!(names, ["enp5s0", "lo", "tun0", "wlp4s0"]);
+}
+
+#[test]
+fn optical_drive_is_read_from_procfs() {
+    let ctx = common::fixture("desktop");
+    let optical = collect_optical(&ctx);
+
+    assert_eq!(optical.devices.len(), 1);
+    assert_eq!(optical.devices[0].name, "sr0");
+    assert_eq!(optical.devices[0].model, "HL-DT-ST DVDRAM GH24NSD1");
+}
+
+#[test]
+fn missing_tree_yields_empty_sections() {
+    let ctx = ProbeContext::from_root(common::fixture_dir("does-not-exist"));
+    let sys = System::new();
+
+    let cpu = collect_cpu_info(&ctx, &sys);
+    assert_eq!(cpu.family, "");
+    assert_eq!(cpu.cache.l2, 0);
+
+    let data = collect_static_data(&ctx, &sys);
+    assert_eq!(data.baseboard.manufacturer, "");
+    assert!(!data.os.uefi);
+    assert!(data.uuid.macs.is_empty());
+
+    assert!(collect_network(&ctx).interfaces.is_empty());
+    assert!(collect_optical(&ctx).devices.is_empty());
+}
@@ -0,0 +1,112 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::{collect_cpu_temp, collect_sensors, ProbeError, Sensor, SensorChip};
+
+fn chip<'a>(chips: &'a [SensorChip], name: &str) -> &'a SensorChip {
+    chips.iter().find(|c| c.name == name).unwrap_or_else(|| panic!("no chip {}", name))
+}
+
+fn sensor<'a>(chip: &'a SensorChip, channel: &str) -> &'a Sensor {
+    chip.sensors.iter().find(|s| s.channel == channel).unwrap_or_else(|| panic!("no sensor {}", channel))
+}
+
+#[test]
+fn sensors_match_golden() {
+    let ctx = common::fixture("desktop");
+    common::assert_golden("sensors_sysfs", &collect_sensors(&ctx));
+}
+
+#[test]
+fn chips_and_channels_sort_numerically() {
+    let ctx = common::fixture("desktop");
+    let chips = collect_sensors(&ctx).chips;
+
+    let hwmon: Vec<&str> = chips.iter().map(|c| c.hwmon.as_str()).collect();
+    assert_eq!(hwmon, ["hwmon0", "hwmon1", "hwmon2", "hwmon3", "hwmon10"]);
+    let coretemp: Vec<&str> = chip(&chips, "coretemp").sensors.iter().map(|s| s.channel.as_str()).collect();
+    assert_eq!(coretemp, ["temp1", "temp2", "temp10"]);
+    assert_eq!(chip(&chips, "nvme").device, "nvme0");
+}
+
+#[test]
+fn readings_are_scaled_to_base_units() {
+    let ctx = common::fixture("desktop");
+    let chips = collect_sensors(&ctx).chips;
+    let psu = chip(&chips, "corsairpsu");
+
+    let rail = sensor(psu, "in1");
+    assert_eq!((rail.kind.as_str(), rail.unit.as_str(), rail.value), ("voltage", "V", Some(12.05)));
+    assert_eq!((rail.min, rail.crit), (None, Some(15.0)));
+    assert_eq!(sensor(psu, "curr1").value, Some(14.25));
+    assert_eq!(sensor(psu, "power1").value, Some(180.0));
+
+    let package = sensor(chip(&chips, "coretemp"), "temp1");
+    assert_eq!((package.label.as_str(), package.value), ("Package id 0", Some(45.0)));
+    assert_eq!((package.max, package.crit), (Some(80.0), Some(100.0)));
+}
+
+#[test]
+fn raised_alarms_are_listed() {
+    let ctx = common::fixture("desktop");
+    let chips = collect_sensors(&ctx).chips;
+    let superio = chip(&chips, "nct6798");
+
+    let stalled = sensor(superio, "fan2");
+    assert_eq!((stalled.value, stalled.min), (Some(0.0), Some(300.0)));
+    assert_eq!(stalled.alarms, ["alarm"]);
+    assert!(sensor(superio, "fan1").alarms.is_empty());
+}
+
+#[test]
+fn thermal_zones_carry_trip_points() {
+    let ctx = common::fixture("desktop");
+    let zones = collect_sensors(&ctx).thermal_zones;
+
+    // cooling_device0 is not a zone.
+    let names: Vec<&str> = zones.iter().map(|z| z.zone.as_str()).collect();
+    assert_eq!(names, ["thermal_zone0", "thermal_zone1", "thermal_zone2"]);
+
+    let acpi = &zones[0];
+    assert_eq!((acpi.zone_type.as_str(), acpi.temperature), ("acpitz", Some(27.8)));
+    assert_eq!(acpi.trip_points.len(), 1);
+    assert_eq!((acpi.trip_points[0].trip_type.as_str(), acpi.trip_points[0].temperature), ("critical", 105.0));
+
+    assert_eq!(zones[1].trip_points.len(), 2);
+    assert_eq!(zones[2].temperature, None);
+}
+
+#[test]
+fn cpu_temperature_comes_from_the_cpu_chip() {
+    // coretemp: the package reading, and the hottest of package and cores.
+    let temp = collect_cpu_temp(&common::fixture("desktop"));
+    assert_eq!((temp.main, temp.max), (45.0, 45.0));
+
+    // k10temp: Tctl stands in for the package; a CCD runs hotter.
+    let temp = collect_cpu_temp(&common::fixture("epyc"));
+    assert_eq!((temp.main, temp.max), (61.25, 63.75));
+}
+
+#[test]
+fn cpu_chips_are_recognised_by_their_labels() {
+    // zenpower: Tdie, as k10temp names it, whatever the driver.
+    let temp = collect_cpu_temp(&common::fixture("zenpower"));
+    assert_eq!((temp.main, temp.max), (52.25, 55.5));
+}
+
+#[test]
+fn cpu_thermal_zones_stand_in_for_hwmon() {
+    // ARM boards: no hwmon chip with a package label, only the SoC zone.
+    let temp = collect_cpu_temp(&common::fixture("arm"));
+    assert_eq!((temp.main, temp.max), (48.312, 48.312));
+}
+
+#[test]
+fn no_cpu_chip_is_reported() {
+    let ctx = common::fixture("hypervisor");
+    let temp = collect_cpu_temp(&ctx);
+    assert_eq!((temp.main, temp.max), (0.0, 0.0));
+    let report = ctx.take_diagnostics().probes.into_iter().find(|p| p.probe == "cpu.temperature").unwrap();
+    assert!(matches!(report.error, Some(ProbeError::NotFound { .. })));
+}
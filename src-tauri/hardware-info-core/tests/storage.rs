@@ -0,0 +1,138 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::{collect_storage, collect_storage_with, ProbeError, StorageOptions};
+
+#[test]
+fn disks_match_golden() {
+    let ctx = common::fixture("desktop");
+    common::assert_golden("storage_sysfs", &collect_storage(&ctx).disk_layout);
+}
+
+#[test]
+fn virtual_disks_are_opt_in() {
+    let ctx = common::fixture("desktop");
+    let names = |include_virtual| -> Vec<String> {
+        collect_storage_with(&ctx, &StorageOptions { include_virtual, ..Default::default() })
+            .disk_layout
+            .into_iter()
+            .map(|d| d.name)
+            .collect()
+    };
+
+    assert_eq!(names(false), ["nvme0n1", "sda", "sdb"]);
+    assert_eq!(names(true), ["dm-0", "dm-1", "loop0", "md0", "nvme0n1", "sda", "sdb", "zram0"]);
+}
+
+#[test]
+fn transport_follows_the_device_path() {
+    let ctx = common::fixture("desktop");
+    let disks = collect_storage(&ctx).disk_layout;
+    let transports: Vec<(&str, &str)> = disks
+        .iter()
+        .map(|d| (d.name.as_str(), d.interface_type.as_str()))
+        .collect();
+
+    // sdb is a SCSI disk too, but sits behind a USB port.
+    assert_eq!(transports, [("nvme0n1", "NVMe"), ("sda", "SATA"), ("sdb", "USB")]);
+}
+
+#[test]
+fn identity_comes_from_the_controller_or_vpd_page() {
+    let ctx = common::fixture("desktop");
+    let disks = collect_storage(&ctx).disk_layout;
+
+    let nvme = disks.iter().find(|d| d.name == "nvme0n1").unwrap();
+    assert_eq!(nvme.model, "Samsung SSD 980 PRO 1TB");
+    assert_eq!(nvme.serial, "S5GXNF0R312345A");
+    assert_eq!(nvme.firmware_revision, "5B2QGXA7");
+    assert_eq!(nvme.wwn, "eui.002538b231b0aa11");
+
+    let hdd = disks.iter().find(|d| d.name == "sda").unwrap();
+    assert_eq!(hdd.disk_type, "HDD");
+    assert_eq!(hdd.serial, "ZFN4ABCD");
+    assert_eq!(hdd.size, 7814037168 * 512);
+    assert_eq!((hdd.logical_sector_size, hdd.physical_sector_size), (512, 4096));
+}
+
+#[test]
+fn stack_matches_golden() {
+    let ctx = common::fixture("desktop");
+    common::assert_golden("storage_stack", &collect_storage(&ctx).stack);
+}
+
+#[test]
+fn stack_runs_from_disk_to_mount_point() {
+    let ctx = common::fixture("desktop");
+    let stack = collect_storage(&ctx).stack;
+    let roots: Vec<&str> = stack.iter().map(|n| n.name.as_str()).collect();
+    assert_eq!(roots, ["nvme0n1", "sda", "sdb"]);
+
+    // sda → sda1 → md0 → LUKS → LVM → /data
+    let mut node = &stack[1];
+    let mut path = Vec::new();
+    loop {
+        path.push((node.name.as_str(), node.kind.as_str()));
+        match node.children.first() {
+            Some(child) => node = child,
+            None => break,
+        }
+    }
+    assert_eq!(
+        path,
+        [("sda", "disk"), ("sda1", "partition"), ("md0", "raid"), ("dm-0", "crypt"), ("dm-1", "lvm")]
+    );
+    assert_eq!(node.mapper_name, "vg0-data");
+    assert_eq!(node.fs_type, "ext4");
+    assert_eq!(node.mountpoints, ["/data"]);
+}
+
+#[test]
+fn degraded_array_lists_its_members() {
+    let ctx = common::fixture("desktop");
+    let stack = collect_storage(&ctx).stack;
+    let md0 = &stack[1].children[0].children[0];
+    let raid = md0.raid.as_ref().unwrap();
+
+    assert_eq!(raid.level, "raid1");
+    assert_eq!((raid.raid_disks, raid.degraded), (2, 1));
+    assert_eq!(raid.sync_action, "idle");
+    assert!(raid.sync_progress.is_none());
+    assert_eq!(raid.members.len(), 1);
+    assert_eq!((raid.members[0].name.as_str(), raid.members[0].slot), ("sda1", Some(0)));
+}
+
+#[test]
+fn mount_points_are_unescaped_and_swap_is_shown() {
+    let ctx = common::fixture("desktop");
+    let stack = collect_storage_with(&ctx, &StorageOptions { include_virtual: true, ..Default::default() }).stack;
+
+    let sdb1 = &stack.iter().find(|n| n.name == "sdb").unwrap().children[0];
+    // Escaped bytes of one UTF-8 character decode together.
+    assert_eq!(sdb1.mountpoints, ["/media/user/SANDISK USB", "/srv/café files"]);
+    let zram = stack.iter().find(|n| n.name == "zram0").unwrap();
+    assert_eq!((zram.fs_type.as_str(), zram.mountpoints.as_slice()), ("swap", ["[SWAP]".to_string()].as_slice()));
+}
+
+#[test]
+fn missing_block_dir_is_reported() {
+    let ctx = common::replay("empty");
+    assert!(collect_storage(&ctx).disk_layout.is_empty());
+
+    let diagnostics = ctx.take_diagnostics();
+    let report = diagnostics.probes.iter().find(|p| p.probe == "storage.disks").unwrap();
+    assert!(matches!(report.error, Some(ProbeError::NotFound { .. })));
+}
+
+#[test]
+fn unreadable_mount_tables_are_reported() {
+    // The hypervisor capture has no /proc/self/mountinfo or /proc/swaps.
+    let ctx = common::fixture("hypervisor");
+    collect_storage(&ctx);
+    let probes = ctx.take_diagnostics().probes;
+    for name in ["storage.mounts", "storage.swaps"] {
+        let report = probes.iter().find(|p| p.probe == name).unwrap();
+        assert!(matches!(report.error, Some(ProbeError::NotFound { .. })), "{}", name);
+    }
+}
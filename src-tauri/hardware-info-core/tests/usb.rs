@@ -0,0 +1,88 @@
+#![cfg(target_os = "linux")]
+
+mod common;
+
+use hardware_info_core::collect_peripherals;
+
+#[test]
+fn usb_devices_match_golden() {
+    let ctx = common::fixture("desktop");
+    common::assert_golden("usb_sysfs", &collect_peripherals(&ctx));
+}
+
+#[test]
+fn interfaces_are_not_listed_as_devices() {
+    let ctx = common::fixture("desktop");
+    let paths: Vec<String> = collect_peripherals(&ctx)
+        .usb_devices
+        .into_iter()
+        .map(|d| d.port_path)
+        .collect();
+
+    assert_eq!(paths, ["usb1", "1-9", "1-4", "1-6", "1-6.2", "usb2"]);
+}
+
+#[test]
+fn names_fall_back_to_device_strings() {
+    let ctx = common::fixture("desktop");
+    let devices = collect_peripherals(&ctx).usb_devices;
+
+    let receiver = devices.iter().find(|d| d.port_path == "1-4").unwrap();
+    assert_eq!(receiver.vendor, "Logitech, Inc.");
+    assert_eq!(receiver.name, "Unifying Receiver");
+    assert_eq!(receiver.product, "USB Receiver");
+
+    // 0b05:19af is not in the fixture's usb.ids
+    let aura = devices.iter().find(|d| d.port_path == "1-9").unwrap();
+    assert_eq!(aura.vendor, "AsusTek Computer Inc.");
+    assert_eq!(aura.name, "AURA LED Controller");
+}
+
+#[test]
+fn bundled_database_names_common_vendors() {
+    let mut ctx = common::fixture("desktop");
+    ctx.hwdata_dirs.clear();
+    let devices = collect_peripherals(&ctx).usb_devices;
+
+    let root = devices.iter().find(|d| d.port_path == "usb2").unwrap();
+    assert_eq!(root.name, "3.0 root hub");
+    assert_eq!(root.class_name, "Hub");
+    let stick = devices.iter().find(|d| d.port_path == "1-6.2").unwrap();
+    assert_eq!(stick.vendor, "SanDisk Corp.");
+    assert_eq!(stick.name, "SanDisk 3.2Gen1");
+}
+
+#[test]
+fn tree_follows_hubs_and_ports() {
+    let ctx = common::fixture("desktop");
+    let tree = collect_peripherals(&ctx).usb_tree;
+
+    assert_eq!(tree.len(), 2);
+    let root = &tree[0];
+    assert_eq!(root.device.port_path, "usb1");
+    assert_eq!(root.interfaces[0].driver, "hub");
+    let ports: Vec<u32> = root.children.iter().map(|n| n.port).collect();
+    assert_eq!(ports, [4, 6, 9]);
+
+    let hub = &root.children[1];
+    assert_eq!(hub.device.class_name, "Hub");
+    assert_eq!(hub.children.len(), 1);
+    let stick = &hub.children[0];
+    assert_eq!(stick.port, 2);
+    assert_eq!(stick.interfaces[0].class_name, "Mass Storage");
+    assert_eq!(stick.interfaces[0].subclass_name, "SCSI");
+    assert_eq!(stick.interfaces[0].driver, "usb-storage");
+}
+
+#[test]
+fn usb3_device_behind_usb2_hub_is_flagged() {
+    let ctx = common::fixture("desktop");
+    let tree = collect_peripherals(&ctx).usb_tree;
+
+    let stick = &tree[0].children[1].children[0];
+    assert_eq!(stick.device.usb_version, "3.20");
+    assert_eq!(stick.device.speed_mbps, 480.0);
+    assert!(stick.speed_downgraded);
+    // A USB 2 hub at high speed is where it should be.
+    assert!(!tree[0].children[1].speed_downgraded);
+}
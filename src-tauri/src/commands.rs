@@ -1,11 +1,11 @@
-use crate::hardware;
+use hardware_info_core::{HardwareInfo, LiveInfo};
 
 #[tauri::command]
-pub fn get_hardware_info() -> Result<hardware::HardwareInfo, String> {
-    Ok(hardware::collect_hardware_info())
+pub fn get_hardware_info() -> Result<HardwareInfo, String> {
+    Ok(hardware_info_core::collect_hardware_info())
 }
 
 #[tauri::command]
-pub fn get_hardware_live() -> Result<hardware::LiveInfo, String> {
-    Ok(hardware::collect_live_info())
+pub fn get_hardware_live() -> Result<LiveInfo, String> {
+    Ok(hardware_info_core::collect_live_info())
 }
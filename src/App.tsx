@@ -10,19 +10,26 @@ import { TabId } from './components/Sidebar';
 // Pages
 import Summary from './pages/Summary';
 import Cpu from './pages/Cpu';
+import Sensors from './pages/Sensors';
 import Ram from './pages/Ram';
 import Motherboard from './pages/Motherboard';
 import Graphics from './pages/Graphics';
+import PciDevices from './pages/PciDevices';
 import Storage from './pages/Storage';
 import OperatingSystem from './pages/OperatingSystem';
 import Network from './pages/Network';
 import Audio from './pages/Audio';
 import Peripherals from './pages/Peripherals';
 import OpticalDrives from './pages/OpticalDrives';
+import Diagnostics from './pages/Diagnostics';
+
+/** Live samples kept for sparklines: five minutes at one poll every 3 s. */
+const LIVE_HISTORY = 100;
 
 function App() {
   const [hardwareInfo, setHardwareInfo] = useState<HardwareInfo | null>(null);
   const [liveInfo, setLiveInfo] = useState<LiveInfo | null>(null);
+  const [liveHistory, setLiveHistory] = useState<LiveInfo[]>([]);
   const [activeTab, setActiveTab] = useState<TabId>('summary');
   const [error, setError] = useState<string>('');
 
@@ -44,6 +51,7 @@ function App() {
       try {
         const data = await invoke<LiveInfo>('get_hardware_live');
         setLiveInfo(data);
+        setLiveHistory((history) => [...history.slice(-(LIVE_HISTORY - 1)), data]);
       } catch (fetchError) {
         console.error(fetchError);
       }
@@ -85,22 +93,28 @@ function App() {
         return <OperatingSystem hardware={mergedHardware} live={liveInfo} />;
       case 'cpu':
         return <Cpu hardware={mergedHardware} live={liveInfo} />;
+      case 'sensors':
+        return <Sensors hardware={mergedHardware} history={liveHistory} />;
       case 'ram':
         return <Ram hardware={mergedHardware} live={liveInfo} />;
       case 'motherboard':
         return <Motherboard hardware={mergedHardware} />;
       case 'graphics':
         return <Graphics hardware={mergedHardware} />;
+      case 'pci':
+        return <PciDevices hardware={mergedHardware} />;
       case 'storage':
-        return <Storage hardware={mergedHardware} />;
+        return <Storage hardware={mergedHardware} history={liveHistory} />;
       case 'network':
-        return <Network hardware={mergedHardware} />;
+        return <Network hardware={mergedHardware} history={liveHistory} />;
       case 'audio':
         return <Audio hardware={mergedHardware} />;
       case 'peripherals':
         return <Peripherals hardware={mergedHardware} />;
       case 'optical':
         return <OpticalDrives hardware={mergedHardware} />;
+      case 'diagnostics':
+        return <Diagnostics hardware={mergedHardware} />;
       default:
         return <Summary hardware={mergedHardware} live={liveInfo} />;
     }
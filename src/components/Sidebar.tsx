@@ -10,21 +10,27 @@ import {
   MousePointer2, 
   Network,
   Activity,
-  Disc
+  Disc,
+  Stethoscope,
+  CircuitBoard,
+  Thermometer
 } from 'lucide-react';
 
 export type TabId = 
   | 'summary' 
   | 'os' 
   | 'cpu' 
+  | 'sensors'
   | 'ram' 
   | 'motherboard' 
   | 'graphics' 
+  | 'pci' 
   | 'storage' 
   | 'audio' 
   | 'peripherals' 
   | 'network'
-  | 'optical';
+  | 'optical'
+  | 'diagnostics';
 
 interface Tab {
   id: TabId;
@@ -36,14 +42,17 @@ const tabs: Tab[] = [
   { id: 'summary', label: 'Summary', icon: Activity },
   { id: 'os', label: 'Operating System', icon: Monitor },
   { id: 'cpu', label: 'CPU', icon: Cpu },
+  { id: 'sensors', label: 'Sensors', icon: Thermometer },
   { id: 'ram', label: 'RAM', icon: Database },
   { id: 'motherboard', label: 'Motherboard', icon: Layers },
   { id: 'graphics', label: 'Graphics', icon: Monitor },
+  { id: 'pci', label: 'PCI Devices', icon: CircuitBoard },
   { id: 'storage', label: 'Storage', icon: HardDrive },
   { id: 'audio', label: 'Audio', icon: Music },
   { id: 'peripherals', label: 'Peripherals', icon: MousePointer2 },
   { id: 'network', label: 'Network', icon: Network },
   { id: 'optical', label: 'Optical Drives', icon: Disc },
+  { id: 'diagnostics', label: 'Diagnostics', icon: Stethoscope },
 ];
 
 interface SidebarProps {
@@ -0,0 +1,27 @@
+import React from 'react';
+
+interface SparklineProps {
+  values: number[];
+  /** Top of the scale; defaults to the largest value. */
+  max?: number;
+  className?: string;
+}
+
+const WIDTH = 120;
+const HEIGHT = 28;
+
+const Sparkline: React.FC<SparklineProps> = ({ values, max, className = 'text-blue-400' }) => {
+  const top = Math.max(max ?? Math.max(...values, 0), Number.EPSILON);
+  const step = values.length > 1 ? WIDTH / (values.length - 1) : WIDTH;
+  const points = values
+    .map((value, idx) => `${(idx * step).toFixed(1)},${(HEIGHT - (Math.min(value, top) / top) * HEIGHT).toFixed(1)}`)
+    .join(' ');
+
+  return (
+    <svg viewBox={`0 0 ${WIDTH} ${HEIGHT}`} width={WIDTH} height={HEIGHT} className={className}>
+      {values.length > 1 && <polyline points={points} fill="none" stroke="currentColor" strokeWidth={1.5} />}
+    </svg>
+  );
+};
+
+export default Sparkline;
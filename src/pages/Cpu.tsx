@@ -1,18 +1,341 @@
 import React from 'react';
 import Row from '../components/Row';
 import { formatBytes, formatGHz, formatPercent } from '../utils';
-import { HardwareInfo, LiveInfo } from '../types';
+import {
+  CacheInstance,
+  CpuCache,
+  CpuFeatures,
+  CpuPower,
+  CpuTopology,
+  FrequencyScaling,
+  HardwareInfo,
+  LiveInfo,
+} from '../types';
 
 interface CpuProps {
   hardware: HardwareInfo;
   live: LiveInfo | null;
 }
 
+/** `0-3,8`, the kernel's own notation. */
+const formatCpuList = (cpus: number[]) => {
+  const ranges: [number, number][] = [];
+  for (const cpu of cpus) {
+    const last = ranges[ranges.length - 1];
+    if (last && last[1] + 1 === cpu) last[1] = cpu;
+    else ranges.push([cpu, cpu]);
+  }
+  return ranges.map(([start, end]) => (start === end ? `${start}` : `${start}-${end}`)).join(',');
+};
+
+/** Instances with the same level, type and geometry, with the CPUs they serve. */
+const groupCaches = (instances: CacheInstance[]) => {
+  const groups: { instance: CacheInstance; count: number; cpus: number[] }[] = [];
+  for (const instance of instances) {
+    const group = groups.find(
+      (g) =>
+        g.instance.level === instance.level &&
+        g.instance.type === instance.type &&
+        g.instance.size === instance.size &&
+        g.instance.ways === instance.ways &&
+        g.instance.lineSize === instance.lineSize,
+    );
+    if (group) {
+      group.count += 1;
+      group.cpus.push(...instance.cpus);
+    } else {
+      groups.push({ instance, count: 1, cpus: [...instance.cpus] });
+    }
+  }
+  return groups.map((g) => ({ ...g, cpus: [...new Set(g.cpus)].sort((a, b) => a - b) }));
+};
+
+const CacheTable: React.FC<{ cache: CpuCache }> = ({ cache }) => (
+  <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+    <h3 className="text-blue-400 font-semibold mb-4">Cache</h3>
+    <div className="overflow-x-auto">
+      <table className="w-full text-left">
+        <thead>
+          <tr className="border-b border-gray-800 bg-gray-800/30">
+            <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Cache</th>
+            <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">Size</th>
+            <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">Ways</th>
+            <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">Line</th>
+            <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">Sets</th>
+            <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">CPUs</th>
+          </tr>
+        </thead>
+        <tbody className="divide-y divide-gray-800">
+          {groupCaches(cache.instances).map(({ instance, count, cpus }, idx) => (
+            <tr key={idx} className="hover:bg-gray-800/20">
+              <td className="px-4 py-3 text-sm text-gray-200">
+                L{instance.level} {instance.type}
+              </td>
+              <td className="px-4 py-3 text-sm text-gray-200 text-right">
+                {count} × {formatBytes(instance.size)}
+              </td>
+              <td className="px-4 py-3 text-sm text-gray-400 font-mono text-right">{instance.ways || '—'}</td>
+              <td className="px-4 py-3 text-sm text-gray-400 font-mono text-right">{instance.lineSize} B</td>
+              <td className="px-4 py-3 text-sm text-gray-400 font-mono text-right">{instance.sets}</td>
+              <td className="px-4 py-3 text-sm text-gray-400 font-mono">{formatCpuList(cpus)}</td>
+            </tr>
+          ))}
+        </tbody>
+      </table>
+    </div>
+    <div className="space-y-1 mt-4 pt-4 border-t border-gray-800">
+      {cache.totals.map((total) => (
+        <Row
+          key={`${total.level}${total.type}`}
+          label={`Total L${total.level} ${total.type}`}
+          value={formatBytes(total.size)}
+        />
+      ))}
+    </div>
+  </section>
+);
+
+const VIRTUALIZATION: Record<string, string> = { vmx: 'Intel VT-x', svm: 'AMD-V' };
+
+const STATUS_COLORS: Record<string, string> = {
+  'not affected': 'text-emerald-400',
+  mitigated: 'text-blue-400',
+  vulnerable: 'text-red-400',
+  unknown: 'text-gray-500',
+};
+
+const Features: React.FC<{ features: CpuFeatures }> = ({ features }) => (
+  <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+    <h3 className="text-blue-400 font-semibold mb-4">Features</h3>
+    <div className="space-y-1">
+      <Row label="Microcode" value={features.microcode || '—'} />
+      <Row label="AVX-512" value={features.flags.includes('avx512f') ? 'Supported' : 'Not supported'} />
+      <Row
+        label="Virtualization"
+        value={VIRTUALIZATION[features.virtualization] || (features.hypervisor ? 'Hidden by hypervisor' : 'Not available')}
+      />
+      {features.hypervisor && <Row label="Hypervisor" value="Running as a guest" />}
+    </div>
+    <div className="mt-4 pt-4 border-t border-gray-800 space-y-3">
+      {features.extensions.map((category) => (
+        <div key={category.category} className="flex gap-4">
+          <span className="text-gray-400 text-sm w-36 shrink-0">{category.category}</span>
+          <div className="flex flex-wrap gap-1.5">
+            {category.flags.map((flag) => (
+              <span key={flag} className="px-2 py-0.5 rounded bg-gray-800 text-gray-200 text-xs font-mono">
+                {flag}
+              </span>
+            ))}
+          </div>
+        </div>
+      ))}
+    </div>
+    {features.vulnerabilities.length > 0 && (
+      <div className="overflow-x-auto mt-4 pt-4 border-t border-gray-800">
+        <table className="w-full text-left">
+          <thead>
+            <tr className="border-b border-gray-800 bg-gray-800/30">
+              <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Vulnerability</th>
+              <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Status</th>
+              <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Detail</th>
+            </tr>
+          </thead>
+          <tbody className="divide-y divide-gray-800">
+            {features.vulnerabilities.map((v) => (
+              <tr key={v.name} className="hover:bg-gray-800/20">
+                <td className="px-4 py-3 text-sm text-gray-200 font-mono">{v.name}</td>
+                <td className={`px-4 py-3 text-sm ${STATUS_COLORS[v.status]}`}>{v.status}</td>
+                <td className="px-4 py-3 text-sm text-gray-400">{v.detail}</td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      </div>
+    )}
+  </section>
+);
+
+const LIMIT_NAMES: Record<string, string> = { long_term: 'PL1', short_term: 'PL2', peak_power: 'PL4' };
+
+const formatWindow = (secs: number) => (secs >= 1 ? `${secs.toFixed(1)} s` : `${(secs * 1000).toFixed(1)} ms`);
+
+const Power: React.FC<{ power: CpuPower }> = ({ power }) => (
+  <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+    <h3 className="text-blue-400 font-semibold mb-4">Power</h3>
+    <div className="space-y-1">
+      {power.domains.map((domain) => (
+        <React.Fragment key={domain.zone}>
+          <Row
+            label={domain.zone.split(':').length > 2 ? `↳ ${domain.name}` : domain.name}
+            value={`${domain.watts === null ? '—' : `${domain.watts.toFixed(1)} W`}${domain.enabled ? '' : ' (disabled)'}`}
+          />
+          {domain.limits
+            .filter((limit) => limit.watts > 0)
+            .map((limit) => (
+              <Row
+                key={limit.name}
+                label={`${domain.name} ${LIMIT_NAMES[limit.name] || limit.name}`}
+                value={[
+                  `${limit.watts.toFixed(1)} W`,
+                  limit.timeWindow !== null && `over ${formatWindow(limit.timeWindow)}`,
+                  limit.maxWatts !== null && `max ${limit.maxWatts.toFixed(1)} W`,
+                ]
+                  .filter(Boolean)
+                  .join(', ')}
+              />
+            ))}
+        </React.Fragment>
+      ))}
+    </div>
+  </section>
+);
+
+const distinct = (values: string[]) => [...new Set(values.filter(Boolean))].join(', ');
+
+const Scaling: React.FC<{ scaling: FrequencyScaling }> = ({ scaling }) => {
+  const { policies } = scaling;
+  const epp = distinct(policies.map((p) => p.energyPerformancePreference));
+  return (
+    <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+      <h3 className="text-blue-400 font-semibold mb-4">Frequency Scaling</h3>
+      <div className="space-y-1">
+        <Row label="Driver" value={scaling.driver} />
+        <Row label="Boost" value={scaling.boost === null ? '—' : scaling.boost ? 'Enabled' : 'Disabled'} />
+        <Row label="Governor" value={distinct(policies.map((p) => p.governor))} />
+        <Row label="Available Governors" value={policies[0].availableGovernors.join(', ')} />
+        {epp && <Row label="Energy Preference" value={epp} />}
+      </div>
+      <div className="overflow-x-auto mt-4 pt-4 border-t border-gray-800">
+        <table className="w-full text-left">
+          <thead>
+            <tr className="border-b border-gray-800 bg-gray-800/30">
+              <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Policy</th>
+              <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">CPUs</th>
+              <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">Base</th>
+              <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">Hardware</th>
+              <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">Limits</th>
+              <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">Current</th>
+            </tr>
+          </thead>
+          <tbody className="divide-y divide-gray-800">
+            {policies.map((p) => {
+              const capped = p.scalingMin !== p.hardwareMin || p.scalingMax !== p.hardwareMax;
+              return (
+                <tr key={p.policy} className="hover:bg-gray-800/20">
+                  <td className="px-4 py-3 text-sm text-gray-200 font-mono">policy{p.policy}</td>
+                  <td className="px-4 py-3 text-sm text-gray-400 font-mono">{formatCpuList(p.cpus)}</td>
+                  <td className="px-4 py-3 text-sm text-gray-200 text-right">{p.base ? formatGHz(p.base) : '—'}</td>
+                  <td className="px-4 py-3 text-sm text-gray-200 text-right">
+                    {formatGHz(p.hardwareMin)} – {formatGHz(p.hardwareMax)}
+                  </td>
+                  <td className={`px-4 py-3 text-sm text-right ${capped ? 'text-amber-400' : 'text-gray-400'}`}>
+                    {formatGHz(p.scalingMin)} – {formatGHz(p.scalingMax)}
+                  </td>
+                  <td className="px-4 py-3 text-sm text-gray-200 font-mono text-right">{formatGHz(p.current)}</td>
+                </tr>
+              );
+            })}
+          </tbody>
+        </table>
+      </div>
+    </section>
+  );
+};
+
+const Topology: React.FC<{ topology: CpuTopology }> = ({ topology }) => {
+  const offline = topology.cpus.filter((c) => !c.online).map((c) => c.cpu);
+  return (
+    <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+      <h3 className="text-blue-400 font-semibold mb-4">Topology</h3>
+      <ul>
+        {topology.packages.map((pkg) => (
+          <li key={pkg.id} className="py-1">
+            <div className="text-sm text-gray-200 font-medium">Package {pkg.id}</div>
+            <ul className="ml-6 border-l border-gray-800 pl-4">
+              {pkg.dies.map((die) => (
+                <li key={die.id} className="py-1">
+                  <div className="text-sm text-gray-300">Die {die.id}</div>
+                  <ul className="ml-6 border-l border-gray-800 pl-4">
+                    {die.clusters.map((cluster) => (
+                      <li key={cluster.id} className="py-1 text-sm">
+                        <span className="text-gray-400">Cluster {cluster.id}</span>
+                        {cluster.cores.map((core) => (
+                          <span key={core.id} className="text-gray-200 font-mono ml-3">
+                            core {core.id} <span className="text-gray-500">[{formatCpuList(core.threads)}]</span>
+                          </span>
+                        ))}
+                      </li>
+                    ))}
+                  </ul>
+                </li>
+              ))}
+            </ul>
+          </li>
+        ))}
+      </ul>
+      {offline.length > 0 && (
+        <div className="mt-4 pt-4 border-t border-gray-800">
+          <Row label="Offline CPUs" value={formatCpuList(offline)} />
+        </div>
+      )}
+      {topology.numaNodes.length > 0 && (
+        <div className="overflow-x-auto mt-4 pt-4 border-t border-gray-800">
+          <table className="w-full text-left">
+            <thead>
+              <tr className="border-b border-gray-800 bg-gray-800/30">
+                <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">NUMA Node</th>
+                <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">CPUs</th>
+                <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">Memory</th>
+                {topology.numaNodes.map((node) => (
+                  <th
+                    key={node.id}
+                    className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right"
+                  >
+                    → {node.id}
+                  </th>
+                ))}
+              </tr>
+            </thead>
+            <tbody className="divide-y divide-gray-800">
+              {topology.numaNodes.map((node) => (
+                <tr key={node.id} className="hover:bg-gray-800/20">
+                  <td className="px-4 py-3 text-sm text-gray-200">Node {node.id}</td>
+                  <td className="px-4 py-3 text-sm text-gray-400 font-mono">{formatCpuList(node.cpus)}</td>
+                  <td className="px-4 py-3 text-sm text-gray-200 text-right">{formatBytes(node.memoryTotal)}</td>
+                  {node.distances.map((distance, idx) => (
+                    <td key={idx} className="px-4 py-3 text-sm text-gray-400 font-mono text-right">
+                      {distance}
+                    </td>
+                  ))}
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        </div>
+      )}
+    </section>
+  );
+};
+
 const Cpu: React.FC<CpuProps> = ({ hardware, live }) => {
   const cpu = hardware.cpu;
   const speed = live?.cpuCurrentSpeed || hardware.cpuCurrentSpeed;
   const load = live?.currentLoad || hardware.currentLoad;
   const temp = live?.cpuTemperature || hardware.cpuTemperature;
+  const times = live?.cpuTimes || hardware.cpuTimes;
+  const power = live?.cpuPower || cpu.power;
+  const breakdown = times
+    ? [
+        ['User', times.user],
+        ['Nice', times.nice],
+        ['System', times.system],
+        ['Idle', times.idle],
+        ['I/O Wait', times.iowait],
+        ['IRQ', times.irq],
+        ['Soft IRQ', times.softirq],
+        ['Steal', times.steal],
+      ] as const
+    : [];
 
   return (
     <div className="space-y-6">
@@ -38,6 +361,15 @@ const Cpu: React.FC<CpuProps> = ({ hardware, live }) => {
           <div className="space-y-1">
             <Row label="Physical Cores" value={cpu.physicalCores} />
             <Row label="Logical Processors" value={cpu.cores} />
+            {(cpu.coreTypes || []).map((type) => (
+              <Row
+                key={type.kind}
+                label={`${type.kind[0].toUpperCase()}${type.kind.slice(1)} Cores`}
+                value={`${type.cores} (CPUs ${formatCpuList(type.cpus)}), ${formatGHz(type.speedMin)} – ${formatGHz(type.speedMax)}`}
+              />
+            ))}
+            <Row label="L1 Data Cache" value={formatBytes(cpu.cache.l1d)} />
+            <Row label="L1 Instruction Cache" value={formatBytes(cpu.cache.l1i)} />
             <Row label="L2 Cache" value={formatBytes(cpu.cache.l2)} />
             <Row label="L3 Cache" value={formatBytes(cpu.cache.l3)} />
           </div>
@@ -61,7 +393,52 @@ const Cpu: React.FC<CpuProps> = ({ hardware, live }) => {
             <Row label="Max Core Temp" value={temp.max ? `${temp.max}°C` : 'N/A'} />
           </div>
         </section>
+
+        {breakdown.length > 0 && (
+          <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+            <h3 className="text-blue-400 font-semibold mb-4">CPU Time</h3>
+            <div className="space-y-1">
+              {breakdown.map(([label, pct]) => (
+                <Row key={label} label={label} value={formatPercent(pct)} />
+              ))}
+            </div>
+          </section>
+        )}
       </div>
+
+      {(cpu.cache.totals || []).length > 0 && <CacheTable cache={cpu.cache} />}
+
+      {(cpu.topology?.packages || []).length > 0 && <Topology topology={cpu.topology} />}
+
+      {(power?.domains || []).length > 0 && <Power power={power} />}
+
+      {(cpu.frequencyScaling?.policies || []).length > 0 && <Scaling scaling={cpu.frequencyScaling} />}
+
+      {cpu.features && (cpu.features.flags.length > 0 || cpu.features.vulnerabilities.length > 0) && (
+        <Features features={cpu.features} />
+      )}
+
+      {(load.cpus || []).length > 0 && (
+        <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+          <h3 className="text-blue-400 font-semibold mb-4">Per CPU</h3>
+          <div className="grid grid-cols-1 md:grid-cols-2 xl:grid-cols-4 gap-x-8 gap-y-2">
+            {load.cpus.map((pct, idx) => (
+              <div key={idx} className="flex items-center gap-3 px-2">
+                <span className="text-gray-400 text-sm font-medium w-14">CPU {idx}</span>
+                <div className="flex-1 h-2 bg-gray-800 rounded-full overflow-hidden">
+                  <div
+                    className={`h-full ${pct > 90 ? 'bg-red-500' : 'bg-blue-500'}`}
+                    style={{ width: `${Math.min(pct, 100)}%` }}
+                  />
+                </div>
+                <span className="text-gray-200 text-xs font-mono w-28 text-right">
+                  {formatPercent(pct)} · {formatGHz(speed.cores?.[idx] ?? 0)}
+                </span>
+              </div>
+            ))}
+          </div>
+        </section>
+      )}
     </div>
   );
 };
@@ -0,0 +1,47 @@
+import React from 'react';
+import Row from '../components/Row';
+import { HardwareInfo } from '../types';
+import { describeProbeError } from '../utils';
+
+interface DiagnosticsProps {
+  hardware: HardwareInfo;
+}
+
+const Diagnostics: React.FC<DiagnosticsProps> = ({ hardware }) => {
+  const probes = hardware.diagnostics?.probes || [];
+  const failed = probes.filter((p) => p.error);
+
+  return (
+    <div className="space-y-6">
+      <header className="mb-8">
+        <h2 className="text-2xl font-bold text-white mb-2">Diagnostics</h2>
+        <p className="text-gray-400">
+          What each probe read, how long it took and why it came back empty.
+        </p>
+      </header>
+
+      <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+        <h3 className="text-blue-400 font-semibold mb-4 text-sm uppercase tracking-wider">Overview</h3>
+        <div className="space-y-1">
+          <Row label="Probes Run" value={probes.length} />
+          <Row label="Failed" value={String(failed.length)} />
+        </div>
+      </section>
+
+      {probes.map((p, idx) => (
+        <section key={idx} className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+          <h3 className={`${p.error ? 'text-amber-400' : 'text-blue-400'} font-semibold mb-4 text-sm uppercase tracking-wider`}>
+            {p.probe}
+          </h3>
+          <div className="space-y-1">
+            <Row label="Source" value={p.source} />
+            <Row label="Duration" value={`${p.durationMs.toFixed(1)} ms`} />
+            <Row label="Result" value={describeProbeError(p.error)} />
+          </div>
+        </section>
+      ))}
+    </div>
+  );
+};
+
+export default Diagnostics;
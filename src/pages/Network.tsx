@@ -1,13 +1,163 @@
 import React from 'react';
 import Row from '../components/Row';
-import { HardwareInfo } from '../types';
+import Sparkline from '../components/Sparkline';
+import { formatBytes, formatPercent } from '../utils';
+import {
+  HardwareInfo,
+  InterfaceAddress,
+  LiveInfo,
+  NetworkInterface,
+  NetworkIo,
+  Route,
+  VethPeer,
+  WirelessInfo,
+} from '../types';
 
 interface NetworkProps {
   hardware: HardwareInfo;
+  /** Oldest first; empty until the first live poll. */
+  history: LiveInfo[];
 }
 
-const Network: React.FC<NetworkProps> = ({ hardware }) => {
-  const interfaces = hardware.network.interfaces || [];
+const AddressList: React.FC<{ label: string; addresses: InterfaceAddress[] }> = ({ label, addresses }) => (
+  <div className="flex justify-between py-2 border-b border-gray-800/50 last:border-0 hover:bg-gray-800/20 px-2 transition-colors">
+    <span className="text-gray-400 text-sm font-medium">{label}</span>
+    <div className="text-right">
+      {addresses.length > 0 ? (
+        addresses.map((a) => (
+          <div key={a.address} className="text-gray-200 text-sm font-mono">
+            {a.address}/{a.prefixLen}
+            {a.scope && a.scope !== 'global' && <span className="text-gray-500 text-xs ml-2">{a.scope}</span>}
+          </div>
+        ))
+      ) : (
+        <strong className="text-gray-200 text-sm">—</strong>
+      )}
+    </div>
+  </div>
+);
+
+const linkState = (operstate: string, carrier: boolean | null) => {
+  if (carrier === null) return operstate;
+  return `${operstate}, ${carrier ? 'carrier' : 'no carrier'}`;
+};
+
+const Traffic: React.FC<{ io: NetworkIo; samples: (NetworkIo | undefined)[] }> = ({ io, samples }) => (
+  <div className="grid grid-cols-1 md:grid-cols-2 gap-x-12 mt-4 pt-4 border-t border-gray-800">
+    <div className="flex items-center justify-between px-2">
+      <span className="text-gray-400 text-sm font-medium">Receive</span>
+      <Sparkline values={samples.map((s) => s?.rxBytesPerSec ?? 0)} className="text-emerald-400" />
+      <span className="text-gray-200 text-sm font-mono">
+        {formatBytes(io.rxBytesPerSec)}/s · {io.rxPacketsPerSec.toFixed(0)} pkt/s
+      </span>
+    </div>
+    <div className="flex items-center justify-between px-2">
+      <span className="text-gray-400 text-sm font-medium">Transmit</span>
+      <Sparkline values={samples.map((s) => s?.txBytesPerSec ?? 0)} className="text-amber-400" />
+      <span className="text-gray-200 text-sm font-mono">
+        {formatBytes(io.txBytesPerSec)}/s · {io.txPacketsPerSec.toFixed(0)} pkt/s
+      </span>
+    </div>
+    <div className="space-y-1 mt-2">
+      <Row label="Received Since Boot" value={`${formatBytes(io.rxBytesTotal)} (${io.rxPacketsTotal} packets)`} />
+      <Row label="Receive Errors / Drops" value={`${io.rxErrorsTotal} / ${io.rxDroppedTotal}`} />
+    </div>
+    <div className="space-y-1 mt-2">
+      <Row label="Sent Since Boot" value={`${formatBytes(io.txBytesTotal)} (${io.txPacketsTotal} packets)`} />
+      <Row label="Transmit Errors / Drops" value={`${io.txErrorsTotal} / ${io.txDroppedTotal}`} />
+    </div>
+  </div>
+);
+
+const Wireless: React.FC<{ wifi: WirelessInfo }> = ({ wifi }) => {
+  const channel = [
+    wifi.frequencyMhz !== null ? `${wifi.frequencyMhz} MHz` : '',
+    wifi.band,
+    wifi.channel !== null ? `channel ${wifi.channel}` : '',
+    wifi.channelWidthMhz !== null ? `${wifi.channelWidthMhz} MHz wide` : '',
+  ].filter(Boolean);
+  const signal = [
+    wifi.signalDbm !== null ? `${wifi.signalDbm} dBm` : '',
+    wifi.noiseDbm !== null ? `noise ${wifi.noiseDbm} dBm` : '',
+  ].filter(Boolean);
+
+  return (
+    <div className="grid grid-cols-1 md:grid-cols-2 gap-x-12 mt-4 pt-4 border-t border-gray-800">
+      <div className="space-y-1">
+        <Row label="SSID" value={wifi.ssid || 'Not connected'} />
+        <Row label="BSSID" value={wifi.bssid} />
+        <Row label="Channel" value={channel.join(', ')} />
+        <Row label="Signal" value={signal.join(', ')} />
+      </div>
+      <div className="space-y-1">
+        <Row label="Link Quality" value={wifi.linkQualityPct !== null ? formatPercent(wifi.linkQualityPct) : ''} />
+        <Row
+          label="Bitrate (tx / rx)"
+          value={
+            wifi.txBitrateMbps !== null && wifi.rxBitrateMbps !== null
+              ? `${wifi.txBitrateMbps} / ${wifi.rxBitrateMbps} Mb/s`
+              : ''
+          }
+        />
+        <Row label="Radio" value={[wifi.phy, wifi.supportedBands.join(', ')].filter(Boolean).join(' · ')} />
+        <Row label="Regulatory Domain" value={wifi.regulatoryDomain} />
+      </div>
+    </div>
+  );
+};
+
+const vethPeerText = (peer: VethPeer) => {
+  if (peer.netnsId === null && peer.name) return peer.name;
+  if (peer.netnsId !== null) return `ifindex ${peer.ifindex} in ${peer.netns || `namespace ${peer.netnsId}`}`;
+  return `ifindex ${peer.ifindex}`;
+};
+
+/** Interfaces each one is built on: bridge ports, bond slaves, a VLAN's parent. */
+const lowerInterfaces = (iface: NetworkInterface) => [...iface.ports, ...(iface.vlan ? [iface.vlan.parent] : [])];
+
+const TopologyNode: React.FC<{ iface: NetworkInterface; interfaces: NetworkInterface[]; depth: number }> = ({
+  iface,
+  interfaces,
+  depth,
+}) => {
+  // Guards against a malformed tree that loops.
+  const children =
+    depth > 8
+      ? []
+      : lowerInterfaces(iface)
+          .map((name) => interfaces.find((i) => i.iface === name))
+          .filter((i): i is NetworkInterface => i !== undefined);
+  return (
+    <li className="py-1">
+      <div className="text-sm">
+        <span className="text-gray-200 font-mono">{iface.iface}</span>
+        <span className="text-gray-500 ml-2">{iface.kind}</span>
+        {iface.vlan && <span className="text-gray-400 ml-2">id {iface.vlan.id}</span>}
+        {iface.bond && <span className="text-gray-400 ml-2">{iface.bond.mode}</span>}
+        {iface.vethPeer && <span className="text-gray-400 ml-2">peer {vethPeerText(iface.vethPeer)}</span>}
+      </div>
+      {children.length > 0 && (
+        <ul className="ml-6 border-l border-gray-800 pl-4">
+          {children.map((child) => (
+            <TopologyNode key={child.iface} iface={child} interfaces={interfaces} depth={depth + 1} />
+          ))}
+        </ul>
+      )}
+    </li>
+  );
+};
+
+const gatewayText = (route: Route | null) => (route ? `${route.gateway} via ${route.iface}` : '');
+
+const Network: React.FC<NetworkProps> = ({ hardware, history }) => {
+  const network = hardware.network;
+  const interfaces = network.interfaces || [];
+  const routes = network.routes || [];
+  const latestIo = history.length > 0 ? history[history.length - 1].networkIo : hardware.networkIo || [];
+  const ioHistory = (iface: string) =>
+    history.map((sample) => sample.networkIo.find((io) => io.iface === iface));
+  const lower = interfaces.flatMap(lowerInterfaces);
+  const topologyRoots = lower.length > 0 ? interfaces.filter((i) => !lower.includes(i.iface)) : [];
 
   return (
     <div className="space-y-6">
@@ -20,18 +170,44 @@ const Network: React.FC<NetworkProps> = ({ hardware }) => {
         interfaces.map((iface, idx) => (
           <section key={idx} className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
             <h3 className="text-blue-400 font-semibold mb-4 text-sm uppercase tracking-wider">
-              {iface.iface}
+              {iface.iface} <span className="text-gray-500 normal-case">({iface.kind})</span>
             </h3>
             <div className="grid grid-cols-1 md:grid-cols-2 gap-x-12">
               <div className="space-y-1">
-                <Row label="IPv4 Address" value={iface.ip4} />
-                <Row label="IPv6 Address" value={iface.ip6} />
+                <AddressList label="IPv4 Addresses" addresses={iface.ip4} />
+                <AddressList label="IPv6 Addresses" addresses={iface.ip6} />
+                <Row label="MAC Address" value={iface.mac} />
+                <Row label="MTU" value={iface.mtu} />
               </div>
               <div className="space-y-1">
-                <Row label="MAC Address" value={iface.mac} />
-                <Row label="Status" value="Active" />
+                <Row label="State" value={linkState(iface.operstate, iface.carrier)} />
+                <Row
+                  label="Link Speed"
+                  value={
+                    iface.speedMbps !== null
+                      ? `${iface.speedMbps} Mb/s${iface.duplex ? ` ${iface.duplex} duplex` : ''}`
+                      : ''
+                  }
+                />
+                <Row label="Driver" value={iface.driver} />
+                <Row label="Device" value={iface.parentDevice && `${iface.parentBus} ${iface.parentDevice}`} />
+                {iface.master && <Row label="Member Of" value={iface.master} />}
+                {iface.bond && (
+                  <Row
+                    label="Bond"
+                    value={`${iface.bond.mode}, active ${iface.bond.activeSlave}, MII ${iface.bond.miiStatus}`}
+                  />
+                )}
+                {iface.vlan && <Row label="VLAN" value={`${iface.vlan.id} on ${iface.vlan.parent}`} />}
+                {iface.vethPeer && <Row label="Peer" value={vethPeerText(iface.vethPeer)} />}
               </div>
             </div>
+            {iface.wireless && <Wireless wifi={iface.wireless} />}
+            {latestIo
+              .filter((io) => io.iface === iface.iface)
+              .map((io) => (
+                <Traffic key={io.iface} io={io} samples={ioHistory(io.iface)} />
+              ))}
           </section>
         ))
       ) : (
@@ -39,6 +215,66 @@ const Network: React.FC<NetworkProps> = ({ hardware }) => {
           No network interfaces detected.
         </div>
       )}
+
+      {topologyRoots.length > 0 && (
+        <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+          <h3 className="text-blue-400 font-semibold mb-4 text-sm uppercase tracking-wider">Topology</h3>
+          <ul>
+            {topologyRoots.map((iface) => (
+              <TopologyNode key={iface.iface} iface={iface} interfaces={interfaces} depth={0} />
+            ))}
+          </ul>
+          {(network.namespaces || []).length > 0 && (
+            <div className="mt-4 pt-4 border-t border-gray-800">
+              <Row
+                label="Network Namespaces"
+                value={network.namespaces.map((ns) => (ns.id !== null ? `${ns.name} (id ${ns.id})` : ns.name)).join(', ')}
+              />
+            </div>
+          )}
+        </section>
+      )}
+
+      <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+        <h3 className="text-blue-400 font-semibold mb-4 text-sm uppercase tracking-wider">Routing &amp; DNS</h3>
+        <div className="grid grid-cols-1 md:grid-cols-2 gap-x-12 mb-6">
+          <div className="space-y-1">
+            <Row label="IPv4 Gateway" value={gatewayText(network.ipv4Gateway)} />
+            <Row label="IPv6 Gateway" value={gatewayText(network.ipv6Gateway)} />
+          </div>
+          <div className="space-y-1">
+            <Row label="DNS Servers" value={network.dns?.servers.join(', ') ?? ''} />
+            <Row label="Search Domains" value={network.dns?.searchDomains.join(' ') ?? ''} />
+            {network.dns?.stubResolver && <Row label="Stub Resolver" value={network.dns.stubResolver} />}
+          </div>
+        </div>
+        {routes.length > 0 && (
+          <div className="overflow-x-auto">
+            <table className="w-full text-left">
+              <thead>
+                <tr className="border-b border-gray-800 bg-gray-800/30">
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Destination</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Gateway</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Interface</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Metric</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Table</th>
+                </tr>
+              </thead>
+              <tbody className="divide-y divide-gray-800">
+                {routes.map((route, idx) => (
+                  <tr key={idx} className="hover:bg-gray-800/20">
+                    <td className="px-4 py-3 text-sm text-gray-200 font-mono">{route.destination}</td>
+                    <td className="px-4 py-3 text-sm text-gray-400 font-mono">{route.gateway || '—'}</td>
+                    <td className="px-4 py-3 text-sm text-gray-400">{route.iface}</td>
+                    <td className="px-4 py-3 text-sm text-gray-400">{route.metric}</td>
+                    <td className="px-4 py-3 text-sm text-gray-400">{route.table}</td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        )}
+      </section>
     </div>
   );
 };
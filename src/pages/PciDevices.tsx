@@ -0,0 +1,49 @@
+import React from 'react';
+import Row from '../components/Row';
+import { HardwareInfo } from '../types';
+
+interface PciDevicesProps {
+  hardware: HardwareInfo;
+}
+
+const PciDevices: React.FC<PciDevicesProps> = ({ hardware }) => {
+  const devices = hardware.pci?.devices || [];
+
+  return (
+    <div className="space-y-6">
+      <header className="mb-8">
+        <h2 className="text-2xl font-bold text-white mb-2">PCI Devices</h2>
+        <p className="text-gray-400">Every device on the PCI bus, with names from the pci.ids database.</p>
+      </header>
+
+      {devices.length > 0 ? (
+        devices.map((dev) => (
+          <section key={dev.address} className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+            <h3 className="text-blue-400 font-semibold mb-4 text-sm uppercase tracking-wider">
+              {dev.address}: {dev.device}
+            </h3>
+            <div className="grid grid-cols-1 md:grid-cols-2 gap-x-12">
+              <div className="space-y-1">
+                <Row label="Class" value={dev.subclassName || dev.className} />
+                <Row label="Vendor" value={dev.vendor} />
+                <Row label="IDs" value={`${dev.vendorId}:${dev.deviceId} rev ${dev.revision}`} />
+                <Row label="Subsystem" value={`${dev.subsystemVendor} ${dev.subsystem}`.trim()} />
+              </div>
+              <div className="space-y-1">
+                <Row label="Driver" value={dev.driver} />
+                <Row label="IOMMU Group" value={dev.iommuGroup ?? ''} />
+                <Row label="NUMA Node" value={dev.numaNode ?? ''} />
+              </div>
+            </div>
+          </section>
+        ))
+      ) : (
+        <div className="p-12 text-center bg-gray-900/50 rounded-xl border border-dashed border-gray-800 text-gray-500">
+          No PCI devices detected (/sys/bus/pci/devices).
+        </div>
+      )}
+    </div>
+  );
+};
+
+export default PciDevices;
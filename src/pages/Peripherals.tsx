@@ -1,19 +1,46 @@
 import React from 'react';
 
-import { HardwareInfo } from '../types';
+import { HardwareInfo, UsbNode } from '../types';
+import { formatUsbSpeed } from '../utils';
 
 interface PeripheralsProps {
   hardware: HardwareInfo;
 }
 
+const UsbTreeNode: React.FC<{ node: UsbNode }> = ({ node }) => (
+  <li className="py-1">
+    <div className="text-sm">
+      {node.port > 0 && <span className="text-gray-500 font-mono mr-2">port {node.port}</span>}
+      <span className="text-gray-200">{node.device.vendor} {node.device.name}</span>
+      <span className="text-gray-400 ml-2">{formatUsbSpeed(node.device.speedMbps)}</span>
+      {node.speedDowngraded && (
+        <span className="text-amber-400 ml-2">USB 3 device running at USB 2 speed</span>
+      )}
+    </div>
+    {node.interfaces.map((iface) => (
+      <div key={iface.number} className="text-xs text-gray-500 ml-4">
+        Interface {iface.number}: {iface.className || iface.class} ({iface.driver || 'no driver'})
+      </div>
+    ))}
+    {node.children.length > 0 && (
+      <ul className="ml-6 border-l border-gray-800 pl-4">
+        {node.children.map((child) => (
+          <UsbTreeNode key={child.device.portPath} node={child} />
+        ))}
+      </ul>
+    )}
+  </li>
+);
+
 const Peripherals: React.FC<PeripheralsProps> = ({ hardware }) => {
   const devices = hardware.peripherals.usbDevices || [];
+  const tree = hardware.peripherals.usbTree || [];
 
   return (
     <div className="space-y-6">
       <header className="mb-8">
         <h2 className="text-2xl font-bold text-white mb-2">Peripherals (USB)</h2>
-        <p className="text-gray-400">USB devices, input devices, and external hardware detected via lsusb.</p>
+        <p className="text-gray-400">USB devices, input devices, and external hardware, with names from the usb.ids database.</p>
       </header>
 
       {devices.length > 0 ? (
@@ -24,7 +51,9 @@ const Peripherals: React.FC<PeripheralsProps> = ({ hardware }) => {
                 <tr className="border-b border-gray-800 bg-gray-800/30">
                   <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Bus/Dev</th>
                   <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">ID</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Vendor</th>
                   <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Device Name</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Speed</th>
                 </tr>
               </thead>
               <tbody className="divide-y divide-gray-800">
@@ -36,9 +65,15 @@ const Peripherals: React.FC<PeripheralsProps> = ({ hardware }) => {
                     <td className="px-4 py-3 text-sm text-blue-400 font-mono">
                       {usb.vendorId}:{usb.productId}
                     </td>
+                    <td className="px-4 py-3 text-sm text-gray-400">
+                      {usb.vendor}
+                    </td>
                     <td className="px-4 py-3 text-sm text-gray-200">
                       {usb.name}
                     </td>
+                    <td className="px-4 py-3 text-sm text-gray-400">
+                      {formatUsbSpeed(usb.speedMbps)}
+                    </td>
                   </tr>
                 ))}
               </tbody>
@@ -47,9 +82,20 @@ const Peripherals: React.FC<PeripheralsProps> = ({ hardware }) => {
         </section>
       ) : (
         <div className="p-12 text-center bg-gray-900/50 rounded-xl border border-dashed border-gray-800 text-gray-500">
-          No USB devices detected (lsusb).
+          No USB devices detected (/sys/bus/usb/devices).
         </div>
       )}
+
+      {tree.length > 0 && (
+        <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+          <h3 className="text-blue-400 font-semibold mb-4 text-sm uppercase tracking-wider">Topology</h3>
+          <ul>
+            {tree.map((node) => (
+              <UsbTreeNode key={node.device.portPath} node={node} />
+            ))}
+          </ul>
+        </section>
+      )}
     </div>
   );
 };
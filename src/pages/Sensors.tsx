@@ -0,0 +1,134 @@
+import React from 'react';
+import Sparkline from '../components/Sparkline';
+import { HardwareInfo, LiveInfo, Sensor, SensorChip, ThermalZone } from '../types';
+
+interface SensorsProps {
+  hardware: HardwareInfo;
+  /** Oldest first; empty until the first live poll. */
+  history: LiveInfo[];
+}
+
+const DIGITS: Record<string, number> = { '°C': 1, RPM: 0, V: 3, A: 2 };
+
+const formatReading = (value: number | null, unit: string) =>
+  value === null ? '—' : `${value.toFixed(DIGITS[unit] ?? 1)} ${unit}`;
+
+const limitsText = (sensor: Sensor) =>
+  (
+    [
+      ['min', sensor.min],
+      ['max', sensor.max],
+      ['crit', sensor.crit],
+    ] as const
+  )
+    .filter(([, limit]) => limit !== null)
+    .map(([name, limit]) => `${name} ${formatReading(limit, sensor.unit)}`)
+    .join(', ');
+
+const ChipCard: React.FC<{ chip: SensorChip; samples: (SensorChip | undefined)[] }> = ({ chip, samples }) => (
+  <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+    <h3 className="text-blue-400 font-semibold mb-4 text-sm uppercase tracking-wider">
+      {chip.name} <span className="text-gray-500 normal-case">({chip.device || chip.hwmon})</span>
+    </h3>
+    <div className="overflow-x-auto">
+      <table className="w-full text-left">
+        <thead>
+          <tr className="border-b border-gray-800 bg-gray-800/30">
+            <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Sensor</th>
+            <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">Value</th>
+            <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">History</th>
+            <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Limits</th>
+          </tr>
+        </thead>
+        <tbody className="divide-y divide-gray-800">
+          {chip.sensors.map((sensor) => (
+            <tr key={sensor.channel} className="hover:bg-gray-800/20">
+              <td className="px-4 py-3 text-sm text-gray-200">
+                {sensor.label || sensor.channel}
+                <span className="text-gray-500 text-xs ml-2">{sensor.kind}</span>
+              </td>
+              <td className="px-4 py-3 text-sm text-gray-200 font-mono text-right">
+                {formatReading(sensor.value, sensor.unit)}
+              </td>
+              <td className="px-4 py-3">
+                <Sparkline
+                  values={samples.map((s) => s?.sensors.find((x) => x.channel === sensor.channel)?.value ?? 0)}
+                  className="text-emerald-400"
+                />
+              </td>
+              <td className="px-4 py-3 text-sm text-gray-400">
+                {limitsText(sensor)}
+                {sensor.alarms.length > 0 && (
+                  <span className="text-amber-400 ml-2">{sensor.alarms.join(', ')}</span>
+                )}
+              </td>
+            </tr>
+          ))}
+        </tbody>
+      </table>
+    </div>
+  </section>
+);
+
+const zoneTrips = (zone: ThermalZone) =>
+  zone.tripPoints.map((trip) => `${trip.type} at ${formatReading(trip.temperature, '°C')}`).join(', ');
+
+const Sensors: React.FC<SensorsProps> = ({ hardware, history }) => {
+  const sensors = history.length > 0 ? history[history.length - 1].sensors : hardware.sensors;
+  const chips = sensors?.chips || [];
+  const zones = sensors?.thermalZones || [];
+  const chipHistory = (hwmon: string) => history.map((sample) => sample.sensors.chips.find((c) => c.hwmon === hwmon));
+
+  return (
+    <div className="space-y-6">
+      <header className="mb-8">
+        <h2 className="text-2xl font-bold text-white mb-2">Sensors</h2>
+        <p className="text-gray-400">Temperatures, fans, voltages, currents and power from every monitoring chip.</p>
+      </header>
+
+      {chips.length > 0 ? (
+        chips.map((chip) => <ChipCard key={chip.hwmon || chip.name} chip={chip} samples={chipHistory(chip.hwmon)} />)
+      ) : (
+        <div className="p-12 text-center bg-gray-900/50 rounded-xl border border-dashed border-gray-800 text-gray-500">
+          No sensors detected.
+        </div>
+      )}
+
+      {zones.length > 0 && (
+        <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+          <h3 className="text-blue-400 font-semibold mb-4 text-sm uppercase tracking-wider">Thermal Zones</h3>
+          <div className="overflow-x-auto">
+            <table className="w-full text-left">
+              <thead>
+                <tr className="border-b border-gray-800 bg-gray-800/30">
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Zone</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Type</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">
+                    Temperature
+                  </th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Trip Points</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Policy</th>
+                </tr>
+              </thead>
+              <tbody className="divide-y divide-gray-800">
+                {zones.map((zone) => (
+                  <tr key={zone.zone} className="hover:bg-gray-800/20">
+                    <td className="px-4 py-3 text-sm text-gray-200 font-mono">{zone.zone}</td>
+                    <td className="px-4 py-3 text-sm text-gray-400">{zone.type}</td>
+                    <td className="px-4 py-3 text-sm text-gray-200 font-mono text-right">
+                      {formatReading(zone.temperature, '°C')}
+                    </td>
+                    <td className="px-4 py-3 text-sm text-gray-400">{zoneTrips(zone) || '—'}</td>
+                    <td className="px-4 py-3 text-sm text-gray-400">{zone.policy}</td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        </section>
+      )}
+    </div>
+  );
+};
+
+export default Sensors;
@@ -1,15 +1,61 @@
 import React from 'react';
 
+import Sparkline from '../components/Sparkline';
 import { formatBytes, formatPercent } from '../utils';
-import { HardwareInfo } from '../types';
+import { HardwareInfo, LiveInfo, StorageNode } from '../types';
 
 interface StorageProps {
   hardware: HardwareInfo;
+  /** Oldest first; empty until the first live poll. */
+  history: LiveInfo[];
 }
 
-const Storage: React.FC<StorageProps> = ({ hardware }) => {
+const StorageTreeNode: React.FC<{ node: StorageNode }> = ({ node }) => (
+  <li className="py-1">
+    <div className="text-sm">
+      <span className="text-gray-200 font-mono">{node.mapperName || node.name}</span>
+      <span className="text-gray-500 ml-2">{node.kind}</span>
+      <span className="text-gray-400 ml-2">{formatBytes(node.size)}</span>
+      {node.raid && (
+        <span className="text-gray-400 ml-2">
+          {node.raid.level} {node.raid.arrayState}
+          {node.raid.syncProgress !== null && ` · ${node.raid.syncAction} ${node.raid.syncProgress.toFixed(1)}%`}
+        </span>
+      )}
+      {node.raid && node.raid.degraded > 0 && (
+        <span className="text-amber-400 ml-2">
+          degraded: {node.raid.degraded} of {node.raid.raidDisks} members missing
+        </span>
+      )}
+      {node.mountpoints.length > 0 && (
+        <span className="text-blue-400 ml-2">
+          {node.fsType} on {node.mountpoints.join(', ')}
+        </span>
+      )}
+    </div>
+    {node.raid?.members.map((member) => (
+      <div key={member.name} className="text-xs text-gray-500 ml-4">
+        {member.slot !== null ? `Slot ${member.slot}` : 'Spare'}: {member.name} ({member.state})
+      </div>
+    ))}
+    {node.children.length > 0 && (
+      <ul className="ml-6 border-l border-gray-800 pl-4">
+        {node.children.map((child) => (
+          <StorageTreeNode key={child.name} node={child} />
+        ))}
+      </ul>
+    )}
+  </li>
+);
+
+const Storage: React.FC<StorageProps> = ({ hardware, history }) => {
   const layout = hardware.storage.diskLayout || [];
   const filesystems = hardware.storage.filesystems || [];
+  const partitioned = layout.filter((disk) => disk.partitionTable);
+  const stack = hardware.storage.stack || [];
+  const latestIo = history.length > 0 ? history[history.length - 1].diskIo : hardware.diskIo || [];
+  const ioHistory = (name: string) =>
+    history.map((sample) => sample.diskIo.find((io) => io.name === name));
 
   return (
     <div className="space-y-6">
@@ -27,17 +73,38 @@ const Storage: React.FC<StorageProps> = ({ hardware }) => {
             <thead>
               <tr className="border-b border-gray-800 bg-gray-800/30">
                 <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Device</th>
+                <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Model</th>
                 <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Type</th>
                 <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Interface</th>
+                <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Serial</th>
+                <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Firmware</th>
+                <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Sectors</th>
                 <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">Size</th>
               </tr>
             </thead>
             <tbody className="divide-y divide-gray-800">
               {layout.map((disk, idx) => (
                 <tr key={idx} className="hover:bg-gray-800/20">
-                  <td className="px-4 py-3 text-sm text-gray-200">{disk.name}</td>
+                  <td className="px-4 py-3 text-sm text-gray-200 font-mono">
+                    {disk.name}
+                    {disk.removable && <span className="ml-2 text-xs text-gray-500">removable</span>}
+                  </td>
+                  <td className="px-4 py-3 text-sm text-gray-200">
+                    {[disk.vendor, disk.model].filter(Boolean).join(' ') || '—'}
+                  </td>
                   <td className="px-4 py-3 text-sm text-gray-200 uppercase">{disk.type}</td>
-                  <td className="px-4 py-3 text-sm text-gray-200">{disk.interfaceType || 'SATA/NVMe'}</td>
+                  <td className="px-4 py-3 text-sm text-gray-200">{disk.interfaceType || '—'}</td>
+                  <td className="px-4 py-3 text-sm text-gray-400 font-mono text-xs" title={disk.wwn}>
+                    {disk.serial || '—'}
+                  </td>
+                  <td className="px-4 py-3 text-sm text-gray-400 font-mono text-xs">{disk.firmwareRevision || '—'}</td>
+                  <td className="px-4 py-3 text-sm text-gray-400 font-mono text-xs">
+                    {disk.logicalSectorSize
+                      ? disk.logicalSectorSize === disk.physicalSectorSize
+                        ? `${disk.logicalSectorSize} B`
+                        : `${disk.logicalSectorSize} / ${disk.physicalSectorSize} B`
+                      : '—'}
+                  </td>
                   <td className="px-4 py-3 text-sm text-gray-200 text-right font-mono">{formatBytes(disk.size)}</td>
                 </tr>
               ))}
@@ -46,6 +113,118 @@ const Storage: React.FC<StorageProps> = ({ hardware }) => {
         </div>
       </section>
 
+      {latestIo.length > 0 && (
+        <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+          <h3 className="text-blue-400 font-semibold mb-4 text-sm uppercase tracking-wider">Disk Activity</h3>
+          <div className="overflow-x-auto">
+            <table className="w-full text-left">
+              <thead>
+                <tr className="border-b border-gray-800 bg-gray-800/30">
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Device</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Read</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Write</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">IOPS (r / w)</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Latency (r / w)</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Queue</th>
+                  <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Busy</th>
+                </tr>
+              </thead>
+              <tbody className="divide-y divide-gray-800">
+                {latestIo.map((io) => {
+                  const samples = ioHistory(io.name);
+                  return (
+                    <tr key={io.name} className="hover:bg-gray-800/20">
+                      <td className="px-4 py-3 text-sm text-gray-200 font-mono">{io.name}</td>
+                      <td className="px-4 py-3 text-sm text-gray-200">
+                        <Sparkline values={samples.map((s) => s?.readBytesPerSec ?? 0)} className="text-emerald-400" />
+                        <span className="font-mono text-xs">{formatBytes(io.readBytesPerSec)}/s</span>
+                      </td>
+                      <td className="px-4 py-3 text-sm text-gray-200">
+                        <Sparkline values={samples.map((s) => s?.writeBytesPerSec ?? 0)} className="text-amber-400" />
+                        <span className="font-mono text-xs">{formatBytes(io.writeBytesPerSec)}/s</span>
+                      </td>
+                      <td className="px-4 py-3 text-sm text-gray-400 font-mono text-xs">
+                        {io.readIops.toFixed(0)} / {io.writeIops.toFixed(0)}
+                      </td>
+                      <td className="px-4 py-3 text-sm text-gray-400 font-mono text-xs">
+                        {io.readLatencyMs.toFixed(1)} / {io.writeLatencyMs.toFixed(1)} ms
+                      </td>
+                      <td className="px-4 py-3 text-sm text-gray-400 font-mono text-xs">{io.queueDepth.toFixed(2)}</td>
+                      <td className="px-4 py-3 text-sm text-gray-200">
+                        <Sparkline values={samples.map((s) => s?.utilizationPct ?? 0)} max={100} />
+                        <span className="font-mono text-xs">{formatPercent(io.utilizationPct)}</span>
+                      </td>
+                    </tr>
+                  );
+                })}
+              </tbody>
+            </table>
+          </div>
+        </section>
+      )}
+
+      {partitioned.map((disk) => {
+        const table = disk.partitionTable!;
+        return (
+          <section
+            key={disk.name}
+            className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm"
+          >
+            <h3 className="text-blue-400 font-semibold mb-1 text-sm uppercase tracking-wider">
+              Partitions — {disk.name}
+            </h3>
+            <p className="text-xs text-gray-500 mb-4 font-mono">
+              {table.scheme
+                ? `${table.scheme} · ${table.diskId}`
+                : 'Partition table not readable without root; showing the kernel\'s extents only.'}
+            </p>
+            <div className="overflow-x-auto">
+              <table className="w-full text-left">
+                <thead>
+                  <tr className="border-b border-gray-800 bg-gray-800/30">
+                    <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">#</th>
+                    <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Type</th>
+                    <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Label</th>
+                    <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Start – End LBA</th>
+                    <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">UUID</th>
+                    <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider">Flags</th>
+                    <th className="px-4 py-3 text-xs font-bold text-gray-400 uppercase tracking-wider text-right">Size</th>
+                  </tr>
+                </thead>
+                <tbody className="divide-y divide-gray-800">
+                  {table.partitions.map((part) => (
+                    <tr key={part.number} className="hover:bg-gray-800/20">
+                      <td className="px-4 py-3 text-sm text-gray-200 font-mono">{part.number}</td>
+                      <td className="px-4 py-3 text-sm text-gray-200" title={part.typeId}>
+                        {part.typeName || part.typeId || '—'}
+                      </td>
+                      <td className="px-4 py-3 text-sm text-gray-200">{part.label || '—'}</td>
+                      <td className="px-4 py-3 text-sm text-gray-400 font-mono text-xs">
+                        {part.startLba} – {part.endLba}
+                      </td>
+                      <td className="px-4 py-3 text-sm text-gray-400 font-mono text-xs">{part.uuid || '—'}</td>
+                      <td className="px-4 py-3 text-sm text-gray-400 text-xs">{part.flags.join(', ') || '—'}</td>
+                      <td className="px-4 py-3 text-sm text-gray-200 text-right font-mono">{formatBytes(part.size)}</td>
+                    </tr>
+                  ))}
+                </tbody>
+              </table>
+            </div>
+          </section>
+        );
+      })}
+
+      {stack.length > 0 && (
+        <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
+          <h3 className="text-blue-400 font-semibold mb-4 text-sm uppercase tracking-wider">Storage Stack</h3>
+          <ul>
+            {stack.map((node) => (
+              <StorageTreeNode key={node.name} node={node} />
+            ))}
+          </ul>
+        </section>
+      )}
+
       <section className="bg-gray-900/50 rounded-xl p-6 border border-gray-800 backdrop-blur-sm">
         <h3 className="text-blue-400 font-semibold mb-4 text-sm uppercase tracking-wider">
           File Systems (Mount Points)
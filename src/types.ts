@@ -1,8 +1,30 @@
 // TypeScript interfaces matching the Rust structs
 
 export interface CpuCache {
+  /** Sizes of the caches serving cpu0. */
+  l1d: number;
+  l1i: number;
   l2: number;
   l3: number;
+  instances: CacheInstance[];
+  totals: CacheTotal[];
+}
+
+export interface CacheInstance {
+  level: number;
+  type: 'Data' | 'Instruction' | 'Unified';
+  size: number;
+  lineSize: number;
+  ways: number;
+  sets: number;
+  cpus: number[];
+}
+
+export interface CacheTotal {
+  level: number;
+  type: string;
+  size: number;
+  instances: number;
 }
 
 export interface CpuInfo {
@@ -16,16 +38,151 @@ export interface CpuInfo {
   speed: number;
   speedMax: number;
   cache: CpuCache;
+  topology: CpuTopology;
+  /** Fastest first; empty unless the processor mixes core types. */
+  coreTypes: CpuCoreType[];
+  features: CpuFeatures;
+  frequencyScaling: FrequencyScaling;
+  /** Draw over the report's sample interval. */
+  power: CpuPower;
+}
+
+/** Empty where frequency is not scaled, as in most virtual machines. */
+export interface FrequencyScaling {
+  driver: string;
+  boost: boolean | null;
+  policies: CpufreqPolicy[];
+}
+
+/** Frequencies in GHz, 0 when not reported. */
+export interface CpufreqPolicy {
+  policy: number;
+  cpus: number[];
+  governor: string;
+  availableGovernors: string[];
+  hardwareMin: number;
+  hardwareMax: number;
+  scalingMin: number;
+  scalingMax: number;
+  current: number;
+  base: number;
+  energyPerformancePreference: string;
+  availablePreferences: string[];
+}
+
+export interface CpuFeatures {
+  flags: string[];
+  extensions: FeatureCategory[];
+  microcode: string;
+  /** `vmx`, `svm`, or empty. */
+  virtualization: string;
+  hypervisor: boolean;
+  vulnerabilities: CpuVulnerability[];
+}
+
+export interface FeatureCategory {
+  category: string;
+  flags: string[];
+}
+
+export interface CpuVulnerability {
+  name: string;
+  status: 'not affected' | 'mitigated' | 'vulnerable' | 'unknown';
+  detail: string;
+}
+
+export interface CpuCoreType {
+  kind: 'performance' | 'mid' | 'efficiency';
+  cores: number;
+  cpus: number[];
+  speedMin: number;
+  speedMax: number;
+}
+
+export interface CpuCore {
+  id: number;
+  threads: number[];
+}
+
+export interface CpuCluster {
+  id: number;
+  cores: CpuCore[];
+}
+
+export interface CpuDie {
+  id: number;
+  clusters: CpuCluster[];
+}
+
+export interface CpuPackage {
+  id: number;
+  dies: CpuDie[];
+}
+
+export interface LogicalCpu {
+  cpu: number;
+  online: boolean;
+  numaNode: number | null;
+}
+
+export interface NumaNode {
+  id: number;
+  cpus: number[];
+  memoryTotal: number;
+  distances: number[];
+}
+
+export interface CpuTopology {
+  packages: CpuPackage[];
+  cpus: LogicalCpu[];
+  numaNodes: NumaNode[];
 }
 
 export interface CpuCurrentSpeed {
   avg: number;
   min: number;
   max: number;
+  cores: number[];
 }
 
 export interface CurrentLoad {
   currentLoad: number;
+  cpus: number[];
+}
+
+export interface CpuTimes {
+  user: number;
+  nice: number;
+  system: number;
+  idle: number;
+  iowait: number;
+  irq: number;
+  softirq: number;
+  steal: number;
+}
+
+export interface CpuPower {
+  domains: PowerDomain[];
+}
+
+export interface PowerDomain {
+  /** `package-0`, `core`, `uncore`, `dram`, `psys`, or an amd_energy label. */
+  name: string;
+  /** Powercap zone such as `intel-rapl:0:1`, or `hwmon3/energy1`. */
+  zone: string;
+  enabled: boolean;
+  /** Null on the first sample or when the counter is unreadable without root. */
+  watts: number | null;
+  limits: PowerLimit[];
+}
+
+/** `long_term` is PL1, `short_term` PL2. */
+export interface PowerLimit {
+  name: string;
+  watts: number;
+  /** Seconds. */
+  timeWindow: number | null;
+  maxWatts: number | null;
 }
 
 export interface CpuTemperature {
@@ -66,11 +223,64 @@ export interface GraphicsInfo {
   controllers: GpuController[];
 }
 
+export interface PciDevice {
+  address: string;
+  classId: string;
+  progIf: string;
+  className: string;
+  subclassName: string;
+  vendorId: string;
+  deviceId: string;
+  subsystemVendorId: string;
+  subsystemDeviceId: string;
+  revision: string;
+  vendor: string;
+  device: string;
+  subsystemVendor: string;
+  subsystem: string;
+  driver: string;
+  iommuGroup: number | null;
+  numaNode: number | null;
+}
+
+export interface PciInfo {
+  devices: PciDevice[];
+}
+
 export interface DiskLayoutEntry {
   name: string;
   type: string;
   size: number;
   interfaceType: string;
+  model: string;
+  vendor: string;
+  serial: string;
+  wwn: string;
+  firmwareRevision: string;
+  rotational: boolean;
+  removable: boolean;
+  logicalSectorSize: number;
+  physicalSectorSize: number;
+  partitionTable: PartitionTable | null;
+}
+
+export interface PartitionTable {
+  scheme: string;
+  diskId: string;
+  sectorSize: number;
+  partitions: Partition[];
+}
+
+export interface Partition {
+  number: number;
+  startLba: number;
+  endLba: number;
+  size: number;
+  typeId: string;
+  typeName: string;
+  label: string;
+  uuid: string;
+  flags: string[];
 }
 
 export interface FilesystemEntry {
@@ -81,20 +291,163 @@ export interface FilesystemEntry {
   use: number;
 }
 
+export interface StorageNode {
+  name: string;
+  mapperName: string;
+  kind: string;
+  size: number;
+  fsType: string;
+  mountpoints: string[];
+  raid: RaidInfo | null;
+  children: StorageNode[];
+}
+
+export interface RaidInfo {
+  level: string;
+  arrayState: string;
+  syncAction: string;
+  syncProgress: number | null;
+  raidDisks: number;
+  degraded: number;
+  members: RaidMember[];
+}
+
+export interface RaidMember {
+  name: string;
+  state: string;
+  slot: number | null;
+}
+
+export interface DiskIo {
+  name: string;
+  readBytesPerSec: number;
+  writeBytesPerSec: number;
+  readIops: number;
+  writeIops: number;
+  readLatencyMs: number;
+  writeLatencyMs: number;
+  queueDepth: number;
+  utilizationPct: number;
+}
+
 export interface StorageInfo {
   diskLayout: DiskLayoutEntry[];
+  stack: StorageNode[];
   filesystems: FilesystemEntry[];
 }
 
+export interface InterfaceAddress {
+  address: string;
+  prefixLen: number;
+  scope: string;
+}
+
 export interface NetworkInterface {
   iface: string;
-  ip4: string;
-  ip6: string;
+  ifindex: number;
+  kind: string;
+  ip4: InterfaceAddress[];
+  ip6: InterfaceAddress[];
   mac: string;
+  mtu: number;
+  operstate: string;
+  carrier: boolean | null;
+  speedMbps: number | null;
+  duplex: string;
+  driver: string;
+  parentBus: string;
+  parentDevice: string;
+  wireless: WirelessInfo | null;
+  master: string;
+  ports: string[];
+  bond: BondInfo | null;
+  vlan: VlanInfo | null;
+  vethPeer: VethPeer | null;
+}
+
+export interface BondInfo {
+  mode: string;
+  activeSlave: string;
+  miiStatus: string;
+}
+
+export interface VlanInfo {
+  id: number;
+  parent: string;
+}
+
+export interface VethPeer {
+  name: string;
+  ifindex: number;
+  netnsId: number | null;
+  netns: string;
+}
+
+export interface NetworkNamespace {
+  name: string;
+  id: number | null;
+}
+
+export interface WirelessInfo {
+  phy: string;
+  ssid: string;
+  bssid: string;
+  frequencyMhz: number | null;
+  channel: number | null;
+  band: string;
+  channelWidthMhz: number | null;
+  signalDbm: number | null;
+  noiseDbm: number | null;
+  linkQualityPct: number | null;
+  txBitrateMbps: number | null;
+  rxBitrateMbps: number | null;
+  supportedBands: string[];
+  regulatoryDomain: string;
+}
+
+export interface Route {
+  family: string;
+  destination: string;
+  gateway: string;
+  iface: string;
+  metric: number;
+  table: string;
+}
+
+export interface DnsConfig {
+  servers: string[];
+  searchDomains: string[];
+  stubResolver: string;
+  source: string;
 }
 
 export interface NetworkInfo {
   interfaces: NetworkInterface[];
+  routes: Route[];
+  ipv4Gateway: Route | null;
+  ipv6Gateway: Route | null;
+  dns: DnsConfig;
+  namespaces: NetworkNamespace[];
+}
+
+export interface NetworkIo {
+  iface: string;
+  rxBytesPerSec: number;
+  txBytesPerSec: number;
+  rxPacketsPerSec: number;
+  txPacketsPerSec: number;
+  rxErrors: number;
+  txErrors: number;
+  rxDropped: number;
+  txDropped: number;
+  rxBytesTotal: number;
+  txBytesTotal: number;
+  rxPacketsTotal: number;
+  txPacketsTotal: number;
+  rxErrorsTotal: number;
+  txErrorsTotal: number;
+  rxDroppedTotal: number;
+  txDroppedTotal: number;
 }
 
 export interface AudioDevice {
@@ -114,10 +467,38 @@ export interface UsbDevice {
   productId: string;
   bus: string;
   device: string;
+  manufacturer: string;
+  product: string;
+  serial: string;
+  deviceClass: string;
+  className: string;
+  speedMbps: number;
+  maxPowerMa: number;
+  usbVersion: string;
+  portPath: string;
+}
+
+export interface UsbInterface {
+  number: number;
+  class: string;
+  subclass: string;
+  protocol: string;
+  className: string;
+  subclassName: string;
+  driver: string;
+}
+
+export interface UsbNode {
+  device: UsbDevice;
+  port: number;
+  speedDowngraded: boolean;
+  interfaces: UsbInterface[];
+  children: UsbNode[];
 }
 
 export interface PeripheralInfo {
   usbDevices: UsbDevice[];
+  usbTree: UsbNode[];
 }
 
 export interface OpticalDevice {
@@ -175,26 +556,95 @@ export interface RuntimeInfo {
   current: number;
 }
 
+export type ProbeError =
+  | { kind: 'missingTool'; tool: string }
+  | { kind: 'permissionDenied'; path: string }
+  | { kind: 'notFound'; path: string }
+  | { kind: 'parseFailure'; source: string; message: string }
+  | { kind: 'commandFailed'; tool: string; exitCode: number | null; stderr: string }
+  | { kind: 'io'; path: string; message: string }
+  | { kind: 'unsupported'; platform: string };
+
+export interface ProbeReport {
+  probe: string;
+  source: string;
+  durationMs: number;
+  error: ProbeError | null;
+}
+
+export interface Diagnostics {
+  probes: ProbeReport[];
+}
+
+export interface Sensor {
+  kind: string;
+  channel: string;
+  label: string;
+  unit: string;
+  value: number | null;
+  min: number | null;
+  max: number | null;
+  crit: number | null;
+  alarms: string[];
+}
+
+export interface SensorChip {
+  hwmon: string;
+  name: string;
+  device: string;
+  sensors: Sensor[];
+}
+
+export interface TripPoint {
+  type: string;
+  temperature: number;
+  hysteresis: number | null;
+}
+
+export interface ThermalZone {
+  zone: string;
+  type: string;
+  temperature: number | null;
+  policy: string;
+  tripPoints: TripPoint[];
+}
+
+export interface SensorInfo {
+  chips: SensorChip[];
+  thermalZones: ThermalZone[];
+}
+
 export interface HardwareInfo {
   staticData: StaticData;
   cpu: CpuInfo;
   cpuCurrentSpeed: CpuCurrentSpeed;
   currentLoad: CurrentLoad;
+  cpuTimes: CpuTimes;
   cpuTemperature: CpuTemperature;
+  sensors: SensorInfo;
   graphics: GraphicsInfo;
+  pci: PciInfo;
   network: NetworkInfo;
   storage: StorageInfo;
+  diskIo: DiskIo[];
+  networkIo: NetworkIo[];
   memory: MemoryInfo;
   audio: AudioInfo;
   peripherals: PeripheralInfo;
   optical: OpticalInfo;
   runtime: RuntimeInfo;
+  diagnostics: Diagnostics;
 }
 
 export interface LiveInfo {
   cpuCurrentSpeed: CpuCurrentSpeed;
   currentLoad: CurrentLoad;
+  cpuTimes: CpuTimes;
+  cpuPower: CpuPower;
   cpuTemperature: CpuTemperature;
+  sensors: SensorInfo;
   memory: MemoryInfo;
+  diskIo: DiskIo[];
+  networkIo: NetworkIo[];
   runtime: RuntimeInfo;
 }
@@ -1,3 +1,5 @@
+import type { ProbeError } from './types';
+
 const dash = '—';
 
 export function formatBytes(value?: number) {
@@ -28,3 +30,33 @@ export function formatSeconds(value?: number) {
   const mins = Math.floor((value % 3600) / 60);
   return `${hrs}h ${mins}m`;
 }
+
+export function formatUsbSpeed(mbps?: number) {
+  if (!mbps || mbps <= 0) return dash;
+  return mbps >= 1000 ? `${mbps / 1000} Gbit/s` : `${mbps} Mbit/s`;
+}
+
+// Mirrors the Display impl of ProbeError in hardware-info-core.
+export function describeProbeError(error: ProbeError | null) {
+  if (!error) return 'OK';
+  switch (error.kind) {
+    case 'missingTool':
+      return `${error.tool} is not installed`;
+    case 'permissionDenied':
+      return `permission denied: ${error.path}`;
+    case 'notFound':
+      return `not present: ${error.path}`;
+    case 'parseFailure':
+      return `could not parse ${error.source}: ${error.message}`;
+    case 'commandFailed': {
+      const status = error.exitCode === null
+        ? `${error.tool} was terminated by a signal`
+        : `${error.tool} exited with status ${error.exitCode}`;
+      return error.stderr ? `${status}: ${error.stderr}` : status;
+    }
+    case 'io':
+      return `${error.path}: ${error.message}`;
+    case 'unsupported':
+      return `not supported on ${error.platform}`;
+  }
+}